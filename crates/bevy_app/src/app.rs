@@ -1,4 +1,4 @@
-use crate::{CoreSchedule, CoreSet, Plugin, PluginGroup, StartupSet};
+use crate::{CoreSchedule, CoreSet, FixedUpdateSet, Plugin, PluginGroup, StartupSet};
 pub use bevy_derive::AppLabel;
 use bevy_ecs::{
     prelude::*,
@@ -8,8 +8,8 @@ use bevy_ecs::{
         ScheduleLabel,
     },
 };
-use bevy_utils::{tracing::debug, HashMap, HashSet};
-use std::fmt::Debug;
+use bevy_utils::{tracing::debug, Duration, HashMap, HashSet, Instant};
+use std::{fmt::Debug, num::NonZeroU32};
 
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
@@ -143,6 +143,57 @@ pub struct SubApp {
     /// A function that allows access to both the [`SubApp`] [`World`] and the main [`App`]. This is
     /// useful for moving data between the sub app and the main app.
     extract: Box<dyn Fn(&mut World, &mut App) + Send>,
+
+    /// Determines on which updates of the main app this sub app is extracted and run.
+    update_mode: SubAppUpdateMode,
+    /// The number of main app updates left before the next run in [`SubAppUpdateMode::EveryNFrames`].
+    skipped_updates: u32,
+    /// When this sub app was last scheduled to run in [`SubAppUpdateMode::FixedRate`].
+    last_update: Option<Instant>,
+}
+
+/// Determines on which updates of the main [`App`] a [`SubApp`] is extracted and run.
+///
+/// Regardless of the mode, a sub app always runs on an update for which it was requested through
+/// [`SubAppUpdateRequests`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SubAppUpdateMode {
+    /// The sub app runs on every update of the main app.
+    #[default]
+    EveryFrame,
+    /// The sub app runs on the first update of the main app, and then once every `n` updates.
+    EveryNFrames(NonZeroU32),
+    /// The sub app runs at most once per update of the main app, whenever at least the given
+    /// amount of real time has passed since its previous scheduled run.
+    ///
+    /// Skipped runs are not caught up on: if the main app updates slower than the given period,
+    /// the sub app runs on every update.
+    FixedRate(Duration),
+    /// The sub app only runs on updates for which it was requested through
+    /// [`SubAppUpdateRequests`].
+    OnDemand,
+}
+
+/// Requests for [`SubApp`]s to be updated during the next [`App::update`], regardless of their
+/// [`SubAppUpdateMode`].
+///
+/// This resource lives in the main app's [`World`], so it can be used from regular systems.
+/// Requests are cleared once the sub apps have been updated.
+#[derive(Resource, Debug, Default)]
+pub struct SubAppUpdateRequests {
+    requested: HashSet<AppLabelId>,
+}
+
+impl SubAppUpdateRequests {
+    /// Requests the sub app with the given `label` to be updated during the next [`App::update`].
+    pub fn request(&mut self, label: impl AppLabel) {
+        self.requested.insert(label.as_label());
+    }
+
+    /// Returns `true` if an update was requested for the sub app with the given `label`.
+    pub fn is_requested(&self, label: impl AppLabel) -> bool {
+        self.requested.contains(&label.as_label())
+    }
 }
 
 impl SubApp {
@@ -156,9 +207,154 @@ impl SubApp {
         Self {
             app,
             extract: Box::new(extract),
+            update_mode: SubAppUpdateMode::default(),
+            skipped_updates: 0,
+            last_update: None,
         }
     }
 
+    /// Sets the [`SubAppUpdateMode`] of this sub app.
+    pub fn with_update_mode(mut self, update_mode: SubAppUpdateMode) -> Self {
+        self.set_update_mode(update_mode);
+        self
+    }
+
+    /// Sets the [`SubAppUpdateMode`] of this sub app.
+    pub fn set_update_mode(&mut self, update_mode: SubAppUpdateMode) {
+        self.update_mode = update_mode;
+        self.skipped_updates = 0;
+        self.last_update = None;
+    }
+
+    /// Returns the [`SubAppUpdateMode`] of this sub app.
+    pub fn update_mode(&self) -> SubAppUpdateMode {
+        self.update_mode
+    }
+
+    /// Adds a function that is called after the existing extract function of this sub app.
+    ///
+    /// See [`SubApp::new`] for the meaning of the parameters.
+    pub fn with_extract(mut self, extract: impl Fn(&mut World, &mut App) + Send + 'static) -> Self {
+        let previous = self.extract;
+        self.extract = Box::new(move |main_world, sub_app| {
+            previous(main_world, sub_app);
+            extract(main_world, sub_app);
+        });
+        self
+    }
+
+    /// Clones the resource `R` from the main world into the sub app world every time this sub app
+    /// is extracted. If the main world doesn't contain `R`, it is removed from the sub app world.
+    pub fn with_extracted_resource<R: Resource + Clone>(self) -> Self {
+        self.with_extract(|main_world, sub_app| match main_world.get_resource::<R>() {
+            Some(resource) => sub_app.world.insert_resource(resource.clone()),
+            None => {
+                sub_app.world.remove_resource::<R>();
+            }
+        })
+    }
+
+    /// Clones the resource `R` from the sub app world back into the main world every time this sub
+    /// app is extracted. Because this happens before the sub app runs, the main world sees the
+    /// value produced by the previous run of the sub app. If the sub app world doesn't contain `R`,
+    /// it is removed from the main world.
+    pub fn with_synced_resource<R: Resource + Clone>(self) -> Self {
+        self.with_extract(
+            |main_world, sub_app| match sub_app.world.get_resource::<R>() {
+                Some(resource) => main_world.insert_resource(resource.clone()),
+                None => {
+                    main_world.remove_resource::<R>();
+                }
+            },
+        )
+    }
+
+    /// Moves this sub app to a dedicated thread, so that it can run in parallel with the main app.
+    ///
+    /// Returns a [`SubApp`] that takes its place in the main app. When it is extracted, it waits
+    /// for the previous run of the threaded sub app to finish, calls the extract function of the
+    /// threaded sub app on the main thread, then sends it back to its thread to run. Data is only
+    /// exchanged with the main world during extract; the `app` of the returned sub app is empty.
+    ///
+    /// The [`SubAppUpdateMode`] of this sub app is moved to the returned one.
+    ///
+    /// # Panics
+    ///
+    /// Extracting the returned sub app panics if the threaded sub app panicked while running.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn into_threaded(mut self) -> SubApp {
+        use std::sync::mpsc::sync_channel;
+
+        let update_mode = self.update_mode;
+        self.set_update_mode(SubAppUpdateMode::EveryFrame);
+
+        let (to_thread_tx, to_thread_rx) = sync_channel::<SubApp>(1);
+        let (from_thread_tx, from_thread_rx) = sync_channel::<SubApp>(1);
+        // The sub app starts out on the main thread side so that it is extracted before its first run
+        from_thread_tx.send(self).unwrap();
+
+        std::thread::Builder::new()
+            .name("SubApp".to_string())
+            .spawn(move || {
+                while let Ok(mut sub_app) = to_thread_rx.recv() {
+                    sub_app.run();
+                    if from_thread_tx.send(sub_app).is_err() {
+                        break;
+                    }
+                }
+            })
+            .expect("Failed to spawn sub app thread.");
+
+        let mut app = App::empty();
+        app.add_simple_outer_schedule();
+        app.init_schedule(CoreSchedule::Main);
+
+        SubApp::new(app, move |main_world, _| {
+            let mut sub_app = from_thread_rx
+                .recv()
+                .expect("The thread running the sub app panicked.");
+            sub_app.extract(main_world);
+            to_thread_tx
+                .send(sub_app)
+                .expect("The thread running the sub app panicked.");
+        })
+        .with_update_mode(update_mode)
+    }
+
+    /// Returns `true` if this sub app should run during the current update of the main app,
+    /// and advances its [`SubAppUpdateMode`] bookkeeping.
+    fn should_update(&mut self, requested: bool) -> bool {
+        let scheduled = match self.update_mode {
+            SubAppUpdateMode::EveryFrame => true,
+            SubAppUpdateMode::EveryNFrames(n) => {
+                if self.skipped_updates == 0 {
+                    self.skipped_updates = n.get() - 1;
+                    true
+                } else {
+                    self.skipped_updates -= 1;
+                    false
+                }
+            }
+            SubAppUpdateMode::FixedRate(period) => {
+                let now = Instant::now();
+                match self.last_update {
+                    Some(last_update) if now - last_update < period => false,
+                    // Advance by exactly one period to avoid drifting, unless we fell behind
+                    Some(last_update) if now - last_update < period * 2 => {
+                        self.last_update = Some(last_update + period);
+                        true
+                    }
+                    _ => {
+                        self.last_update = Some(now);
+                        true
+                    }
+                }
+            }
+            SubAppUpdateMode::OnDemand => false,
+        };
+        scheduled || requested
+    }
+
     /// Runs the `SubApp`'s default schedule.
     pub fn run(&mut self) {
         self.app
@@ -192,6 +388,7 @@ impl Default for App {
         app.add_default_schedules();
 
         app.add_event::<AppExit>();
+        app.init_resource::<SubAppUpdateRequests>();
 
         #[cfg(feature = "bevy_ci_testing")]
         {
@@ -231,7 +428,7 @@ impl App {
 
     /// Advances the execution of the [`Schedule`] by one cycle.
     ///
-    /// This method also updates sub apps.
+    /// This method also updates sub apps, according to their [`SubAppUpdateMode`].
     /// See [`insert_sub_app`](Self::insert_sub_app) for more details.
     ///
     /// The schedule run by this method is determined by the [`outer_schedule_label`](App) field.
@@ -247,9 +444,18 @@ impl App {
             let _bevy_frame_update_span = info_span!("main app").entered();
             self.world.run_schedule_ref(&*self.outer_schedule_label);
         }
-        for (_label, sub_app) in self.sub_apps.iter_mut() {
+        let requests = self
+            .world
+            .get_resource_mut::<SubAppUpdateRequests>()
+            .filter(|requests| !requests.requested.is_empty())
+            .map(|mut requests| std::mem::take(&mut requests.requested))
+            .unwrap_or_default();
+        for (label, sub_app) in self.sub_apps.iter_mut() {
+            if !sub_app.should_update(requests.contains(label)) {
+                continue;
+            }
             #[cfg(feature = "trace")]
-            let _sub_app_span = info_span!("sub app", name = ?_label).entered();
+            let _sub_app_span = info_span!("sub app", name = ?label).entered();
             sub_app.extract(&mut self.world);
             sub_app.run();
         }
@@ -525,7 +731,7 @@ impl App {
     /// - [`CoreSchedule::Outer`]: uses [`CoreSchedule::outer_schedule`]
     /// - [`CoreSchedule::Startup`]: uses [`StartupSet::base_schedule`]
     /// - [`CoreSchedule::Main`]: uses [`CoreSet::base_schedule`]
-    /// - [`CoreSchedule::FixedUpdate`]: no starting configuration
+    /// - [`CoreSchedule::FixedUpdate`]: uses [`FixedUpdateSet::base_schedule`]
     ///
     /// # Examples
     ///
@@ -542,7 +748,7 @@ impl App {
         self.add_schedule(CoreSchedule::Outer, CoreSchedule::outer_schedule());
         self.add_schedule(CoreSchedule::Startup, StartupSet::base_schedule());
         self.add_schedule(CoreSchedule::Main, CoreSet::base_schedule());
-        self.init_schedule(CoreSchedule::FixedUpdate);
+        self.add_schedule(CoreSchedule::FixedUpdate, FixedUpdateSet::base_schedule());
 
         self
     }
@@ -930,6 +1136,17 @@ impl App {
         self.sub_apps.insert(label.as_label(), sub_app);
     }
 
+    /// Requests the sub app with the given `label` to be updated during the next call to
+    /// [`App::update`], regardless of its [`SubAppUpdateMode`].
+    ///
+    /// Systems can do the same through the [`SubAppUpdateRequests`] resource.
+    pub fn request_sub_app_update(&mut self, label: impl AppLabel) -> &mut Self {
+        self.world
+            .get_resource_or_insert_with(SubAppUpdateRequests::default)
+            .request(label);
+        self
+    }
+
     /// Removes a sub app from the app. Returns [`None`] if the label doesn't exist.
     pub fn remove_sub_app(&mut self, label: impl AppLabel) -> Option<SubApp> {
         self.sub_apps.remove(&label.as_label())
@@ -1022,7 +1239,13 @@ pub struct AppExit;
 
 #[cfg(test)]
 mod tests {
-    use crate::{App, Plugin};
+    use crate as bevy_app;
+    use crate::{
+        App, AppLabel, CoreSchedule, Plugin, SubApp, SubAppUpdateMode, SubAppUpdateRequests,
+    };
+    use bevy_ecs::prelude::*;
+    use bevy_utils::Duration;
+    use std::num::NonZeroU32;
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -1076,4 +1299,144 @@ mod tests {
         }
         App::new().add_plugin(PluginRun);
     }
+
+    #[derive(Resource, Default, Clone, PartialEq, Debug)]
+    struct Counter(u32);
+
+    #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, AppLabel)]
+    struct CountingApp;
+
+    fn counting_sub_app() -> App {
+        let mut sub_app = App::empty();
+        sub_app.add_simple_outer_schedule();
+        sub_app.init_schedule(CoreSchedule::Main);
+        sub_app.init_resource::<Counter>();
+        sub_app.add_system(|mut counter: ResMut<Counter>| counter.0 += 1);
+        sub_app
+    }
+
+    fn run_count(app: &App) -> u32 {
+        app.sub_app(CountingApp).world.resource::<Counter>().0
+    }
+
+    #[test]
+    fn sub_app_every_n_frames() {
+        let mut app = App::new();
+        app.insert_sub_app(
+            CountingApp,
+            SubApp::new(counting_sub_app(), |_, _| {})
+                .with_update_mode(SubAppUpdateMode::EveryNFrames(NonZeroU32::new(3).unwrap())),
+        );
+
+        let counts: Vec<u32> = (0..7)
+            .map(|_| {
+                app.update();
+                run_count(&app)
+            })
+            .collect();
+        assert_eq!(counts, vec![1, 1, 1, 2, 2, 2, 3]);
+    }
+
+    #[test]
+    fn sub_app_on_demand() {
+        let mut app = App::new();
+        app.insert_sub_app(
+            CountingApp,
+            SubApp::new(counting_sub_app(), |_, _| {}).with_update_mode(SubAppUpdateMode::OnDemand),
+        );
+
+        app.update();
+        assert_eq!(run_count(&app), 0);
+
+        app.request_sub_app_update(CountingApp);
+        app.update();
+        assert_eq!(run_count(&app), 1);
+
+        // requests are only honored once
+        app.update();
+        assert_eq!(run_count(&app), 1);
+
+        // requests can be made by systems of the main app
+        app.add_system(|mut requests: ResMut<SubAppUpdateRequests>| {
+            requests.request(CountingApp);
+        });
+        app.update();
+        app.update();
+        assert_eq!(run_count(&app), 3);
+    }
+
+    #[test]
+    fn sub_app_fixed_rate() {
+        let period = Duration::from_secs(60);
+        let mut app = App::new();
+        app.insert_sub_app(
+            CountingApp,
+            SubApp::new(counting_sub_app(), |_, _| {})
+                .with_update_mode(SubAppUpdateMode::FixedRate(period)),
+        );
+
+        app.update();
+        app.update();
+        assert_eq!(run_count(&app), 1);
+
+        // pretend a full period has passed since the last run
+        let sub_app = app.sub_apps.get_mut(&CountingApp.as_label()).unwrap();
+        sub_app.last_update = sub_app.last_update.map(|last_update| last_update - period);
+
+        app.update();
+        assert_eq!(run_count(&app), 2);
+
+        // the next run is one period after the previous one
+        app.update();
+        app.update();
+        assert_eq!(run_count(&app), 2);
+    }
+
+    #[test]
+    fn sub_app_extracted_and_synced_resources() {
+        #[derive(Resource, Clone)]
+        struct Input(u32);
+
+        let mut sub_app = counting_sub_app();
+        sub_app.add_system(|input: Res<Input>, mut counter: ResMut<Counter>| {
+            counter.0 += input.0;
+        });
+
+        let mut app = App::new();
+        app.insert_resource(Input(10));
+        app.insert_sub_app(
+            CountingApp,
+            SubApp::new(sub_app, |_, _| {})
+                .with_extracted_resource::<Input>()
+                .with_synced_resource::<Counter>(),
+        );
+
+        app.update();
+        // the counter is synced back before the sub app runs
+        assert_eq!(app.world.resource::<Counter>().0, 0);
+        assert_eq!(run_count(&app), 11);
+
+        app.world.resource_mut::<Input>().0 = 100;
+        app.update();
+        assert_eq!(app.world.resource::<Counter>().0, 11);
+        assert_eq!(run_count(&app), 112);
+    }
+
+    #[test]
+    fn threaded_sub_app() {
+        let mut app = App::new();
+        app.insert_sub_app(
+            CountingApp,
+            SubApp::new(counting_sub_app(), |_, _| {})
+                .with_synced_resource::<Counter>()
+                .with_update_mode(SubAppUpdateMode::EveryNFrames(NonZeroU32::new(2).unwrap()))
+                .into_threaded(),
+        );
+
+        for _ in 0..5 {
+            app.update();
+        }
+        // the sub app ran on updates 1, 3 and 5, and the counter was synced back before each run
+        assert_eq!(app.world.resource::<Counter>().0, 2);
+    }
 }
@@ -23,7 +23,8 @@ pub mod prelude {
     pub use crate::AppTypeRegistry;
     #[doc(hidden)]
     pub use crate::{
-        app::App, CoreSchedule, CoreSet, DynamicPlugin, Plugin, PluginGroup, StartupSet,
+        app::App, CoreSchedule, CoreSet, DynamicPlugin, FixedUpdateSet, Plugin, PluginGroup,
+        StartupSet,
     };
 }
 
@@ -204,3 +205,41 @@ impl StartupSet {
         schedule
     }
 }
+
+/// The names of the default [`App`] fixed update sets, which live in [`CoreSchedule::FixedUpdate`].
+///
+/// The corresponding [`SystemSets`](bevy_ecs::schedule::SystemSet) are added by [`App::add_default_schedules`].
+///
+/// The `*Flush` sets are assigned to the copy of [`apply_system_buffers`]
+/// that runs immediately after the matching system set.
+/// These can be useful for ordering, but you almost never want to add your systems to these sets.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+#[system_set(base)]
+pub enum FixedUpdateSet {
+    /// Runs before [`FixedUpdateSet::Update`] on every fixed step.
+    First,
+    /// The copy of [`apply_system_buffers`] that runs immediately after `First`.
+    FirstFlush,
+    /// Runs the fixed update logic. Systems are registered here by default.
+    Update,
+}
+
+impl FixedUpdateSet {
+    /// Sets up the base structure of [`CoreSchedule::FixedUpdate`].
+    ///
+    /// The sets defined in this enum are configured to run in order,
+    /// and a copy of [`apply_system_buffers`] is inserted into each `*Flush` set.
+    pub fn base_schedule() -> Schedule {
+        use FixedUpdateSet::*;
+        let mut schedule = Schedule::new();
+        schedule.set_default_base_set(Update);
+
+        // Create "stage-like" structure using buffer flushes + ordering
+        schedule.add_system(apply_system_buffers.in_base_set(FirstFlush));
+
+        schedule.configure_set(First.before(FirstFlush));
+        schedule.configure_set(Update.after(FirstFlush));
+
+        schedule
+    }
+}
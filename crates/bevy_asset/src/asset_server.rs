@@ -1,17 +1,31 @@
 use crate::{
+    meta_file_path,
     path::{AssetPath, AssetPathId, SourcePathId},
-    Asset, AssetIo, AssetIoError, AssetLifecycle, AssetLifecycleChannel, AssetLifecycleEvent,
-    AssetLoader, Assets, Handle, HandleId, HandleUntyped, LabelId, LoadContext, LoadState,
-    RefChange, RefChangeChannel, SourceInfo, SourceMeta,
+    saver::SaveRequest,
+    Asset, AssetEvent, AssetIo, AssetIoError, AssetLifecycle, AssetLifecycleChannel,
+    AssetLifecycleEvent, AssetLoader, AssetSettings, AssetSettingsError, Assets, Handle, HandleId,
+    HandleUntyped, LabelId, LoadContext, LoadState, MemoryAssetIo, RecursiveDependencyLoadState,
+    RefChange, RefChangeChannel, SourceInfo, SourceMeta, EMBEDDED_ASSET_SOURCE,
 };
 use anyhow::Result;
-use bevy_ecs::system::{Res, ResMut, Resource};
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    event::EventWriter,
+    system::{Res, ResMut, Resource},
+};
 use bevy_log::warn;
+use bevy_reflect::TypePath;
 use bevy_tasks::IoTaskPool;
-use bevy_utils::{Entry, HashMap, Uuid};
-use crossbeam_channel::TryRecvError;
+use bevy_utils::{Duration, Entry, HashMap, HashSet, Instant, Uuid};
+use crossbeam_channel::{Receiver, Sender, TryRecvError};
 use parking_lot::{Mutex, RwLock};
-use std::{path::Path, sync::Arc};
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
 use thiserror::Error;
 
 /// Errors that occur while loading assets with an `AssetServer`.
@@ -30,6 +44,14 @@ pub enum AssetServerError {
         extensions: Vec<String>,
     },
 
+    /// No asset source is registered with the name used in an asset path.
+    #[error("no asset source named `{0}`")]
+    MissingAssetSource(String),
+
+    /// The loader requested by the settings of an asset source was not found.
+    #[error("no `AssetLoader` found with the type path {0}")]
+    MissingAssetLoaderByName(String),
+
     /// The handle type does not match the type of the loaded asset.
     #[error("the given type does not match the type of the loaded asset")]
     IncorrectHandleType,
@@ -41,6 +63,19 @@ pub enum AssetServerError {
     /// Encountered an error while reading an asset from disk.
     #[error("encountered an error while reading an asset: {0}")]
     AssetIoError(#[from] AssetIoError),
+
+    /// Encountered an error while reading the `.meta` file of an asset.
+    #[error("encountered an error while reading the settings of an asset: {0}")]
+    AssetSettingsError(#[from] AssetSettingsError),
+
+    /// An asset was loaded with [`LoadContext::load_direct`] while loading itself.
+    #[error("the asset `{0}` depends on itself")]
+    CyclicLoad(AssetPath<'static>),
+
+    /// The loader of an asset loaded with [`LoadContext::load_direct`] didn't set the
+    /// requested asset.
+    #[error("the loader didn't set the asset `{0}`")]
+    MissingLabeledAsset(AssetPath<'static>),
 }
 
 fn format_missing_asset_ext(exts: &[String]) -> String {
@@ -62,17 +97,118 @@ pub(crate) struct AssetRefCounter {
     pub(crate) mark_unused_assets: Arc<Mutex<Vec<HandleId>>>,
 }
 
+/// An event sent when an asset source fails to load.
+///
+/// The error is also available with [`AssetServer::load_state_with_error`] until the asset
+/// source is loaded again, for example with [`AssetServer::retry`].
+#[derive(Clone, Debug)]
+pub struct AssetLoadFailedEvent {
+    /// The path of the asset that failed to load.
+    pub path: AssetPath<'static>,
+    /// The [type path] of the loader that failed, or [`None`] if the asset failed to load before
+    /// a loader ran.
+    ///
+    /// [type path]: bevy_reflect::TypePath::type_path
+    pub loader: Option<&'static str>,
+    /// Why the asset failed to load.
+    pub error: Arc<AssetServerError>,
+}
+
+struct LoadFailedChannel {
+    sender: Sender<AssetLoadFailedEvent>,
+    receiver: Receiver<AssetLoadFailedEvent>,
+}
+
+impl Default for LoadFailedChannel {
+    fn default() -> Self {
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        LoadFailedChannel { sender, receiver }
+    }
+}
+
+/// The default of [`AssetServer::set_watch_debounce`].
+pub const DEFAULT_WATCH_DEBOUNCE: Duration = Duration::from_millis(50);
+
+/// A change to a file of an asset source, reported by the asset I/O watching it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum PathChange {
+    Added,
+    Modified,
+    Removed,
+}
+
+/// An event sent when a file is added to or removed from a folder loaded with
+/// [`AssetServer::load_folder`], while watching for changes.
+#[derive(Debug)]
+pub enum AssetFolderEvent {
+    /// A file was added to a folder, and its assets are being loaded.
+    Added {
+        /// The path of the added file.
+        path: AssetPath<'static>,
+        /// A strong handle to the default asset of the file, to keep its assets loaded.
+        handle: HandleUntyped,
+    },
+    /// A file or folder was removed from a folder. Its assets stay loaded as long as they have
+    /// strong handles.
+    Removed {
+        /// The path of the removed file or folder.
+        path: AssetPath<'static>,
+    },
+}
+
+/// How to select the loader of an asset source, besides its extension.
+///
+/// The hints are given by each load request. They are kept in the [`SourceInfo`] of the source
+/// while it's loaded, so that reloads use the same loader, and are reset once its assets are
+/// freed.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct LoaderHints {
+    /// The type path of the loader to use.
+    pub(crate) loader: Option<String>,
+    /// The type of the default asset requested by a typed load.
+    pub(crate) asset_type: Option<Uuid>,
+}
+
+impl LoaderHints {
+    /// Creates the hints of a request loading `asset_path`, which may override its loader.
+    pub(crate) fn new(asset_path: &AssetPath<'_>) -> Self {
+        Self {
+            loader: asset_path.loader().map(String::from),
+            asset_type: None,
+        }
+    }
+
+    /// Creates the hints of a typed request loading a `T` from `asset_path`.
+    pub(crate) fn typed<T: Asset>(asset_path: &AssetPath<'_>) -> Self {
+        Self {
+            asset_type: asset_path.label().is_none().then_some(T::TYPE_UUID),
+            ..Self::new(asset_path)
+        }
+    }
+}
+
 /// Internal data for the asset server.
 ///
 /// [`AssetServer`] is the public API for interacting with the asset server.
 pub struct AssetServerInternal {
-    pub(crate) asset_io: Box<dyn AssetIo>,
+    pub(crate) asset_io: Arc<dyn AssetIo>,
+    pub(crate) sources: RwLock<HashMap<String, Arc<dyn AssetIo>>>,
     pub(crate) asset_ref_counter: AssetRefCounter,
     pub(crate) asset_sources: Arc<RwLock<HashMap<SourcePathId, SourceInfo>>>,
     pub(crate) asset_lifecycles: Arc<RwLock<HashMap<Uuid, Box<dyn AssetLifecycle>>>>,
     loaders: RwLock<Vec<Arc<dyn AssetLoader>>>,
-    extension_to_loader_index: RwLock<HashMap<String, usize>>,
+    extension_to_loader_indices: RwLock<HashMap<String, Vec<usize>>>,
+    type_path_to_loader_index: RwLock<HashMap<&'static str, usize>>,
     handle_to_path: Arc<RwLock<HashMap<HandleId, AssetPath<'static>>>>,
+    load_failed_events: LoadFailedChannel,
+    watch_debounce: RwLock<Duration>,
+    changed_paths: Mutex<HashMap<AssetPath<'static>, (PathChange, Instant)>>,
+    watched_folders: RwLock<HashSet<AssetPath<'static>>>,
+    load_dependants: RwLock<HashMap<SourcePathId, HashSet<AssetPath<'static>>>>,
+    /// Incremented whenever the load state of an asset source changes.
+    load_state_changes: AtomicU64,
+    save_requests: Mutex<HashMap<Uuid, Vec<SaveRequest>>>,
+    saved_asset_types: RwLock<HashSet<Uuid>>,
 }
 
 /// Loads assets from the filesystem in the background.
@@ -97,7 +233,7 @@ pub struct AssetServerInternal {
 /// use bevy_asset::{AssetServer, Handle};
 /// use bevy_ecs::prelude::{Commands, Res};
 ///
-/// # #[derive(Debug, bevy_reflect::TypeUuid)]
+/// # #[derive(Debug, bevy_reflect::TypePath, bevy_reflect::TypeUuid)]
 /// # #[uuid = "00000000-0000-0000-0000-000000000000"]
 /// # struct Image;
 ///
@@ -123,25 +259,104 @@ impl AssetServer {
     }
 
     /// Creates a new asset server with a boxed asset I/O.
+    ///
+    /// The asset I/O is used for the default asset source. The server also has an empty
+    /// [`EMBEDDED_ASSET_SOURCE`], and other asset sources can be added with
+    /// [`AssetServer::add_source`].
     pub fn with_boxed_io(asset_io: Box<dyn AssetIo>) -> Self {
+        let mut sources: HashMap<String, Arc<dyn AssetIo>> = HashMap::default();
+        sources.insert(
+            EMBEDDED_ASSET_SOURCE.to_string(),
+            Arc::new(MemoryAssetIo::default()),
+        );
         AssetServer {
             server: Arc::new(AssetServerInternal {
                 loaders: Default::default(),
-                extension_to_loader_index: Default::default(),
+                extension_to_loader_indices: Default::default(),
+                type_path_to_loader_index: Default::default(),
                 asset_sources: Default::default(),
                 asset_ref_counter: Default::default(),
                 handle_to_path: Default::default(),
+                load_failed_events: Default::default(),
+                watch_debounce: RwLock::new(DEFAULT_WATCH_DEBOUNCE),
+                changed_paths: Default::default(),
+                watched_folders: Default::default(),
+                load_dependants: Default::default(),
+                load_state_changes: Default::default(),
                 asset_lifecycles: Default::default(),
-                asset_io,
+                asset_io: asset_io.into(),
+                sources: RwLock::new(sources),
+                save_requests: Default::default(),
+                saved_asset_types: Default::default(),
             }),
         }
     }
 
-    /// Returns the associated asset I/O.
+    /// Returns the asset I/O of the default asset source.
     pub fn asset_io(&self) -> &dyn AssetIo {
         &*self.server.asset_io
     }
 
+    /// Adds a named asset source, whose assets are loaded with asset paths like
+    /// `name://path/to/file.ext`.
+    ///
+    /// If a source with the same name was already added, it is replaced.
+    pub fn add_source(&self, name: impl Into<String>, asset_io: Box<dyn AssetIo>) {
+        let name = name.into();
+        if self
+            .server
+            .sources
+            .write()
+            .insert(name.clone(), asset_io.into())
+            .is_some()
+        {
+            warn!("replacing the asset source `{}`", name);
+        }
+    }
+
+    /// Returns the asset I/O of the asset source with the given name, or of the default asset
+    /// source for [`None`].
+    pub fn source_io(&self, name: Option<&str>) -> Result<Arc<dyn AssetIo>, AssetServerError> {
+        match name {
+            None => Ok(self.server.asset_io.clone()),
+            Some(name) => self
+                .server
+                .sources
+                .read()
+                .get(name)
+                .cloned()
+                .ok_or_else(|| AssetServerError::MissingAssetSource(name.to_string())),
+        }
+    }
+
+    /// Returns the names of the named asset sources.
+    pub fn source_names(&self) -> Vec<String> {
+        self.server.sources.read().keys().cloned().collect()
+    }
+
+    /// Adds an asset compiled into the binary to the [`EMBEDDED_ASSET_SOURCE`], which can then be
+    /// loaded with the asset path `embedded://path`.
+    ///
+    /// ```
+    /// # use bevy_asset::AssetServer;
+    /// # fn embed(asset_server: &AssetServer) {
+    /// asset_server.add_embedded_asset("shaders/custom.wgsl", b"// shader source");
+    /// # }
+    /// ```
+    pub fn add_embedded_asset(&self, path: impl Into<PathBuf>, bytes: &'static [u8]) {
+        let sources = self.server.sources.read();
+        match sources
+            .get(EMBEDDED_ASSET_SOURCE)
+            .and_then(|asset_io| asset_io.downcast_ref::<MemoryAssetIo>())
+        {
+            Some(embedded) => embedded.insert(path, bytes),
+            None => warn!(
+                "the `{}` asset source was replaced, embedded assets can't be added to it",
+                EMBEDDED_ASSET_SOURCE
+            ),
+        }
+    }
+
     pub(crate) fn register_asset_type<T: Asset>(&self) -> Assets<T> {
         if self
             .server
@@ -158,20 +373,63 @@ impl AssetServer {
 
     /// Adds the provided asset loader to the server.
     ///
-    /// If `loader` has one or more supported extensions in conflict with loaders that came before
-    /// it, it will replace them.
+    /// If several loaders support the same extension, the last one added is used, unless the
+    /// asset is loaded with [`AssetServer::load`] and another loader's
+    /// [`asset_type_uuid`](AssetLoader::asset_type_uuid) matches the requested asset type. A
+    /// warning is logged when the loaders can't be told apart by their asset type.
+    ///
+    /// Loaders are referenced in `.meta` files and asset paths by their [type path], which is
+    /// stable across compiler versions.
+    ///
+    /// [type path]: TypePath::type_path
     pub fn add_loader<T>(&self, loader: T)
     where
-        T: AssetLoader,
+        T: AssetLoader + TypePath,
     {
+        let type_path = T::type_path();
         let mut loaders = self.server.loaders.write();
         let loader_index = loaders.len();
-        for extension in loader.extensions() {
-            self.server
-                .extension_to_loader_index
-                .write()
-                .insert(extension.to_string(), loader_index);
+        let mut conflicts: Vec<(usize, Vec<&str>)> = Vec::new();
+        {
+            let mut extension_to_loader_indices = self.server.extension_to_loader_indices.write();
+            for extension in loader.extensions() {
+                let indices = extension_to_loader_indices
+                    .entry(extension.to_string())
+                    .or_default();
+                for &index in indices.iter() {
+                    let other_type = loaders[index].asset_type_uuid();
+                    if other_type.is_some() && other_type != loader.asset_type_uuid() {
+                        continue;
+                    }
+                    match conflicts.iter_mut().find(|(other, _)| *other == index) {
+                        Some((_, extensions)) => extensions.push(extension),
+                        None => conflicts.push((index, vec![extension])),
+                    }
+                }
+                indices.push(loader_index);
+            }
+        }
+
+        let mut type_path_to_loader_index = self.server.type_path_to_loader_index.write();
+        for (index, extensions) in conflicts {
+            let other_name = type_path_to_loader_index
+                .iter()
+                .find(|(_, &other_index)| other_index == index)
+                .map(|(&name, _)| name);
+            // adding a loader of the same type again replaces it
+            if other_name == Some(type_path) {
+                continue;
+            }
+            warn!(
+                "`{}` and `{}` both load `{}` files into the same asset type; `{}` is used \
+                unless a `.meta` file or `AssetServer::load_with_loader` selects the other",
+                type_path,
+                other_name.unwrap_or("a replaced loader"),
+                extensions.join("`, `"),
+                type_path,
+            );
         }
+        type_path_to_loader_index.insert(type_path, loader_index);
         loaders.push(Arc::new(loader));
     }
 
@@ -187,22 +445,96 @@ impl AssetServer {
         HandleUntyped::strong(id.into(), sender)
     }
 
-    fn get_asset_loader(&self, extension: &str) -> Result<Arc<dyn AssetLoader>, AssetServerError> {
+    fn get_asset_loader(
+        &self,
+        extension: &str,
+        asset_type: Option<Uuid>,
+    ) -> Result<Arc<dyn AssetLoader>, AssetServerError> {
+        let indices = {
+            // scope map to drop lock as soon as possible
+            let map = self.server.extension_to_loader_indices.read();
+            map.get(extension).cloned().unwrap_or_default()
+        };
+        let loaders = self.server.loaders.read();
+        // prefer the last loader of the requested asset type, then the last loader added
+        asset_type
+            .and_then(|asset_type| {
+                indices
+                    .iter()
+                    .rev()
+                    .find(|&&index| loaders[index].asset_type_uuid() == Some(asset_type))
+            })
+            .or_else(|| indices.last())
+            .map(|&index| loaders[index].clone())
+            .ok_or_else(|| AssetServerError::MissingAssetLoader {
+                extensions: vec![extension.to_string()],
+            })
+    }
+
+    /// Gets the last added loader whose [`magic_bytes`](AssetLoader::magic_bytes) start `bytes`,
+    /// preferring loaders of the requested asset type.
+    fn get_magic_bytes_asset_loader(
+        &self,
+        bytes: &[u8],
+        asset_type: Option<Uuid>,
+    ) -> Option<Arc<dyn AssetLoader>> {
+        let loaders = self.server.loaders.read();
+        let matches = |loader: &&Arc<dyn AssetLoader>| {
+            loader
+                .magic_bytes()
+                .iter()
+                .any(|magic_bytes| bytes.starts_with(magic_bytes))
+        };
+        asset_type
+            .and_then(|asset_type| {
+                loaders
+                    .iter()
+                    .rev()
+                    .filter(matches)
+                    .find(|loader| loader.asset_type_uuid() == Some(asset_type))
+            })
+            .or_else(|| loaders.iter().rev().find(matches))
+            .cloned()
+    }
+
+    fn get_asset_loader_by_name(
+        &self,
+        type_path: &str,
+    ) -> Result<Arc<dyn AssetLoader>, AssetServerError> {
         let index = {
             // scope map to drop lock as soon as possible
-            let map = self.server.extension_to_loader_index.read();
-            map.get(extension).copied()
+            let map = self.server.type_path_to_loader_index.read();
+            map.get(type_path).copied()
         };
         index
             .map(|index| self.server.loaders.read()[index].clone())
-            .ok_or_else(|| AssetServerError::MissingAssetLoader {
-                extensions: vec![extension.to_string()],
+            .ok_or_else(|| AssetServerError::MissingAssetLoaderByName(type_path.to_string()))
+    }
+
+    /// Gets the type path of a loader added with [`AssetServer::add_loader`].
+    fn loader_type_path(&self, loader: &Arc<dyn AssetLoader>) -> Option<&'static str> {
+        let loaders = self.server.loaders.read();
+        self.server
+            .type_path_to_loader_index
+            .read()
+            .iter()
+            .find(|(_, &index)| {
+                Arc::as_ptr(&loaders[index]) as *const () == Arc::as_ptr(loader) as *const ()
             })
+            .map(|(&type_path, _)| type_path)
     }
 
     fn get_path_asset_loader<P: AsRef<Path>>(
         &self,
         path: P,
+    ) -> Result<Arc<dyn AssetLoader>, AssetServerError> {
+        self.get_typed_path_asset_loader(path, None)
+    }
+
+    fn get_typed_path_asset_loader<P: AsRef<Path>>(
+        &self,
+        path: P,
+        asset_type: Option<Uuid>,
     ) -> Result<Arc<dyn AssetLoader>, AssetServerError> {
         let s = path
             .as_ref()
@@ -221,7 +553,7 @@ impl AssetServer {
         while let Some(idx) = ext.find('.') {
             ext = &ext[idx + 1..];
             exts.push(ext);
-            if let Ok(loader) = self.get_asset_loader(ext) {
+            if let Ok(loader) = self.get_asset_loader(ext, asset_type) {
                 return Ok(loader);
             }
         }
@@ -252,6 +584,44 @@ impl AssetServer {
         }
     }
 
+    /// Gets the load state of the asset with the provided handle, along with the error it failed
+    /// to load with if the load state is [`LoadState::Failed`].
+    ///
+    /// Failures are also sent as [`AssetLoadFailedEvent`]s.
+    pub fn load_state_with_error<H: Into<HandleId>>(
+        &self,
+        handle: H,
+    ) -> (LoadState, Option<Arc<AssetServerError>>) {
+        match handle.into() {
+            HandleId::AssetPathId(id) => {
+                let asset_sources = self.server.asset_sources.read();
+                asset_sources
+                    .get(&id.source_path_id())
+                    .map_or((LoadState::NotLoaded, None), |info| {
+                        (info.load_state, info.error.clone())
+                    })
+            }
+            HandleId::Id(_, _) => (LoadState::NotLoaded, None),
+        }
+    }
+
+    /// Loads the asset with the provided handle again if it failed to load, and returns whether
+    /// it did.
+    ///
+    /// This is useful for assets that may load later on, such as assets of an asset source that
+    /// was added after they failed, or assets read over the network.
+    pub fn retry<H: Into<HandleId>>(&self, handle: H) -> bool {
+        let handle_id = handle.into();
+        if self.get_load_state(handle_id) != LoadState::Failed {
+            return false;
+        }
+        let Some(path) = self.get_handle_path(handle_id) else {
+            return false;
+        };
+        self.load_untracked(path, true, None);
+        true
+    }
+
     /// Gets the overall load state of a group of assets from the provided handles.
     ///
     /// This method will only return [`LoadState::Loaded`] if all assets in the
@@ -276,6 +646,95 @@ impl AssetServer {
         load_state
     }
 
+    /// Gets the load state of the asset with the provided handle and of all the assets it depends
+    /// on, recursively.
+    ///
+    /// Dependencies are the paths added with [`LoadedAsset::add_dependency`](crate::LoadedAsset::add_dependency)
+    /// to any asset of the same asset source. A failure anywhere in the dependency tree is reported
+    /// with the path of the asset that failed to load, even if other dependencies are still loading.
+    ///
+    /// Once this returns [`RecursiveDependencyLoadState::Loaded`] for an asset loaded from a path,
+    /// an [`AssetEvent::LoadedWithDependencies`](crate::AssetEvent::LoadedWithDependencies) is
+    /// sent for it.
+    pub fn get_recursive_dependency_load_state<H: Into<HandleId>>(
+        &self,
+        handle: H,
+    ) -> RecursiveDependencyLoadState {
+        let handle_id = handle.into();
+        let HandleId::AssetPathId(id) = handle_id else {
+            return RecursiveDependencyLoadState::NotLoaded;
+        };
+        let asset_sources = self.server.asset_sources.read();
+        let Some(source_info) = asset_sources.get(&id.source_path_id()) else {
+            return RecursiveDependencyLoadState::NotLoaded;
+        };
+        match source_info.load_state {
+            LoadState::NotLoaded | LoadState::Unloaded => {
+                return RecursiveDependencyLoadState::NotLoaded
+            }
+            LoadState::Failed => {
+                let path = self
+                    .server
+                    .handle_to_path
+                    .read()
+                    .get(&handle_id)
+                    .cloned()
+                    .unwrap_or_else(|| source_info.asset_path().to_owned());
+                return RecursiveDependencyLoadState::Failed(path);
+            }
+            LoadState::Loading | LoadState::Loaded => {}
+        }
+
+        let mut load_state = if source_info.load_state == LoadState::Loaded {
+            RecursiveDependencyLoadState::Loaded
+        } else {
+            RecursiveDependencyLoadState::Loading
+        };
+        let mut visited = HashSet::default();
+        visited.insert(id.source_path_id());
+        let mut pending = vec![source_info];
+        while let Some(source_info) = pending.pop() {
+            let dependencies = source_info
+                .meta
+                .iter()
+                .flat_map(|meta| &meta.assets)
+                .flat_map(|asset| &asset.dependencies);
+            for dependency in dependencies {
+                let source_path_id = dependency.get_id().source_path_id();
+                if !visited.insert(source_path_id) {
+                    continue;
+                }
+                // dependencies are queued when their dependent is loaded, so a dependency
+                // without a source info hasn't started loading yet
+                let Some(dependency_info) = asset_sources.get(&source_path_id) else {
+                    load_state = RecursiveDependencyLoadState::Loading;
+                    continue;
+                };
+                match dependency_info.load_state {
+                    LoadState::Failed => {
+                        return RecursiveDependencyLoadState::Failed(dependency.clone());
+                    }
+                    LoadState::Unloaded => return RecursiveDependencyLoadState::NotLoaded,
+                    LoadState::NotLoaded | LoadState::Loading => {
+                        load_state = RecursiveDependencyLoadState::Loading;
+                    }
+                    LoadState::Loaded => {}
+                }
+                pending.push(dependency_info);
+            }
+        }
+
+        load_state
+    }
+
+    /// Records that the load state of an asset source changed, so that the assets waiting for
+    /// their dependencies to load are checked again.
+    fn load_state_changed(&self) {
+        self.server
+            .load_state_changes
+            .fetch_add(1, Ordering::AcqRel);
+    }
+
     /// Queues an [`Asset`] at the provided relative path for asynchronous loading.
     ///
     /// The absolute path to the asset is `"ROOT/ASSET_FOLDER_NAME/path"`. Its extension is then
@@ -295,25 +754,60 @@ impl AssetServer {
     ///
     /// The asset is loaded asynchronously, and will generally not be available by the time
     /// this calls returns. Use [`AssetServer::get_load_state`] to determine when the asset is
-    /// effectively loaded and available in the [`Assets`] collection. The asset will always fail to
-    /// load if the provided path doesn't contain an extension.
+    /// effectively loaded and available in the [`Assets`] collection.
+    ///
+    /// If several loaders support the extension of the asset, the loader of the requested asset
+    /// type `T` is used, see [`AssetLoader::asset_type_uuid`]. Files whose extensions aren't
+    /// supported by any loader are loaded by the loader of their
+    /// [magic bytes](AssetLoader::magic_bytes), if there is one.
     ///
     /// [asset loader]: AssetLoader
     #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
     pub fn load<'a, T: Asset, P: Into<AssetPath<'a>>>(&self, path: P) -> Handle<T> {
-        self.load_untyped(path).typed()
+        let path = path.into();
+        let hints = LoaderHints::typed::<T>(&path);
+        let handle_id = self.load_untracked(path, false, Some(hints));
+        self.get_handle(handle_id)
     }
 
-    async fn load_async(
+    /// Queues the [`Asset`] at the provided path for loading with the loader `L`, instead of the
+    /// loader selected by its extension or its `.meta` file.
+    ///
+    /// The loader is also used when the asset is reloaded, until its assets are freed. It must
+    /// have been added with [`AssetServer::add_loader`], or the asset fails to load. The loader
+    /// can also be overridden in the asset path, see [`AssetPath::loader`].
+    ///
+    /// The loader isn't changed if the asset is already loaded or loading.
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
+    pub fn load_with_loader<'a, T: Asset, L: AssetLoader + TypePath, P: Into<AssetPath<'a>>>(
+        &self,
+        path: P,
+    ) -> Handle<T> {
+        let path = path.into();
+        let hints = LoaderHints {
+            loader: Some(L::type_path().to_string()),
+            ..LoaderHints::typed::<T>(&path)
+        };
+        let handle_id = self.load_untracked(path, false, Some(hints));
+        self.get_handle(handle_id)
+    }
+
+    /// Loads the asset source at `asset_path`, unless it's already loaded or loading and `force`
+    /// is `false`.
+    ///
+    /// The loader is selected with `hints`, or with the hints of the previous load request for
+    /// reloads, which don't give any.
+    pub(crate) async fn load_async(
         &self,
         asset_path: AssetPath<'_>,
         force: bool,
-    ) -> Result<AssetPathId, AssetServerError> {
+        hints: Option<LoaderHints>,
+    ) -> Result<AssetPathId, Arc<AssetServerError>> {
         let asset_path_id: AssetPathId = asset_path.get_id();
 
         // load metadata and update source info. this is done in a scope to ensure we release the
         // locks before loading
-        let version = {
+        let (version, hints) = {
             let mut asset_sources = self.server.asset_sources.write();
             let source_info = match asset_sources.entry(asset_path_id.source_path_id()) {
                 Entry::Occupied(entry) => entry.into_mut(),
@@ -322,8 +816,11 @@ impl AssetServer {
                     committed_assets: Default::default(),
                     load_state: LoadState::NotLoaded,
                     meta: None,
+                    source: asset_path.source().map(String::from),
                     path: asset_path.path().to_owned(),
                     version: 0,
+                    error: None,
+                    loader_hints: LoaderHints::default(),
                 }),
             };
 
@@ -338,53 +835,60 @@ impl AssetServer {
             }
 
             source_info.load_state = LoadState::Loading;
+            self.load_state_changed();
             source_info.committed_assets.clear();
             source_info.version += 1;
             source_info.meta = None;
-            source_info.version
+            source_info.error = None;
+            if let Some(hints) = hints {
+                source_info.loader_hints = hints;
+            }
+            (source_info.version, source_info.loader_hints.clone())
         };
 
-        let set_asset_failed = || {
+        let set_asset_failed = |error: AssetServerError, loader: Option<&'static str>| {
+            let error = Arc::new(error);
             let mut asset_sources = self.server.asset_sources.write();
             let source_info = asset_sources
                 .get_mut(&asset_path_id.source_path_id())
                 .expect("`AssetSource` should exist at this point.");
             source_info.load_state = LoadState::Failed;
+            self.load_state_changed();
+            source_info.error = Some(error.clone());
+            self.server
+                .load_failed_events
+                .sender
+                .send(AssetLoadFailedEvent {
+                    path: asset_path.to_owned(),
+                    loader,
+                    error: error.clone(),
+                })
+                .unwrap();
+            error
         };
 
-        // get the according asset loader
-        let asset_loader = match self.get_path_asset_loader(asset_path.path()) {
-            Ok(loader) => loader,
-            Err(err) => {
-                set_asset_failed();
-                return Err(err);
-            }
-        };
-
-        // load the asset bytes
-        let bytes = match self.asset_io().load_path(asset_path.path()).await {
-            Ok(bytes) => bytes,
-            Err(err) => {
-                set_asset_failed();
-                return Err(AssetServerError::AssetIoError(err));
-            }
-        };
+        let (asset_io, settings, asset_loader, bytes) =
+            match self.read_source(&asset_path, &hints).await {
+                Ok(source) => source,
+                Err(err) => return Err(set_asset_failed(err, None)),
+            };
 
         // load the asset source using the corresponding AssetLoader
         let mut load_context = LoadContext::new(
+            self,
+            asset_path.source(),
             asset_path.path(),
-            &self.server.asset_ref_counter.channel,
-            self.asset_io(),
+            &*asset_io,
             version,
+            settings,
         );
 
-        if let Err(err) = asset_loader
-            .load(&bytes, &mut load_context)
-            .await
-            .map_err(AssetServerError::AssetLoaderError)
-        {
-            set_asset_failed();
-            return Err(err);
+        if let Err(err) = asset_loader.load(&bytes, &mut load_context).await {
+            let loader = self.loader_type_path(&asset_loader);
+            return Err(set_asset_failed(
+                AssetServerError::AssetLoaderError(err),
+                loader,
+            ));
         }
 
         // if version has changed since we loaded and grabbed a lock, return. there is a newer
@@ -400,6 +904,7 @@ impl AssetServer {
         // if all assets have been committed already (aka there were 0), set state to "Loaded"
         if source_info.is_loaded() {
             source_info.load_state = LoadState::Loaded;
+            self.load_state_changed();
         }
 
         // reset relevant SourceInfo fields
@@ -417,40 +922,141 @@ impl AssetServer {
             let type_uuid = loaded_asset.value.as_ref().unwrap().type_uuid();
             source_info.asset_types.insert(label_id, type_uuid);
             for dependency in &loaded_asset.dependencies {
-                self.load_untracked(dependency.clone(), false);
+                self.load_untracked(
+                    dependency.clone(),
+                    false,
+                    Some(LoaderHints::new(dependency)),
+                );
             }
         }
 
-        self.asset_io()
-            .watch_path_for_changes(asset_path.path())
-            .unwrap();
+        asset_io.watch_path_for_changes(asset_path.path()).unwrap();
+        if load_context.settings().as_ron().is_some() {
+            asset_io
+                .watch_path_for_changes(&meta_file_path(asset_path.path()))
+                .unwrap();
+        }
+        drop(asset_sources);
+        self.set_load_dependencies(&asset_path, &load_context.load_dependencies);
         self.create_assets_in_load_context(&mut load_context);
         Ok(asset_path_id)
     }
 
+    /// Reads the asset source at `asset_path`, along with its settings and the loader it is
+    /// loaded with.
+    ///
+    /// The loader is selected, in order, by a loader override, the `.meta` file, the extension and
+    /// the requested asset type, and the magic bytes of the file.
+    pub(crate) async fn read_source(
+        &self,
+        asset_path: &AssetPath<'_>,
+        hints: &LoaderHints,
+    ) -> Result<
+        (
+            Arc<dyn AssetIo>,
+            AssetSettings,
+            Arc<dyn AssetLoader>,
+            Vec<u8>,
+        ),
+        AssetServerError,
+    > {
+        // get the asset I/O of the asset source
+        let asset_io = self.source_io(asset_path.source())?;
+
+        // load the settings of the asset source, if it has any
+        let settings = Self::load_settings(&*asset_io, asset_path.path()).await?;
+
+        // get the according asset loader
+        let asset_loader = match hints.loader.as_deref().or(settings.loader()) {
+            Some(type_path) => self.get_asset_loader_by_name(type_path),
+            None => self.get_typed_path_asset_loader(asset_path.path(), hints.asset_type),
+        };
+
+        // load the asset bytes, unless the loader streams them
+        let (asset_loader, bytes) = match asset_loader {
+            Ok(asset_loader) if asset_loader.streams_source() => (asset_loader, Vec::new()),
+            Err(err @ AssetServerError::MissingAssetLoader { .. }) => {
+                let bytes = asset_io.load_path(asset_path.path()).await?;
+                let asset_loader = self
+                    .get_magic_bytes_asset_loader(&bytes, hints.asset_type)
+                    .ok_or(err)?;
+                (asset_loader, bytes)
+            }
+            asset_loader => (asset_loader?, asset_io.load_path(asset_path.path()).await?),
+        };
+        Ok((asset_io, settings, asset_loader, bytes))
+    }
+
+    /// Records the assets loaded with [`LoadContext::load_direct`] while loading the asset source
+    /// at `asset_path`, so that it is reloaded when one of them changes.
+    fn set_load_dependencies(
+        &self,
+        asset_path: &AssetPath<'_>,
+        load_dependencies: &[AssetPath<'static>],
+    ) {
+        let dependant = AssetPath::new_ref(asset_path.path(), None)
+            .with_source_opt(asset_path.source())
+            .to_owned();
+        let mut load_dependants = self.server.load_dependants.write();
+        for dependants in load_dependants.values_mut() {
+            dependants.remove(&dependant);
+        }
+        load_dependants.retain(|_, dependants| !dependants.is_empty());
+        for dependency in load_dependencies {
+            load_dependants
+                .entry(dependency.get_id().source_path_id())
+                .or_default()
+                .insert(dependant.clone());
+        }
+    }
+
+    /// Reads the settings of the asset source at `path` from its `.meta` file. Sources without a
+    /// `.meta` file get the default settings.
+    async fn load_settings(
+        asset_io: &dyn AssetIo,
+        path: &Path,
+    ) -> Result<AssetSettings, AssetServerError> {
+        match asset_io.load_path(&meta_file_path(path)).await {
+            Ok(bytes) => Ok(AssetSettings::from_bytes(&bytes)?),
+            Err(AssetIoError::NotFound(_)) => Ok(AssetSettings::default()),
+            Err(AssetIoError::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+                Ok(AssetSettings::default())
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
     /// Queues the [`Asset`] at the provided path for loading and returns an untyped handle.
     ///
     /// See [`load`](AssetServer::load).
     #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
     pub fn load_untyped<'a, P: Into<AssetPath<'a>>>(&self, path: P) -> HandleUntyped {
-        let handle_id = self.load_untracked(path.into(), false);
+        let path = path.into();
+        let hints = LoaderHints::new(&path);
+        let handle_id = self.load_untracked(path, false, Some(hints));
         self.get_handle_untyped(handle_id)
     }
 
     /// Force an [`Asset`] to be reloaded.
     ///
     /// This is useful for custom hot-reloading or for supporting `watch_for_changes`
-    /// in custom [`AssetIo`] implementations.
+    /// in custom [`AssetIo`] implementations. The asset is reloaded with the loader it was loaded
+    /// with.
     pub fn reload_asset<'a, P: Into<AssetPath<'a>>>(&self, path: P) {
-        self.load_untracked(path.into(), true);
+        self.load_untracked(path.into(), true, None);
     }
 
-    pub(crate) fn load_untracked(&self, asset_path: AssetPath<'_>, force: bool) -> HandleId {
+    pub(crate) fn load_untracked(
+        &self,
+        asset_path: AssetPath<'_>,
+        force: bool,
+        hints: Option<LoaderHints>,
+    ) -> HandleId {
         let server = self.clone();
         let owned_path = asset_path.to_owned();
         IoTaskPool::get()
             .spawn(async move {
-                if let Err(err) = server.load_async(owned_path, force).await {
+                if let Err(err) = server.load_async(owned_path, force, hints).await {
                     warn!("{}", err);
                 }
             })
@@ -466,8 +1072,192 @@ impl AssetServer {
         asset_path.into()
     }
 
+    /// Queues the asset of `handle` to be saved to `path`, which can be in a named asset source.
+    ///
+    /// The asset is serialized by the [`AssetSaver`](crate::AssetSaver) matching the extension of
+    /// `path` at the end of the frame, then written to the asset source in the background. An
+    /// [`AssetSaveEvent`](crate::AssetSaveEvent) is sent once it is saved, or if it couldn't be saved.
+    ///
+    /// Savers are registered with [`AddAsset::add_asset_saver`](crate::AddAsset::add_asset_saver).
+    pub fn save<'a, T: Asset>(&self, handle: &Handle<T>, path: impl Into<AssetPath<'a>>) {
+        let path = path.into().to_owned();
+        if !self.server.saved_asset_types.read().contains(&T::TYPE_UUID) {
+            warn!(
+                "can't save {} to {}: no `AssetSaver` was added for this asset type",
+                std::any::type_name::<T>(),
+                path
+            );
+            return;
+        }
+        self.server
+            .save_requests
+            .lock()
+            .entry(T::TYPE_UUID)
+            .or_default()
+            .push(SaveRequest {
+                id: handle.id(),
+                path,
+            });
+    }
+
+    /// Marks assets of the type with the given UUID as saveable with [`AssetServer::save`].
+    pub(crate) fn register_saved_asset_type(&self, type_uuid: Uuid) {
+        self.server.saved_asset_types.write().insert(type_uuid);
+    }
+
+    pub(crate) fn take_save_requests(&self, type_uuid: Uuid) -> Vec<SaveRequest> {
+        self.server
+            .save_requests
+            .lock()
+            .remove(&type_uuid)
+            .unwrap_or_default()
+    }
+
+    /// Sets how long a changed file must stay unchanged before its assets are reloaded, when
+    /// watching for changes.
+    ///
+    /// Editors often write a file several times when saving it, so this avoids reloading assets
+    /// from partially written files.
+    pub fn set_watch_debounce(&self, debounce: Duration) {
+        *self.server.watch_debounce.write() = debounce;
+    }
+
+    /// Queues a change to the file at `path` in the asset source `source`, reported by the asset
+    /// I/O watching it.
+    ///
+    /// A changed `.meta` file reloads the asset it holds the settings of.
+    pub(crate) fn queue_path_change(&self, source: Option<&str>, path: &Path, change: PathChange) {
+        let mut path = path.to_owned();
+        let mut change = change;
+        if path.extension().and_then(|ext| ext.to_str()) == Some(crate::META_FILE_EXTENSION) {
+            path.set_extension("");
+            change = PathChange::Modified;
+        }
+        let asset_path = AssetPath::from(path).with_source_opt(source).to_owned();
+        let now = Instant::now();
+        self.server
+            .changed_paths
+            .lock()
+            .entry(asset_path)
+            .and_modify(|(pending, time)| {
+                *pending = match (*pending, change) {
+                    // a file saved by replacing it was modified
+                    (PathChange::Removed, PathChange::Added) => PathChange::Modified,
+                    (PathChange::Added, PathChange::Modified) => PathChange::Added,
+                    (_, change) => change,
+                };
+                *time = now;
+            })
+            .or_insert((change, now));
+    }
+
+    /// Reloads the assets of the files that haven't changed for the watch debounce duration, along
+    /// with the assets depending on them. Returns the events of the files added to or removed
+    /// from folders loaded with [`AssetServer::load_folder`].
+    pub(crate) fn process_path_changes(&self) -> Vec<AssetFolderEvent> {
+        let debounce = *self.server.watch_debounce.read();
+        let now = Instant::now();
+        let mut changes = Vec::new();
+        self.server
+            .changed_paths
+            .lock()
+            .retain(|path, &mut (change, time)| {
+                if now.duration_since(time) < debounce {
+                    return true;
+                }
+                changes.push((path.clone(), change));
+                false
+            });
+
+        let mut events = Vec::new();
+        let mut reloaded = HashSet::default();
+        for (path, change) in changes {
+            let known = self
+                .server
+                .asset_sources
+                .read()
+                .contains_key(&path.get_id().source_path_id());
+            match change {
+                PathChange::Modified => self.reload_with_dependants(path, &mut reloaded),
+                PathChange::Added if known => self.reload_with_dependants(path, &mut reloaded),
+                PathChange::Added if self.is_in_watched_folder(&path) => {
+                    events.extend(self.load_added_path(&path));
+                }
+                PathChange::Removed if self.is_in_watched_folder(&path) => {
+                    events.push(AssetFolderEvent::Removed { path });
+                }
+                PathChange::Added | PathChange::Removed => {}
+            }
+        }
+        events
+    }
+
+    /// Reloads the asset source at `path` and, recursively, the asset sources depending on it.
+    fn reload_with_dependants(
+        &self,
+        path: AssetPath<'static>,
+        reloaded: &mut HashSet<SourcePathId>,
+    ) {
+        let mut pending = vec![path];
+        while let Some(path) = pending.pop() {
+            let source_path_id = path.get_id().source_path_id();
+            if !reloaded.insert(source_path_id) {
+                continue;
+            }
+            self.load_untracked(path, true, None);
+
+            // dependants loading the asset with `LoadContext::load_direct`
+            if let Some(dependants) = self.server.load_dependants.read().get(&source_path_id) {
+                pending.extend(dependants.iter().cloned());
+            }
+            // dependants with a dependency added with `LoadedAsset::add_dependency`
+            let asset_sources = self.server.asset_sources.read();
+            for source_info in asset_sources.values() {
+                let depends_on_path = source_info
+                    .meta
+                    .iter()
+                    .flat_map(|meta| &meta.assets)
+                    .flat_map(|asset| &asset.dependencies)
+                    .any(|dependency| dependency.get_id().source_path_id() == source_path_id);
+                if depends_on_path {
+                    pending.push(source_info.asset_path().to_owned());
+                }
+            }
+        }
+    }
+
+    fn is_in_watched_folder(&self, path: &AssetPath) -> bool {
+        self.server.watched_folders.read().iter().any(|folder| {
+            folder.source() == path.source() && path.path().starts_with(folder.path())
+        })
+    }
+
+    /// Loads the assets of a file or folder added to a watched folder.
+    fn load_added_path(&self, path: &AssetPath) -> Vec<AssetFolderEvent> {
+        let Ok(asset_io) = self.source_io(path.source()) else {
+            return Vec::new();
+        };
+        let handles = if asset_io.is_dir(path.path()) {
+            self.load_folder_internal(&*asset_io, path.source(), path.path())
+                .unwrap_or_default()
+        } else if self.get_path_asset_loader(path.path()).is_ok() {
+            vec![self.load_untyped(path.clone())]
+        } else {
+            Vec::new()
+        };
+        handles
+            .into_iter()
+            .filter_map(|handle| {
+                let path = self.get_handle_path(&handle)?.to_owned();
+                Some(AssetFolderEvent::Added { path, handle })
+            })
+            .collect()
+    }
+
     /// Loads assets from the specified folder recursively.
     ///
+    /// The folder can be in a named asset source, for example `mods://ships`.
+    ///
     /// # Errors
     ///
     /// - If the provided path is not a directory, it will fail with
@@ -475,27 +1265,44 @@ impl AssetServer {
     /// - If something unexpected happened while loading an asset, other
     /// [`AssetServerError`]s may be returned.
     #[must_use = "not using the returned strong handles may result in the unexpected release of the assets"]
-    pub fn load_folder<P: AsRef<Path>>(
+    pub fn load_folder<'a, P: Into<AssetPath<'a>>>(
         &self,
         path: P,
     ) -> Result<Vec<HandleUntyped>, AssetServerError> {
-        let path = path.as_ref();
-        if !self.asset_io().is_dir(path) {
+        let path = path.into();
+        let asset_io = self.source_io(path.source())?;
+        let handles = self.load_folder_internal(&*asset_io, path.source(), path.path())?;
+        asset_io.watch_path_for_changes(path.path())?;
+        self.server.watched_folders.write().insert(
+            AssetPath::new_ref(path.path(), None)
+                .with_source_opt(path.source())
+                .to_owned(),
+        );
+        Ok(handles)
+    }
+
+    fn load_folder_internal(
+        &self,
+        asset_io: &dyn AssetIo,
+        source: Option<&str>,
+        path: &Path,
+    ) -> Result<Vec<HandleUntyped>, AssetServerError> {
+        if !asset_io.is_dir(path) {
             return Err(AssetServerError::AssetFolderNotADirectory(
                 path.to_str().unwrap().to_string(),
             ));
         }
 
         let mut handles = Vec::new();
-        for child_path in self.asset_io().read_directory(path.as_ref())? {
-            if self.asset_io().is_dir(&child_path) {
-                handles.extend(self.load_folder(&child_path)?);
+        for child_path in asset_io.read_directory(path)? {
+            if asset_io.is_dir(&child_path) {
+                handles.extend(self.load_folder_internal(asset_io, source, &child_path)?);
             } else {
                 if self.get_path_asset_loader(&child_path).is_err() {
                     continue;
                 }
-                let handle =
-                    self.load_untyped(child_path.to_str().expect("Path should be a valid string."));
+                let handle = self
+                    .load_untyped(AssetPath::from(child_path.as_path()).with_source_opt(source));
                 handles.push(handle);
             }
         }
@@ -567,7 +1374,8 @@ impl AssetServer {
                 .expect("Asset should exist at this point.");
             if let Some(asset_lifecycle) = asset_lifecycles.get(&asset_value.type_uuid()) {
                 let asset_path =
-                    AssetPath::new_ref(load_context.path, label.as_ref().map(|l| l.as_str()));
+                    AssetPath::new_ref(load_context.path, label.as_ref().map(|l| l.as_str()))
+                        .with_source_opt(load_context.source);
                 asset_lifecycle.create_asset(asset_path.into(), asset_value, load_context.version);
             } else {
                 panic!(
@@ -587,6 +1395,7 @@ impl AssetServer {
         let asset_lifecycles = self.server.asset_lifecycles.read();
         let asset_lifecycle = asset_lifecycles.get(&T::TYPE_UUID).unwrap();
         let mut asset_sources_guard = None;
+        let mut added_pending_dependency_loads = false;
         let channel = asset_lifecycle
             .downcast_ref::<AssetLifecycleChannel<T>>()
             .unwrap();
@@ -603,12 +1412,17 @@ impl AssetServer {
                                 source_info.committed_assets.insert(id.label_id());
                                 if source_info.is_loaded() {
                                     source_info.load_state = LoadState::Loaded;
+                                    self.load_state_changed();
                                 }
                             }
                         }
                     }
 
                     assets.set_untracked(result.id, *result.asset);
+                    if let HandleId::AssetPathId(_) = result.id {
+                        added_pending_dependency_loads |=
+                            assets.pending_dependency_loads.insert(result.id);
+                    }
                 }
                 Ok(AssetLifecycleEvent::Free(handle_id)) => {
                     if let HandleId::AssetPathId(id) = handle_id {
@@ -616,10 +1430,15 @@ impl AssetServer {
                             .get_or_insert_with(|| self.server.asset_sources.write());
                         if let Some(source_info) = asset_sources.get_mut(&id.source_path_id()) {
                             source_info.committed_assets.remove(&id.label_id());
+                            if source_info.committed_assets.is_empty() {
+                                source_info.loader_hints = LoaderHints::default();
+                            }
                             source_info.load_state = LoadState::Unloaded;
+                            self.load_state_changed();
                         }
                     }
                     assets.remove(handle_id);
+                    assets.pending_dependency_loads.remove(&handle_id);
                 }
                 Err(TryRecvError::Empty) => {
                     break;
@@ -627,6 +1446,40 @@ impl AssetServer {
                 Err(TryRecvError::Disconnected) => panic!("AssetChannel disconnected."),
             }
         }
+        drop(asset_sources_guard);
+
+        // checking the dependencies of pending assets doesn't change the asset storage, so
+        // don't trigger change detection for it
+        let assets = assets.bypass_change_detection();
+        if assets.pending_dependency_loads.is_empty() {
+            return;
+        }
+        // the dependency trees only need to be walked again if a load state changed since the
+        // last check
+        let load_state_changes = self.server.load_state_changes.load(Ordering::Acquire);
+        if !added_pending_dependency_loads
+            && load_state_changes == assets.checked_load_state_changes
+        {
+            return;
+        }
+        assets.checked_load_state_changes = load_state_changes;
+        let mut loaded = Vec::new();
+        assets.pending_dependency_loads.retain(|&handle_id| {
+            match self.get_recursive_dependency_load_state(handle_id) {
+                RecursiveDependencyLoadState::Loading => true,
+                RecursiveDependencyLoadState::Loaded => {
+                    loaded.push(handle_id);
+                    false
+                }
+                RecursiveDependencyLoadState::NotLoaded
+                | RecursiveDependencyLoadState::Failed(_) => false,
+            }
+        });
+        for handle_id in loaded {
+            assets.send_event(AssetEvent::LoadedWithDependencies {
+                handle: Handle::weak(handle_id),
+            });
+        }
     }
 }
 
@@ -640,19 +1493,40 @@ pub fn free_unused_assets_system(asset_server: Res<AssetServer>) {
     free_unused_assets_system_impl(&asset_server);
 }
 
+/// A system that reloads the assets of the files changed in watched asset sources, along with the
+/// assets depending on them, and sends the [`AssetFolderEvent`]s of watched folders.
+pub fn reload_changed_assets_system(
+    asset_server: Res<AssetServer>,
+    mut events: EventWriter<AssetFolderEvent>,
+) {
+    if asset_server.server.changed_paths.lock().is_empty() {
+        return;
+    }
+    events.send_batch(asset_server.process_path_changes());
+}
+
+/// A system that sends the [`AssetLoadFailedEvent`]s of the assets that failed to load.
+pub fn asset_load_failed_event_system(
+    asset_server: Res<AssetServer>,
+    mut events: EventWriter<AssetLoadFailedEvent>,
+) {
+    events.send_batch(asset_server.server.load_failed_events.receiver.try_iter());
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::{loader::LoadedAsset, update_asset_storage_system};
     use bevy_app::App;
     use bevy_ecs::prelude::*;
-    use bevy_reflect::TypeUuid;
+    use bevy_reflect::{TypePath, TypeUuid};
     use bevy_utils::BoxedFuture;
 
-    #[derive(Debug, TypeUuid)]
+    #[derive(Debug, TypePath, TypeUuid)]
     #[uuid = "a5189b72-0572-4290-a2e0-96f73a491c44"]
     struct PngAsset;
 
+    #[derive(TypePath)]
     struct FakePngLoader;
     impl AssetLoader for FakePngLoader {
         fn load<'a>(
@@ -667,8 +1541,13 @@ mod test {
         fn extensions(&self) -> &[&str] {
             &["png"]
         }
+
+        fn asset_type_uuid(&self) -> Option<Uuid> {
+            Some(PngAsset::TYPE_UUID)
+        }
     }
 
+    #[derive(TypePath)]
     struct FailingLoader;
     impl AssetLoader for FailingLoader {
         fn load<'a>(
@@ -684,6 +1563,7 @@ mod test {
         }
     }
 
+    #[derive(TypePath)]
     struct FakeMultipleDotLoader;
     impl AssetLoader for FakeMultipleDotLoader {
         fn load<'a>(
@@ -699,6 +1579,140 @@ mod test {
         }
     }
 
+    /// Loads a [`PngAsset`] depending on the asset paths listed in the file, one per line.
+    #[derive(TypePath)]
+    struct DependenciesLoader;
+    impl AssetLoader for DependenciesLoader {
+        fn load<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                let dependencies = std::str::from_utf8(bytes)?
+                    .lines()
+                    .map(|path| AssetPath::from(path).to_owned())
+                    .collect();
+                ctx.set_default_asset(LoadedAsset::new(PngAsset).with_dependencies(dependencies));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["deps"]
+        }
+    }
+
+    #[derive(Debug, TypePath, TypeUuid)]
+    #[uuid = "0d9b3a4e-8b52-4d4b-9a38-5f5a3c0f1d27"]
+    struct TextAsset(String);
+
+    #[derive(TypePath)]
+    struct TextLoader;
+    impl AssetLoader for TextLoader {
+        fn load<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                let text = std::str::from_utf8(bytes)?.to_string();
+                ctx.set_default_asset(LoadedAsset::new(TextAsset(text)));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["txt"]
+        }
+
+        fn asset_type_uuid(&self) -> Option<Uuid> {
+            Some(TextAsset::TYPE_UUID)
+        }
+
+        fn magic_bytes(&self) -> &[&[u8]] {
+            &[b"TXT:"]
+        }
+    }
+
+    /// Loads `.png` and `.txt` files as an uppercased [`TextAsset`].
+    #[derive(TypePath)]
+    struct ShoutLoader;
+    impl AssetLoader for ShoutLoader {
+        fn load<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                let text = std::str::from_utf8(bytes)?.to_uppercase();
+                ctx.set_default_asset(LoadedAsset::new(TextAsset(text)));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["png", "txt"]
+        }
+
+        fn asset_type_uuid(&self) -> Option<Uuid> {
+            Some(TextAsset::TYPE_UUID)
+        }
+    }
+
+    /// Loads a [`TextAsset`] joining the texts at the asset paths listed in the file, one per
+    /// line, which are loaded with [`LoadContext::load_direct`].
+    #[derive(TypePath)]
+    struct JoinLoader;
+    impl AssetLoader for JoinLoader {
+        fn load<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                let mut texts = Vec::new();
+                for path in std::str::from_utf8(bytes)?.lines() {
+                    texts.push(ctx.load_direct::<TextAsset>(path).await?.0);
+                }
+                ctx.set_default_asset(LoadedAsset::new(TextAsset(texts.join(" "))));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["join"]
+        }
+    }
+
+    /// Loads a [`TextAsset`] from the first four bytes of the file, read without loading the
+    /// whole file.
+    #[derive(TypePath)]
+    struct HeaderLoader;
+    impl AssetLoader for HeaderLoader {
+        fn load<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                assert!(bytes.is_empty());
+                let header = ctx.read_asset_range(ctx.path(), 0..4).await?;
+                let text = String::from_utf8(header)?;
+                ctx.set_default_asset(LoadedAsset::new(TextAsset(text)));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["pak"]
+        }
+
+        fn streams_source(&self) -> bool {
+            true
+        }
+    }
+
     fn setup(asset_path: impl AsRef<Path>) -> AssetServer {
         use crate::FileAssetIo;
         IoTaskPool::init(Default::default);
@@ -797,11 +1811,11 @@ mod test {
         let path: AssetPath = "file.not-a-real-extension".into();
         let handle = asset_server.get_handle_untyped(path.get_id());
 
-        let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
+        let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true, None))
             .unwrap_err();
-        assert!(match err {
+        assert!(match &*err {
             AssetServerError::MissingAssetLoader { extensions } => {
-                extensions == ["not-a-real-extension"]
+                *extensions == ["not-a-real-extension"]
             }
             _ => false,
         });
@@ -817,9 +1831,9 @@ mod test {
         let path: AssetPath = "an/invalid/path.png".into();
         let handle = asset_server.get_handle_untyped(path.get_id());
 
-        let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
+        let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true, None))
             .unwrap_err();
-        assert!(matches!(err, AssetServerError::AssetIoError(_)));
+        assert!(matches!(*err, AssetServerError::AssetIoError(_)));
 
         assert_eq!(asset_server.get_load_state(handle), LoadState::Failed);
     }
@@ -833,13 +1847,143 @@ mod test {
         let path: AssetPath = "fake.fail".into();
         let handle = asset_server.get_handle_untyped(path.get_id());
 
-        let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
+        let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true, None))
             .unwrap_err();
-        assert!(matches!(err, AssetServerError::AssetLoaderError(_)));
+        assert!(matches!(*err, AssetServerError::AssetLoaderError(_)));
 
         assert_eq!(asset_server.get_load_state(handle), LoadState::Failed);
     }
 
+    #[derive(serde::Deserialize, Default)]
+    struct FailSettings {
+        fail: bool,
+    }
+
+    #[derive(TypePath)]
+    struct SettingsLoader;
+    impl AssetLoader for SettingsLoader {
+        fn load<'a>(
+            &'a self,
+            _: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                if ctx.loader_settings::<FailSettings>()?.fail {
+                    anyhow::bail!("failed");
+                }
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["settings"]
+        }
+    }
+
+    #[test]
+    fn test_meta_settings() {
+        let dir = create_dir_and_file("fake.settings");
+        std::fs::write(dir.path().join("other.fail"), []).unwrap();
+        std::fs::write(dir.path().join("defaulted.settings"), []).unwrap();
+        std::fs::write(
+            dir.path().join("fake.settings.meta"),
+            "(loader_settings: (fail: true))",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("other.fail.meta"),
+            format!("(loader: {:?})", SettingsLoader::type_path()),
+        )
+        .unwrap();
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(FailingLoader);
+        asset_server.add_loader(SettingsLoader);
+
+        // settings are passed to the loader
+        let err = futures_lite::future::block_on(asset_server.load_async(
+            "fake.settings".into(),
+            true,
+            None,
+        ))
+        .unwrap_err();
+        assert!(matches!(*err, AssetServerError::AssetLoaderError(_)));
+
+        // sources without a `.meta` file use default settings
+        futures_lite::future::block_on(asset_server.load_async(
+            "defaulted.settings".into(),
+            true,
+            None,
+        ))
+        .unwrap();
+
+        // the loader can be overridden
+        futures_lite::future::block_on(asset_server.load_async("other.fail".into(), true, None))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_named_sources() {
+        let dir = create_dir_and_file("fake.png");
+        let asset_server = setup(".");
+        asset_server.add_loader(FakePngLoader);
+        let _assets = asset_server.register_asset_type::<PngAsset>();
+        asset_server.add_source("mods", Box::new(crate::FileAssetIo::new(dir.path(), false)));
+        asset_server.add_embedded_asset("shaders/embedded.png", b"");
+
+        futures_lite::future::block_on(asset_server.load_async(
+            "mods://fake.png".into(),
+            true,
+            None,
+        ))
+        .unwrap();
+        futures_lite::future::block_on(asset_server.load_async(
+            "embedded://shaders/embedded.png".into(),
+            true,
+            None,
+        ))
+        .unwrap();
+
+        // the default source doesn't have the asset
+        let err =
+            futures_lite::future::block_on(asset_server.load_async("fake.png".into(), true, None))
+                .unwrap_err();
+        assert!(matches!(*err, AssetServerError::AssetIoError(_)));
+
+        let err = futures_lite::future::block_on(asset_server.load_async(
+            "missing://fake.png".into(),
+            true,
+            None,
+        ))
+        .unwrap_err();
+        assert!(matches!(&*err, AssetServerError::MissingAssetSource(name) if name == "missing"));
+        assert_eq!(
+            asset_server.get_load_state(AssetPath::from("missing://fake.png").get_id()),
+            LoadState::Failed
+        );
+    }
+
+    #[test]
+    fn test_load_folder_from_source() {
+        let asset_server = setup(".");
+        asset_server.add_loader(FakePngLoader);
+        asset_server.add_embedded_asset("textures/a.png", b"");
+        asset_server.add_embedded_asset("textures/nested/b.png", b"");
+
+        let handles = asset_server.load_folder("embedded://textures").unwrap();
+        let mut paths: Vec<_> = handles
+            .iter()
+            .map(|handle| asset_server.get_handle_path(handle).unwrap().to_string())
+            .collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            [
+                "embedded://textures/a.png",
+                "embedded://textures/nested/b.png"
+            ]
+        );
+    }
+
     #[test]
     fn test_asset_lifecycle() {
         let dir = create_dir_and_file("fake.png");
@@ -857,8 +2001,9 @@ mod test {
 
         fn load_asset(path: AssetPath, world: &World) -> HandleUntyped {
             let asset_server = world.resource::<AssetServer>();
-            let id = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
-                .unwrap();
+            let id =
+                futures_lite::future::block_on(asset_server.load_async(path.clone(), true, None))
+                    .unwrap();
             asset_server.get_handle_untyped(id)
         }
 
@@ -917,6 +2062,443 @@ mod test {
         assert!(get_asset(&handle, &app.world).is_some());
     }
 
+    fn setup_dependencies_app() -> App {
+        let asset_server = setup(".");
+        asset_server.add_loader(FakePngLoader);
+        asset_server.add_loader(FailingLoader);
+        asset_server.add_loader(DependenciesLoader);
+        let assets = asset_server.register_asset_type::<PngAsset>();
+
+        let mut app = App::new();
+        app.insert_resource(assets)
+            .insert_resource(asset_server)
+            .add_event::<AssetEvent<PngAsset>>()
+            .add_system(update_asset_storage_system::<PngAsset>)
+            .add_system(
+                Assets::<PngAsset>::asset_event_system
+                    .after(update_asset_storage_system::<PngAsset>),
+            );
+        app
+    }
+
+    fn update_until(app: &mut App, mut done: impl FnMut(&mut App) -> bool) {
+        let start = std::time::Instant::now();
+        while !done(app) {
+            assert!(start.elapsed() < std::time::Duration::from_secs(10));
+            app.update();
+        }
+    }
+
+    #[test]
+    fn test_recursive_dependency_load_state() {
+        let mut app = setup_dependencies_app();
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        // `mid.deps` and `root.deps` depend on each other
+        asset_server.add_embedded_asset("root.deps", b"embedded://mid.deps");
+        asset_server.add_embedded_asset("mid.deps", b"embedded://leaf.png\nembedded://root.deps");
+        asset_server.add_embedded_asset("leaf.png", b"");
+
+        let root = AssetPath::from("embedded://root.deps").get_id();
+        assert_eq!(
+            asset_server.get_recursive_dependency_load_state(root),
+            RecursiveDependencyLoadState::NotLoaded
+        );
+        let handle: Handle<PngAsset> = asset_server.load("embedded://root.deps");
+
+        let mut loaded_events = Vec::new();
+        update_until(&mut app, |app| {
+            let mut events = app.world.resource_mut::<Events<AssetEvent<PngAsset>>>();
+            loaded_events.extend(events.drain().filter_map(|event| match event {
+                AssetEvent::LoadedWithDependencies { handle } => Some(handle.id()),
+                _ => None,
+            }));
+            loaded_events.contains(&handle.id())
+        });
+        assert_eq!(
+            asset_server.get_recursive_dependency_load_state(&handle),
+            RecursiveDependencyLoadState::Loaded
+        );
+        assert_eq!(
+            asset_server.get_load_state(AssetPath::from("embedded://leaf.png").get_id()),
+            LoadState::Loaded
+        );
+    }
+
+    #[test]
+    fn test_recursive_dependency_load_failure() {
+        let mut app = setup_dependencies_app();
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        asset_server.add_embedded_asset("root.deps", b"embedded://mid.deps");
+        asset_server.add_embedded_asset("mid.deps", b"embedded://leaf.png\nembedded://broken.fail");
+        asset_server.add_embedded_asset("leaf.png", b"");
+        asset_server.add_embedded_asset("broken.fail", b"");
+
+        let handle: Handle<PngAsset> = asset_server.load("embedded://root.deps");
+        // the failure can be reported before the asset itself is loaded
+        update_until(&mut app, |_| {
+            asset_server.get_load_state(&handle) == LoadState::Loaded
+                && asset_server.get_recursive_dependency_load_state(&handle)
+                    != RecursiveDependencyLoadState::Loading
+        });
+        assert_eq!(
+            asset_server.get_recursive_dependency_load_state(&handle),
+            RecursiveDependencyLoadState::Failed("embedded://broken.fail".into())
+        );
+
+        app.update();
+        let mut events = app.world.resource_mut::<Events<AssetEvent<PngAsset>>>();
+        assert!(!events
+            .drain()
+            .any(|event| matches!(event, AssetEvent::LoadedWithDependencies { handle: loaded } if loaded == handle)));
+    }
+
+    fn setup_load_direct_app(memory: &MemoryAssetIo) -> App {
+        IoTaskPool::init(Default::default);
+        let asset_server = AssetServer::new(memory.clone());
+        asset_server.add_loader(TextLoader);
+        asset_server.add_loader(JoinLoader);
+        asset_server.add_loader(FakePngLoader);
+        let assets = asset_server.register_asset_type::<TextAsset>();
+
+        let mut app = App::new();
+        app.insert_resource(assets)
+            .insert_resource(asset_server)
+            .add_event::<AssetFolderEvent>()
+            .add_system(crate::memory_asset_watcher_system.before(reload_changed_assets_system))
+            .add_system(reload_changed_assets_system)
+            .add_system(
+                update_asset_storage_system::<TextAsset>.after(reload_changed_assets_system),
+            );
+        app
+    }
+
+    fn get_text(app: &App, path: &str) -> Option<String> {
+        let handle = Handle::<TextAsset>::weak(AssetPath::from(path).get_id().into());
+        let text = app.world.resource::<Assets<TextAsset>>().get(&handle)?;
+        Some(text.0.clone())
+    }
+
+    #[test]
+    fn test_load_direct() {
+        let memory = MemoryAssetIo::new();
+        memory.watch_for_changes().unwrap();
+        memory.insert("a.txt", b"a".as_slice());
+        memory.insert("nested/b.txt", b"b".as_slice());
+        memory.insert("b.join", b"nested/b.txt".as_slice());
+        memory.insert("all.join", b"a.txt\nb.join".as_slice());
+        let mut app = setup_load_direct_app(&memory);
+        let asset_server = app.world.resource::<AssetServer>().clone();
+
+        futures_lite::future::block_on(asset_server.load_async("all.join".into(), false, None))
+            .unwrap();
+        app.update();
+        assert_eq!(get_text(&app, "all.join").as_deref(), Some("a b"));
+        // assets loaded directly aren't added to their `Assets`
+        assert_eq!(get_text(&app, "a.txt"), None);
+
+        // changing an asset loaded directly, even by a nested load, reloads the asset
+        memory.insert("nested/b.txt", b"c".as_slice());
+        update_until(&mut app, |app| {
+            get_text(app, "all.join").as_deref() == Some("a c")
+        });
+    }
+
+    #[test]
+    fn test_load_direct_errors() {
+        let memory = MemoryAssetIo::new();
+        memory.insert("a.join", b"b.join".as_slice());
+        memory.insert("b.join", b"a.join".as_slice());
+        memory.insert("self.join", b"self.join".as_slice());
+        memory.insert("image.join", b"image.png".as_slice());
+        memory.insert("image.png", b"".as_slice());
+        let app = setup_load_direct_app(&memory);
+        let asset_server = app.world.resource::<AssetServer>();
+
+        let load_error = |path: &str| {
+            futures_lite::future::block_on(asset_server.load_async(path.into(), false, None))
+                .unwrap_err()
+        };
+        fn loader_error(err: &AssetServerError) -> &AssetServerError {
+            let AssetServerError::AssetLoaderError(err) = err else {
+                panic!("unexpected error: {err}");
+            };
+            err.downcast_ref::<AssetServerError>().unwrap()
+        }
+        assert!(matches!(
+            loader_error(&load_error("self.join")),
+            AssetServerError::CyclicLoad(path) if *path == "self.join".into()
+        ));
+        // the cycle is detected in the nested load of `b.join`
+        assert!(matches!(
+            loader_error(loader_error(&load_error("a.join"))),
+            AssetServerError::CyclicLoad(path) if *path == "a.join".into()
+        ));
+        assert!(matches!(
+            loader_error(&load_error("image.join")),
+            AssetServerError::IncorrectHandleType
+        ));
+    }
+
+    #[test]
+    fn test_load_failed_events_and_retry() {
+        let asset_server = setup(".");
+        asset_server.add_loader(FakePngLoader);
+        asset_server.add_loader(FailingLoader);
+        asset_server.add_embedded_asset("broken.fail", b"");
+        let assets = asset_server.register_asset_type::<PngAsset>();
+
+        let mut app = App::new();
+        app.insert_resource(assets)
+            .insert_resource(asset_server.clone())
+            .add_event::<AssetLoadFailedEvent>()
+            .add_system(asset_load_failed_event_system)
+            .add_system(update_asset_storage_system::<PngAsset>);
+        let take_events = |app: &mut App| -> Vec<AssetLoadFailedEvent> {
+            app.world
+                .resource_mut::<Events<AssetLoadFailedEvent>>()
+                .drain()
+                .collect()
+        };
+
+        let broken: Handle<PngAsset> = asset_server.load("embedded://broken.fail");
+        let late: Handle<PngAsset> = asset_server.load("late://image.png");
+        let mut events = Vec::new();
+        update_until(&mut app, |app| {
+            events.extend(take_events(app));
+            events.len() == 2
+        });
+        events.sort_by_key(|event| event.path.to_string());
+        assert_eq!(events[0].path, "embedded://broken.fail".into());
+        assert_eq!(events[0].loader, Some(FailingLoader::type_path()));
+        assert!(matches!(
+            *events[0].error,
+            AssetServerError::AssetLoaderError(_)
+        ));
+        assert_eq!(events[1].path, "late://image.png".into());
+        assert_eq!(events[1].loader, None);
+
+        let (load_state, error) = asset_server.load_state_with_error(&late);
+        assert_eq!(load_state, LoadState::Failed);
+        assert!(matches!(
+            error.as_deref(),
+            Some(AssetServerError::MissingAssetSource(name)) if name == "late"
+        ));
+
+        // once the asset source is added, the asset can be loaded
+        let memory = MemoryAssetIo::new();
+        memory.insert("image.png", b"".as_slice());
+        asset_server.add_source("late", Box::new(memory));
+        assert!(asset_server.retry(&late));
+        update_until(&mut app, |_| {
+            asset_server.get_load_state(&late) == LoadState::Loaded
+        });
+        assert!(asset_server.load_state_with_error(&late).1.is_none());
+        assert!(!asset_server.retry(&late));
+
+        // retrying an asset that fails again sends another event
+        assert!(asset_server.retry(&broken));
+        update_until(&mut app, |app| !take_events(app).is_empty());
+    }
+
+    fn setup_hot_reload_app(memory: &MemoryAssetIo) -> App {
+        IoTaskPool::init(Default::default);
+        memory.watch_for_changes().unwrap();
+        let asset_server = AssetServer::new(memory.clone());
+        asset_server.add_loader(FakePngLoader);
+        asset_server.add_loader(DependenciesLoader);
+        let assets = asset_server.register_asset_type::<PngAsset>();
+
+        let mut app = App::new();
+        app.insert_resource(assets)
+            .insert_resource(asset_server)
+            .add_event::<AssetFolderEvent>()
+            .add_system(crate::memory_asset_watcher_system.before(reload_changed_assets_system))
+            .add_system(reload_changed_assets_system)
+            .add_system(
+                update_asset_storage_system::<PngAsset>.after(reload_changed_assets_system),
+            );
+        app
+    }
+
+    fn source_version(asset_server: &AssetServer, path: &str) -> usize {
+        let id = AssetPath::from(path).get_id().source_path_id();
+        asset_server.server.asset_sources.read()[&id].version
+    }
+
+    #[test]
+    fn test_hot_reload_dependants() {
+        let memory = MemoryAssetIo::new();
+        memory.insert("texture.png", b"".as_slice());
+        memory.insert("material.deps", b"texture.png".as_slice());
+        memory.insert("scene.deps", b"material.deps".as_slice());
+        memory.insert("unrelated.png", b"".as_slice());
+        let mut app = setup_hot_reload_app(&memory);
+        let asset_server = app.world.resource::<AssetServer>().clone();
+
+        let scene: Handle<PngAsset> = asset_server.load("scene.deps");
+        let _unrelated: Handle<PngAsset> = asset_server.load("unrelated.png");
+        update_until(&mut app, |_| {
+            asset_server.get_recursive_dependency_load_state(&scene)
+                == RecursiveDependencyLoadState::Loaded
+        });
+
+        // changes are only processed once the file stopped changing for the debounce duration
+        asset_server.set_watch_debounce(Duration::from_secs(3600));
+        memory.insert("texture.png", b"1".as_slice());
+        memory.insert("texture.png", b"2".as_slice());
+        app.update();
+        assert_eq!(source_version(&asset_server, "texture.png"), 1);
+
+        asset_server.set_watch_debounce(Duration::ZERO);
+        app.update();
+        update_until(&mut app, |_| {
+            source_version(&asset_server, "scene.deps") == 2
+        });
+        assert_eq!(source_version(&asset_server, "texture.png"), 2);
+        assert_eq!(source_version(&asset_server, "material.deps"), 2);
+        assert_eq!(source_version(&asset_server, "unrelated.png"), 1);
+    }
+
+    #[test]
+    fn test_watched_folder_events() {
+        let memory = MemoryAssetIo::new();
+        memory.insert("textures/a.png", b"".as_slice());
+        memory.insert("textures/b.png", b"".as_slice());
+        memory.insert("other/c.png", b"".as_slice());
+        let mut app = setup_hot_reload_app(&memory);
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        asset_server.set_watch_debounce(Duration::ZERO);
+        let _handles = asset_server.load_folder("textures").unwrap();
+
+        memory.insert("textures/nested/new.png", b"".as_slice());
+        memory.insert("textures/notes.txt", b"".as_slice());
+        memory.remove("textures/b.png");
+        memory.insert("other/d.png", b"".as_slice());
+        app.update();
+
+        let mut events: Vec<_> = app
+            .world
+            .resource_mut::<Events<AssetFolderEvent>>()
+            .drain()
+            .map(|event| match event {
+                AssetFolderEvent::Added { path, handle } => {
+                    assert_eq!(handle.id(), path.get_id().into());
+                    format!("added {path}")
+                }
+                AssetFolderEvent::Removed { path } => format!("removed {path}"),
+            })
+            .collect();
+        events.sort();
+        assert_eq!(
+            events,
+            ["added textures/nested/new.png", "removed textures/b.png"]
+        );
+    }
+
+    #[test]
+    fn test_loader_selection_by_asset_type() {
+        let asset_server = setup(".");
+        asset_server.add_loader(FakePngLoader);
+        asset_server.add_loader(ShoutLoader);
+
+        let loader = asset_server.get_path_asset_loader("test.png").unwrap();
+        assert_eq!(loader.asset_type_uuid(), Some(TextAsset::TYPE_UUID));
+        let loader = asset_server
+            .get_typed_path_asset_loader("test.png", Some(PngAsset::TYPE_UUID))
+            .unwrap();
+        assert_eq!(loader.asset_type_uuid(), Some(PngAsset::TYPE_UUID));
+        let loader = asset_server
+            .get_typed_path_asset_loader("test.png", Some(TextAsset::TYPE_UUID))
+            .unwrap();
+        assert_eq!(loader.asset_type_uuid(), Some(TextAsset::TYPE_UUID));
+    }
+
+    #[test]
+    fn test_loader_magic_bytes_and_overrides() {
+        let memory = MemoryAssetIo::new();
+        memory.insert("a.txt", b"a".as_slice());
+        memory.insert("b.txt", b"b".as_slice());
+        memory.insert("readme", b"TXT:c".as_slice());
+        let mut app = setup_load_direct_app(&memory);
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        // conflicts with `TextLoader` for `.txt` files, which are loaded into the same asset type
+        asset_server.add_loader(ShoutLoader);
+
+        let a: Handle<TextAsset> = asset_server.load("a.txt");
+        let b = asset_server.load_with_loader::<TextAsset, TextLoader, _>("b.txt");
+        let readme: Handle<TextAsset> = asset_server.load("readme");
+        update_until(&mut app, |app| {
+            let asset_server = app.world.resource::<AssetServer>();
+            [a.id(), b.id(), readme.id()]
+                .into_iter()
+                .all(|id| asset_server.get_load_state(id) == LoadState::Loaded)
+        });
+        assert_eq!(get_text(&app, "a.txt").as_deref(), Some("A"));
+        assert_eq!(get_text(&app, "b.txt").as_deref(), Some("b"));
+        assert_eq!(get_text(&app, "readme").as_deref(), Some("TXT:c"));
+    }
+
+    #[test]
+    fn test_loader_overrides_are_scoped_to_load_requests() {
+        let memory = MemoryAssetIo::new();
+        memory.watch_for_changes().unwrap();
+        memory.insert("a.txt", b"a".as_slice());
+        memory.insert("b.txt", b"b".as_slice());
+        let mut app = setup_load_direct_app(&memory);
+        app.add_system(free_unused_assets_system.before(update_asset_storage_system::<TextAsset>));
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        asset_server.add_loader(ShoutLoader);
+        asset_server.set_watch_debounce(Duration::ZERO);
+        let is_loaded = |path: &'static str| {
+            move |app: &mut App| {
+                let asset_server = app.world.resource::<AssetServer>();
+                asset_server.get_load_state(AssetPath::from(path).get_id()) == LoadState::Loaded
+                    && get_text(app, path).is_some()
+            }
+        };
+
+        // the loader can be overridden in the path
+        let a_path = format!("a.txt?loader={}", TextLoader::type_path());
+        let a: Handle<TextAsset> = asset_server.load(&a_path);
+        let b = asset_server.load_with_loader::<TextAsset, TextLoader, _>("b.txt");
+        update_until(&mut app, is_loaded("a.txt"));
+        update_until(&mut app, is_loaded("b.txt"));
+        assert_eq!(get_text(&app, "a.txt").as_deref(), Some("a"));
+        assert_eq!(get_text(&app, "b.txt").as_deref(), Some("b"));
+
+        // loading the path again doesn't change its loader, and reloads keep it
+        let _b_again: Handle<TextAsset> = asset_server.load("b.txt");
+        memory.insert("b.txt", b"b2".as_slice());
+        update_until(&mut app, |app| {
+            get_text(app, "b.txt").as_deref() == Some("b2")
+        });
+
+        // the override is dropped once the assets are freed
+        drop((a, b, _b_again));
+        update_until(&mut app, |app| get_text(app, "a.txt").is_none());
+        update_until(&mut app, |app| get_text(app, "b.txt").is_none());
+        let _a: Handle<TextAsset> = asset_server.load("a.txt");
+        let _b: Handle<TextAsset> = asset_server.load("b.txt");
+        update_until(&mut app, is_loaded("a.txt"));
+        update_until(&mut app, is_loaded("b.txt"));
+        assert_eq!(get_text(&app, "a.txt").as_deref(), Some("A"));
+        assert_eq!(get_text(&app, "b.txt").as_deref(), Some("B2"));
+    }
+
+    #[test]
+    fn test_streaming_loader() {
+        let memory = MemoryAssetIo::new();
+        memory.insert("big.pak", vec![b'x'; 1 << 20]);
+        let mut app = setup_load_direct_app(&memory);
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        asset_server.add_loader(HeaderLoader);
+
+        futures_lite::future::block_on(asset_server.load_async("big.pak".into(), false, None))
+            .unwrap();
+        app.update();
+        assert_eq!(get_text(&app, "big.pak").as_deref(), Some("xxxx"));
+    }
+
     #[test]
     fn test_get_handle_path() {
         const PATH: &str = "path/file.png";
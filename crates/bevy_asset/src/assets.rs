@@ -1,11 +1,12 @@
 use crate::{
-    update_asset_storage_system, Asset, AssetLoader, AssetServer, AssetSet, Handle, HandleId,
-    RefChange, ReflectAsset, ReflectHandle,
+    save_assets_system, update_asset_storage_system, Asset, AssetLoader, AssetSaveEvent,
+    AssetSaver, AssetSavers, AssetServer, AssetSet, Handle, HandleId, RefChange, ReflectAsset,
+    ReflectHandle,
 };
 use bevy_app::{App, AppTypeRegistry};
 use bevy_ecs::prelude::*;
-use bevy_reflect::{FromReflect, GetTypeRegistration, Reflect};
-use bevy_utils::HashMap;
+use bevy_reflect::{FromReflect, GetTypeRegistration, Reflect, TypePath, TypeUuid};
+use bevy_utils::{HashMap, HashSet};
 use crossbeam_channel::Sender;
 use std::fmt::Debug;
 
@@ -20,6 +21,15 @@ pub enum AssetEvent<T: Asset> {
     Modified { handle: Handle<T> },
     #[allow(missing_docs)]
     Removed { handle: Handle<T> },
+    /// Sent once an asset loaded by the [`AssetServer`](crate::AssetServer) and all of its
+    /// dependencies, recursively, have finished loading. This is sent again after the asset is
+    /// reloaded.
+    ///
+    /// See [`AssetServer::get_recursive_dependency_load_state`](crate::AssetServer::get_recursive_dependency_load_state).
+    LoadedWithDependencies {
+        #[allow(missing_docs)]
+        handle: Handle<T>,
+    },
 }
 
 impl<T: Asset> Debug for AssetEvent<T> {
@@ -46,6 +56,13 @@ impl<T: Asset> Debug for AssetEvent<T> {
                 ))
                 .field("handle", &handle.id())
                 .finish(),
+            AssetEvent::LoadedWithDependencies { handle } => f
+                .debug_struct(&format!(
+                    "AssetEvent<{}>::LoadedWithDependencies",
+                    std::any::type_name::<T>()
+                ))
+                .field("handle", &handle.id())
+                .finish(),
         }
     }
 }
@@ -68,6 +85,11 @@ pub struct Assets<T: Asset> {
     assets: HashMap<HandleId, T>,
     events: Events<AssetEvent<T>>,
     pub(crate) ref_change_sender: Sender<RefChange>,
+    /// Assets loaded by the asset server whose dependencies are still loading.
+    pub(crate) pending_dependency_loads: HashSet<HandleId>,
+    /// The number of load state changes of the asset server when the pending assets were last
+    /// checked.
+    pub(crate) checked_load_state_changes: u64,
 }
 
 impl<T: Asset> Assets<T> {
@@ -76,6 +98,8 @@ impl<T: Asset> Assets<T> {
             assets: HashMap::default(),
             events: Events::default(),
             ref_change_sender,
+            pending_dependency_loads: HashSet::default(),
+            checked_load_state_changes: 0,
         }
     }
 
@@ -221,6 +245,10 @@ impl<T: Asset> Assets<T> {
         asset
     }
 
+    pub(crate) fn send_event(&mut self, event: AssetEvent<T>) {
+        self.events.send(event);
+    }
+
     /// Clears the inner asset map, removing all key-value pairs.
     ///
     /// Keeps the allocated memory for reuse.
@@ -299,7 +327,7 @@ pub trait AddAsset {
     /// The default values may come from the `World` or from `T::default()`.
     fn init_asset_loader<T>(&mut self) -> &mut Self
     where
-        T: AssetLoader + FromWorld;
+        T: AssetLoader + TypePath + FromWorld;
 
     /// Adds an asset loader `T` for internal assets using default values.
     ///
@@ -309,12 +337,43 @@ pub trait AddAsset {
     /// The default values may come from the `World` or from `T::default()`.
     fn init_debug_asset_loader<T>(&mut self) -> &mut Self
     where
-        T: AssetLoader + FromWorld;
+        T: AssetLoader + TypePath + FromWorld;
 
     /// Adds the provided asset loader to the application.
     fn add_asset_loader<T>(&mut self, loader: T) -> &mut Self
     where
-        T: AssetLoader;
+        T: AssetLoader + TypePath;
+
+    /// Adds an asset saver, used by [`AssetServer::save`] to save assets of type `S::Asset`.
+    ///
+    /// The asset type must have been added with [`AddAsset::add_asset`].
+    fn add_asset_saver<S>(&mut self, saver: S) -> &mut Self
+    where
+        S: AssetSaver;
+
+    /// Adds an asset saver `S` using default values.
+    ///
+    /// The default values may come from the `World` or from `S::default()`.
+    fn init_asset_saver<S>(&mut self) -> &mut Self
+    where
+        S: AssetSaver + FromWorld;
+
+    /// Registers an asset processor on the [`AssetProcessor`](crate::AssetProcessor).
+    ///
+    /// Does nothing if the app doesn't process assets, see [`AssetMode`](crate::AssetMode).
+    #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+    fn add_asset_processor<P>(&mut self, processor: P) -> &mut Self
+    where
+        P: crate::Process + TypePath;
+
+    /// Processes the asset sources with the given `extension` with the registered processor `P`,
+    /// unless their `.meta` file selects another one.
+    ///
+    /// Does nothing if the app doesn't process assets, see [`AssetMode`](crate::AssetMode).
+    #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+    fn set_default_asset_processor<P>(&mut self, extension: &str) -> &mut Self
+    where
+        P: crate::Process + TypePath;
 }
 
 impl AddAsset for App {
@@ -375,7 +434,7 @@ impl AddAsset for App {
 
     fn init_asset_loader<T>(&mut self) -> &mut Self
     where
-        T: AssetLoader + FromWorld,
+        T: AssetLoader + TypePath + FromWorld,
     {
         let result = T::from_world(&mut self.world);
         self.add_asset_loader(result)
@@ -383,7 +442,7 @@ impl AddAsset for App {
 
     fn init_debug_asset_loader<T>(&mut self) -> &mut Self
     where
-        T: AssetLoader + FromWorld,
+        T: AssetLoader + TypePath + FromWorld,
     {
         #[cfg(feature = "debug_asset_server")]
         {
@@ -397,11 +456,59 @@ impl AddAsset for App {
 
     fn add_asset_loader<T>(&mut self, loader: T) -> &mut Self
     where
-        T: AssetLoader,
+        T: AssetLoader + TypePath,
     {
         self.world.resource_mut::<AssetServer>().add_loader(loader);
         self
     }
+
+    fn add_asset_saver<S>(&mut self, saver: S) -> &mut Self
+    where
+        S: AssetSaver,
+    {
+        if !self.world.contains_resource::<AssetSavers<S::Asset>>() {
+            self.world
+                .resource::<AssetServer>()
+                .register_saved_asset_type(S::Asset::TYPE_UUID);
+            self.init_resource::<AssetSavers<S::Asset>>()
+                .add_event::<AssetSaveEvent<S::Asset>>()
+                .add_system(save_assets_system::<S::Asset>.in_base_set(AssetSet::AssetEvents));
+        }
+        self.world
+            .resource_mut::<AssetSavers<S::Asset>>()
+            .add(saver);
+        self
+    }
+
+    fn init_asset_saver<S>(&mut self) -> &mut Self
+    where
+        S: AssetSaver + FromWorld,
+    {
+        let saver = S::from_world(&mut self.world);
+        self.add_asset_saver(saver)
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+    fn add_asset_processor<P>(&mut self, processor: P) -> &mut Self
+    where
+        P: crate::Process + TypePath,
+    {
+        if let Some(asset_processor) = self.world.get_resource::<crate::AssetProcessor>() {
+            asset_processor.add_processor(processor);
+        }
+        self
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+    fn set_default_asset_processor<P>(&mut self, extension: &str) -> &mut Self
+    where
+        P: crate::Process + TypePath,
+    {
+        if let Some(asset_processor) = self.world.get_resource::<crate::AssetProcessor>() {
+            asset_processor.set_default_processor::<P>(extension);
+        }
+        self
+    }
 }
 
 /// Loads an internal asset.
@@ -488,7 +595,7 @@ mod tests {
 
     #[test]
     fn asset_overwriting() {
-        #[derive(bevy_reflect::TypeUuid)]
+        #[derive(bevy_reflect::TypePath, bevy_reflect::TypeUuid)]
         #[uuid = "44115972-f31b-46e5-be5c-2b9aece6a52f"]
         struct MyAsset;
         let mut app = App::new();
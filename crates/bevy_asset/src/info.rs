@@ -1,7 +1,7 @@
-use crate::{path::AssetPath, LabelId};
+use crate::{path::AssetPath, AssetServerError, LabelId, LoaderHints};
 use bevy_utils::{HashMap, HashSet, Uuid};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::Arc};
 
 /// Metadata for an asset source.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -26,6 +26,9 @@ pub struct AssetMeta {
 pub struct SourceInfo {
     /// Metadata for the source.
     pub meta: Option<SourceMeta>,
+    /// The name of the asset source the source is loaded from, or [`None`] for the default
+    /// asset source.
+    pub source: Option<String>,
     /// The path of the source.
     pub path: PathBuf,
     /// A map of assets and their type identifiers.
@@ -36,9 +39,19 @@ pub struct SourceInfo {
     pub committed_assets: HashSet<LabelId>,
     /// Current version of the source.
     pub version: usize,
+    /// The error the source failed to load with, if its load state is [`LoadState::Failed`].
+    pub error: Option<Arc<AssetServerError>>,
+    /// How the loader of the source was selected by the load request that loaded it, reused when
+    /// it's reloaded.
+    pub(crate) loader_hints: LoaderHints,
 }
 
 impl SourceInfo {
+    /// Returns the asset path of the source, without a label.
+    pub fn asset_path(&self) -> AssetPath<'_> {
+        AssetPath::new_ref(&self.path, None).with_source_opt(self.source.as_deref())
+    }
+
     /// Returns `true` if all assets tracked by the source were loaded into their asset storages.
     pub fn is_loaded(&self) -> bool {
         self.meta.as_ref().map_or(false, |meta| {
@@ -67,3 +80,19 @@ pub enum LoadState {
     /// from the [`Assets`](crate::Assets) collection.
     Unloaded,
 }
+
+/// The load state of an asset and of all the assets it depends on, recursively.
+///
+/// See [`AssetServer::get_recursive_dependency_load_state`](crate::AssetServer::get_recursive_dependency_load_state).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RecursiveDependencyLoadState {
+    /// The asset has not been loaded, or was unloaded.
+    NotLoaded,
+    /// The asset or one of its dependencies is in the process of loading.
+    Loading,
+    /// The asset and all of its dependencies have been loaded.
+    Loaded,
+    /// The asset or one of its dependencies failed to load. Contains the path of the asset that
+    /// failed.
+    Failed(AssetPath<'static>),
+}
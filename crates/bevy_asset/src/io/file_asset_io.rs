@@ -1,15 +1,14 @@
 #[cfg(feature = "filesystem_watcher")]
-use crate::{filesystem_watcher::FilesystemWatcher, AssetServer};
-use crate::{AssetIo, AssetIoError, Metadata};
+use crate::{filesystem_watcher::FilesystemWatcher, AssetServer, PathChange};
+use crate::{AssetIo, AssetIoError, AssetWriter, Metadata, Reader};
 use anyhow::Result;
 #[cfg(feature = "filesystem_watcher")]
 use bevy_ecs::system::Res;
 use bevy_utils::BoxedFuture;
 #[cfg(feature = "filesystem_watcher")]
-use bevy_utils::HashSet;
-#[cfg(feature = "filesystem_watcher")]
 use crossbeam_channel::TryRecvError;
 use fs::File;
+use futures_lite::io::AssertAsync;
 #[cfg(feature = "filesystem_watcher")]
 use parking_lot::RwLock;
 #[cfg(feature = "filesystem_watcher")]
@@ -86,29 +85,68 @@ impl FileAssetIo {
     pub fn root_path(&self) -> &PathBuf {
         &self.root_path
     }
+
+    fn open_file(&self, path: &Path) -> Result<File, AssetIoError> {
+        let full_path = self.root_path.join(path);
+        File::open(&full_path).map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                AssetIoError::NotFound(full_path)
+            } else {
+                e.into()
+            }
+        })
+    }
 }
 
-impl AssetIo for FileAssetIo {
-    fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
+impl AssetWriter for FileAssetIo {
+    fn write_path<'a>(
+        &'a self,
+        path: &'a Path,
+        bytes: &'a [u8],
+    ) -> BoxedFuture<'a, Result<(), AssetIoError>> {
         Box::pin(async move {
-            let mut bytes = Vec::new();
             let full_path = self.root_path.join(path);
-            match File::open(&full_path) {
-                Ok(mut file) => {
-                    file.read_to_end(&mut bytes)?;
-                }
-                Err(e) => {
-                    return if e.kind() == std::io::ErrorKind::NotFound {
-                        Err(AssetIoError::NotFound(full_path))
-                    } else {
-                        Err(e.into())
-                    }
+            if let Some(parent) = full_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(full_path, bytes)?;
+            Ok(())
+        })
+    }
+
+    fn remove_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<(), AssetIoError>> {
+        Box::pin(async move {
+            let full_path = self.root_path.join(path);
+            match fs::remove_file(&full_path) {
+                Ok(()) => Ok(()),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                    Err(AssetIoError::NotFound(full_path))
                 }
+                Err(e) => Err(e.into()),
             }
+        })
+    }
+}
+
+impl AssetIo for FileAssetIo {
+    fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            self.open_file(path)?.read_to_end(&mut bytes)?;
             Ok(bytes)
         })
     }
 
+    fn open_path<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<dyn Reader>, AssetIoError>> {
+        Box::pin(async move {
+            let file = self.open_file(path)?;
+            Ok(Box::new(AssertAsync::new(file)) as Box<dyn Reader>)
+        })
+    }
+
     fn read_directory(
         &self,
         path: &Path,
@@ -161,21 +199,39 @@ impl AssetIo for FileAssetIo {
                 }
             })
     }
+
+    fn writer(&self) -> Option<&dyn AssetWriter> {
+        Some(self)
+    }
 }
 
 /// Watches for file changes in the local file system.
+///
+/// Changes are watched in every asset source backed by a [`FileAssetIo`], and processed by
+/// [`reload_changed_assets_system`](crate::reload_changed_assets_system).
 #[cfg(all(
     feature = "filesystem_watcher",
     all(not(target_arch = "wasm32"), not(target_os = "android"))
 ))]
 pub fn filesystem_watcher_system(asset_server: Res<AssetServer>) {
-    let mut changed = HashSet::default();
-    let asset_io =
-        if let Some(asset_io) = asset_server.server.asset_io.downcast_ref::<FileAssetIo>() {
-            asset_io
-        } else {
-            return;
-        };
+    queue_path_changes(&asset_server, None, &*asset_server.server.asset_io);
+    for (source, asset_io) in asset_server.server.sources.read().iter() {
+        queue_path_changes(&asset_server, Some(source), &**asset_io);
+    }
+}
+
+#[cfg(all(
+    feature = "filesystem_watcher",
+    all(not(target_arch = "wasm32"), not(target_os = "android"))
+))]
+fn queue_path_changes(asset_server: &AssetServer, source: Option<&str>, asset_io: &dyn AssetIo) {
+    use notify::event::{EventKind, ModifyKind};
+
+    let asset_io = if let Some(asset_io) = asset_io.downcast_ref::<FileAssetIo>() {
+        asset_io
+    } else {
+        return;
+    };
     let watcher = asset_io.filesystem_watcher.read();
     if let Some(ref watcher) = *watcher {
         loop {
@@ -184,19 +240,19 @@ pub fn filesystem_watcher_system(asset_server: Res<AssetServer>) {
                 Err(TryRecvError::Empty) => break,
                 Err(TryRecvError::Disconnected) => panic!("FilesystemWatcher disconnected."),
             };
-            if let notify::event::Event {
-                kind: notify::event::EventKind::Modify(_),
-                paths,
-                ..
-            } = event
-            {
-                for path in &paths {
-                    if !changed.contains(path) {
-                        let relative_path = path.strip_prefix(&asset_io.root_path).unwrap();
-                        let _ = asset_server.load_untracked(relative_path.into(), true);
-                    }
-                }
-                changed.extend(paths);
+            for path in &event.paths {
+                let change = match event.kind {
+                    EventKind::Create(_) => PathChange::Added,
+                    // renamed paths are reported as added or removed, depending on whether
+                    // they exist afterwards
+                    EventKind::Modify(ModifyKind::Name(_)) if path.exists() => PathChange::Added,
+                    EventKind::Modify(ModifyKind::Name(_)) => PathChange::Removed,
+                    EventKind::Modify(_) => PathChange::Modified,
+                    EventKind::Remove(_) => PathChange::Removed,
+                    _ => continue,
+                };
+                let relative_path = path.strip_prefix(&asset_io.root_path).unwrap();
+                asset_server.queue_path_change(source, relative_path, change);
             }
         }
     }
@@ -0,0 +1,480 @@
+use crate::{
+    AssetIo, AssetIoError, AssetServer, AssetWriter, FileType, Metadata, PathChange, Reader,
+};
+use anyhow::Result;
+use bevy_ecs::system::Res;
+use bevy_utils::{BoxedFuture, HashMap, HashSet};
+use futures_lite::io::Cursor;
+use parking_lot::{Mutex, RwLock};
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// The bytes of a file, which readers share without copying them.
+#[derive(Clone)]
+enum FileBytes {
+    Static(&'static [u8]),
+    Shared(Arc<[u8]>),
+}
+
+impl From<Cow<'static, [u8]>> for FileBytes {
+    fn from(bytes: Cow<'static, [u8]>) -> Self {
+        match bytes {
+            Cow::Borrowed(bytes) => FileBytes::Static(bytes),
+            Cow::Owned(bytes) => FileBytes::Shared(bytes.into()),
+        }
+    }
+}
+
+impl From<FileBytes> for Cow<'static, [u8]> {
+    fn from(bytes: FileBytes) -> Self {
+        match bytes {
+            FileBytes::Static(bytes) => Cow::Borrowed(bytes),
+            FileBytes::Shared(bytes) => Cow::Owned(bytes.to_vec()),
+        }
+    }
+}
+
+impl AsRef<[u8]> for FileBytes {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            FileBytes::Static(bytes) => bytes,
+            FileBytes::Shared(bytes) => bytes,
+        }
+    }
+}
+
+#[derive(Default)]
+struct MemoryAssetIoData {
+    files: RwLock<HashMap<PathBuf, FileBytes>>,
+    watching: AtomicBool,
+    watched_paths: RwLock<HashSet<PathBuf>>,
+    changed_paths: Mutex<Vec<(PathBuf, PathChange)>>,
+}
+
+/// I/O implementation for assets stored in memory.
+///
+/// The assets form a virtual directory tree, in which directories are implied by the paths of the
+/// files. Files can be added at any time with [`MemoryAssetIo::insert`], from bytes created at
+/// runtime or compiled into the binary with [`include_bytes!`].
+///
+/// A `MemoryAssetIo` is cheap to clone, and clones share the same files. This makes it possible to
+/// keep a clone around to change the files of an asset source added to the
+/// [`AssetServer`](crate::AssetServer):
+///
+/// ```
+/// # use bevy_asset::{AssetServer, MemoryAssetIo};
+/// # fn add_source(asset_server: &AssetServer) {
+/// let memory = MemoryAssetIo::default();
+/// memory.insert("levels/first.level", b"spawn 0 0".as_slice());
+/// asset_server.add_source("memory", Box::new(memory.clone()));
+/// // `memory://levels/first.level` can now be loaded
+/// # }
+/// ```
+///
+/// Once [`watch_for_changes`](AssetIo::watch_for_changes) is enabled, overwriting a loaded file
+/// reloads its assets, and adding or removing files in a folder loaded with
+/// [`AssetServer::load_folder`](crate::AssetServer::load_folder) sends
+/// [`AssetFolderEvent`](crate::AssetFolderEvent)s, like changes to files on disk do with the
+/// `filesystem_watcher` feature.
+///
+/// This is also the I/O implementation of the
+/// [`EMBEDDED_ASSET_SOURCE`](crate::EMBEDDED_ASSET_SOURCE), see
+/// [`embedded_asset!`](crate::embedded_asset).
+#[derive(Clone, Default)]
+pub struct MemoryAssetIo {
+    data: Arc<MemoryAssetIoData>,
+}
+
+impl MemoryAssetIo {
+    /// Creates an empty `MemoryAssetIo`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a file at `path`, overwriting any file previously there.
+    pub fn insert(&self, path: impl Into<PathBuf>, bytes: impl Into<Cow<'static, [u8]>>) {
+        let path = path.into();
+        let overwritten = self
+            .data
+            .files
+            .write()
+            .insert(path.clone(), FileBytes::from(bytes.into()))
+            .is_some();
+        let change = if overwritten {
+            PathChange::Modified
+        } else {
+            PathChange::Added
+        };
+        self.notify_change(path, change);
+    }
+
+    /// Removes the file at `path`, returning its bytes if there was one.
+    pub fn remove(&self, path: impl AsRef<Path>) -> Option<Cow<'static, [u8]>> {
+        let path = path.as_ref();
+        let bytes = self.data.files.write().remove(path);
+        if bytes.is_some() {
+            self.notify_change(path.to_owned(), PathChange::Removed);
+        }
+        bytes.map(Cow::from)
+    }
+
+    /// Returns `true` if there is a file at `path`.
+    pub fn contains(&self, path: impl AsRef<Path>) -> bool {
+        self.data.files.read().contains_key(path.as_ref())
+    }
+
+    /// Returns the paths of the files that changed since the last call, if changes are watched.
+    pub(crate) fn take_changed_paths(&self) -> Vec<(PathBuf, PathChange)> {
+        std::mem::take(&mut *self.data.changed_paths.lock())
+    }
+
+    fn notify_change(&self, path: PathBuf, change: PathChange) {
+        if !self.data.watching.load(Ordering::Acquire) {
+            return;
+        }
+        let watched = self
+            .data
+            .watched_paths
+            .read()
+            .iter()
+            .any(|watched_path| path.starts_with(watched_path));
+        if watched {
+            self.data.changed_paths.lock().push((path, change));
+        }
+    }
+}
+
+impl AssetIo for MemoryAssetIo {
+    fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
+        Box::pin(async move {
+            self.data
+                .files
+                .read()
+                .get(path)
+                .map(|bytes| bytes.as_ref().to_vec())
+                .ok_or_else(|| AssetIoError::NotFound(path.to_owned()))
+        })
+    }
+
+    fn open_path<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<dyn Reader>, AssetIoError>> {
+        Box::pin(async move {
+            let bytes = self.data.files.read().get(path).cloned();
+            match bytes {
+                Some(bytes) => Ok(Box::new(Cursor::new(bytes)) as Box<dyn Reader>),
+                None => Err(AssetIoError::NotFound(path.to_owned())),
+            }
+        })
+    }
+
+    fn read_directory(
+        &self,
+        path: &Path,
+    ) -> Result<Box<dyn Iterator<Item = PathBuf>>, AssetIoError> {
+        let mut children = Vec::new();
+        for file_path in self.data.files.read().keys() {
+            let Ok(relative) = file_path.strip_prefix(path) else {
+                continue;
+            };
+            if let Some(child) = relative.components().next() {
+                let child = path.join(child);
+                if !children.contains(&child) {
+                    children.push(child);
+                }
+            }
+        }
+        if children.is_empty() {
+            return Err(AssetIoError::NotFound(path.to_owned()));
+        }
+        children.sort();
+        Ok(Box::new(children.into_iter()))
+    }
+
+    fn get_metadata(&self, path: &Path) -> Result<Metadata, AssetIoError> {
+        let files = self.data.files.read();
+        if files.contains_key(path) {
+            Ok(Metadata::new(FileType::File))
+        } else if files.keys().any(|file_path| file_path.starts_with(path)) {
+            Ok(Metadata::new(FileType::Directory))
+        } else {
+            Err(AssetIoError::NotFound(path.to_owned()))
+        }
+    }
+
+    fn watch_path_for_changes(&self, path: &Path) -> Result<(), AssetIoError> {
+        if self.data.watching.load(Ordering::Acquire) {
+            self.data.watched_paths.write().insert(path.to_owned());
+        }
+        Ok(())
+    }
+
+    fn watch_for_changes(&self) -> Result<(), AssetIoError> {
+        self.data.watching.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    fn writer(&self) -> Option<&dyn AssetWriter> {
+        Some(self)
+    }
+}
+
+impl AssetWriter for MemoryAssetIo {
+    fn write_path<'a>(
+        &'a self,
+        path: &'a Path,
+        bytes: &'a [u8],
+    ) -> BoxedFuture<'a, Result<(), AssetIoError>> {
+        Box::pin(async move {
+            self.insert(path, bytes.to_vec());
+            Ok(())
+        })
+    }
+
+    fn remove_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<(), AssetIoError>> {
+        Box::pin(async move {
+            self.remove(path)
+                .map(|_| ())
+                .ok_or_else(|| AssetIoError::NotFound(path.to_owned()))
+        })
+    }
+}
+
+/// Queues the changes to the files of the asset sources backed by a [`MemoryAssetIo`], which are
+/// then processed by [`reload_changed_assets_system`](crate::reload_changed_assets_system).
+pub fn memory_asset_watcher_system(asset_server: Res<AssetServer>) {
+    queue_path_changes(&asset_server, None, &*asset_server.server.asset_io);
+    for (source, asset_io) in asset_server.server.sources.read().iter() {
+        queue_path_changes(&asset_server, Some(source), &**asset_io);
+    }
+}
+
+fn queue_path_changes(asset_server: &AssetServer, source: Option<&str>, asset_io: &dyn AssetIo) {
+    if let Some(asset_io) = asset_io.downcast_ref::<MemoryAssetIo>() {
+        for (path, change) in asset_io.take_changed_paths() {
+            asset_server.queue_path_change(source, &path, change);
+        }
+    }
+}
+
+/// Embeds an asset into the binary, and adds it to the
+/// [`EMBEDDED_ASSET_SOURCE`](crate::EMBEDDED_ASSET_SOURCE) of the app's
+/// [`AssetServer`](crate::AssetServer).
+///
+/// The file is read with [`include_bytes!`], so its path is relative to the current source file.
+/// By default, it is added at the same path in the embedded source, but another asset path can be
+/// given:
+///
+/// ```ignore
+/// // loaded with `asset_server.load("embedded://shaders/outline.wgsl")`
+/// embedded_asset!(app, "shaders/outline.wgsl");
+/// // loaded with `asset_server.load("embedded://my_plugin/icon.png")`
+/// embedded_asset!(app, "../assets/icon.png", "my_plugin/icon.png");
+/// ```
+#[macro_export]
+macro_rules! embedded_asset {
+    ($app: expr, $path_str: expr) => {{
+        $crate::embedded_asset!($app, $path_str, $path_str)
+    }};
+    ($app: expr, $path_str: expr, $asset_path: expr) => {{
+        $app.world
+            .resource::<$crate::AssetServer>()
+            .add_embedded_asset($asset_path, include_bytes!($path_str).as_slice());
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AssetPath, LoadContext, LoadState, LoadedAsset};
+    use bevy_app::App;
+    use bevy_ecs::schedule::IntoSystemConfig;
+    use bevy_reflect::{TypePath, TypeUuid};
+    use bevy_tasks::IoTaskPool;
+    use futures_lite::future::block_on;
+
+    #[derive(Debug, TypePath, TypeUuid)]
+    #[uuid = "5d4c1f0e-4d1c-4b1b-9a54-7fbcb3c2a1e7"]
+    struct Text;
+
+    #[derive(TypePath)]
+    struct TextLoader;
+
+    impl crate::AssetLoader for TextLoader {
+        fn load<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            load_context: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                std::str::from_utf8(bytes)?;
+                load_context.set_default_asset(LoadedAsset::new(Text));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["txt", "rs"]
+        }
+    }
+
+    fn setup() -> App {
+        IoTaskPool::init(Default::default);
+        let asset_server = AssetServer::new(MemoryAssetIo::new());
+        asset_server.add_loader(TextLoader);
+        let assets = asset_server.register_asset_type::<Text>();
+        let mut app = App::new();
+        app.insert_resource(asset_server).insert_resource(assets);
+        app
+    }
+
+    fn source_version(app: &App, path: &str) -> usize {
+        let id = AssetPath::from(path).get_id().source_path_id();
+        app.world
+            .resource::<AssetServer>()
+            .server
+            .asset_sources
+            .read()[&id]
+            .version
+    }
+
+    #[test]
+    fn overwritten_assets_are_reloaded() {
+        let mut app = setup();
+        app.add_event::<crate::AssetFolderEvent>()
+            .add_system(memory_asset_watcher_system)
+            .add_system(crate::reload_changed_assets_system.after(memory_asset_watcher_system));
+        let memory = MemoryAssetIo::new();
+        memory.watch_for_changes().unwrap();
+        memory.insert("a.txt", b"first".as_slice());
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        asset_server.add_source("memory", Box::new(memory.clone()));
+
+        let id = block_on(asset_server.load_async("memory://a.txt".into(), false, None)).unwrap();
+        assert_eq!(asset_server.get_load_state(id), LoadState::Loading);
+        assert_eq!(source_version(&app, "memory://a.txt"), 1);
+
+        memory.insert("a.txt", b"second".as_slice());
+        // the reload is debounced, then queued on the task pool
+        let start = std::time::Instant::now();
+        while source_version(&app, "memory://a.txt") != 2 {
+            assert!(start.elapsed() < std::time::Duration::from_secs(10));
+            app.update();
+        }
+    }
+
+    #[test]
+    fn embedded_asset_macro() {
+        let app = setup();
+        crate::embedded_asset!(app, "memory_asset_io.rs", "sources/memory.rs");
+
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        let id =
+            block_on(asset_server.load_async("embedded://sources/memory.rs".into(), false, None))
+                .unwrap();
+        assert_eq!(asset_server.get_load_state(id), LoadState::Loading);
+        assert!(asset_server
+            .source_io(Some(crate::EMBEDDED_ASSET_SOURCE))
+            .unwrap()
+            .is_file(Path::new("sources/memory.rs")));
+    }
+
+    #[test]
+    fn partial_reads() {
+        use futures_lite::io::{AsyncReadExt, AsyncSeekExt, SeekFrom};
+
+        let io = MemoryAssetIo::new();
+        io.insert("static.bin", b"0123456789".as_slice());
+        io.insert("owned.bin", b"abcdefghij".to_vec());
+
+        let mut reader = block_on(io.open_path(Path::new("static.bin"))).unwrap();
+        let mut bytes = [0; 3];
+        block_on(reader.seek(SeekFrom::Start(4))).unwrap();
+        block_on(reader.read_exact(&mut bytes)).unwrap();
+        assert_eq!(&bytes, b"456");
+        block_on(reader.read_exact(&mut bytes)).unwrap();
+        assert_eq!(&bytes, b"789");
+
+        assert_eq!(
+            block_on(io.read_range(Path::new("owned.bin"), 2..5)).unwrap(),
+            b"cde"
+        );
+        assert!(matches!(
+            block_on(io.read_range(Path::new("owned.bin"), 8..12)),
+            Err(AssetIoError::Io(err)) if err.kind() == std::io::ErrorKind::UnexpectedEof
+        ));
+        // ranges are read as far as the file goes, without allocating the whole range up front
+        assert!(matches!(
+            block_on(io.read_range(Path::new("owned.bin"), 2..u64::MAX)),
+            Err(AssetIoError::Io(err)) if err.kind() == std::io::ErrorKind::UnexpectedEof
+        ));
+        assert!(matches!(
+            block_on(io.open_path(Path::new("missing.bin"))),
+            Err(AssetIoError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn virtual_directory_tree() {
+        let io = MemoryAssetIo::new();
+        io.insert("a.txt", b"a".as_slice());
+        io.insert("dir/b.txt", vec![b'b']);
+        io.insert("dir/nested/c.txt", b"c".as_slice());
+
+        assert_eq!(
+            block_on(io.load_path(Path::new("dir/b.txt"))).unwrap(),
+            b"b"
+        );
+        assert!(matches!(
+            block_on(io.load_path(Path::new("missing.txt"))),
+            Err(AssetIoError::NotFound(_))
+        ));
+
+        assert!(io.is_file(Path::new("a.txt")));
+        assert!(io.is_dir(Path::new("dir/nested")));
+        assert!(!io.is_dir(Path::new("missing")));
+
+        let root: Vec<_> = io.read_directory(Path::new("")).unwrap().collect();
+        assert_eq!(root, [PathBuf::from("a.txt"), PathBuf::from("dir")]);
+        let dir: Vec<_> = io.read_directory(Path::new("dir")).unwrap().collect();
+        assert_eq!(
+            dir,
+            [PathBuf::from("dir/b.txt"), PathBuf::from("dir/nested")]
+        );
+    }
+
+    #[test]
+    fn overwriting_watched_files_is_notified() {
+        let io = MemoryAssetIo::new();
+        io.insert("a.txt", b"a".as_slice());
+        io.insert("b.txt", b"b".as_slice());
+
+        // changes aren't tracked before watching is enabled
+        io.watch_path_for_changes(Path::new("a.txt")).unwrap();
+        io.insert("a.txt", b"a2".as_slice());
+        assert!(io.take_changed_paths().is_empty());
+
+        io.watch_for_changes().unwrap();
+        io.watch_path_for_changes(Path::new("a.txt")).unwrap();
+        io.insert("a.txt", b"a3".as_slice());
+        io.insert("b.txt", b"b2".as_slice());
+        io.remove("a.txt");
+        io.insert("a.txt", b"a4".as_slice());
+        assert_eq!(
+            io.take_changed_paths(),
+            [
+                (PathBuf::from("a.txt"), PathChange::Modified),
+                (PathBuf::from("a.txt"), PathChange::Removed),
+                (PathBuf::from("a.txt"), PathChange::Added)
+            ]
+        );
+        assert!(io.take_changed_paths().is_empty());
+    }
+}
@@ -2,6 +2,7 @@
 mod android_asset_io;
 #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
 mod file_asset_io;
+mod memory_asset_io;
 #[cfg(target_arch = "wasm32")]
 mod wasm_asset_io;
 
@@ -11,6 +12,7 @@ mod metadata;
 pub use android_asset_io::*;
 #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
 pub use file_asset_io::*;
+pub use memory_asset_io::*;
 #[cfg(target_arch = "wasm32")]
 pub use wasm_asset_io::*;
 
@@ -19,8 +21,10 @@ pub use metadata::*;
 use anyhow::Result;
 use bevy_utils::BoxedFuture;
 use downcast_rs::{impl_downcast, Downcast};
+use futures_lite::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, Cursor, SeekFrom};
 use std::{
     io,
+    ops::Range,
     path::{Path, PathBuf},
 };
 use thiserror::Error;
@@ -41,6 +45,13 @@ pub enum AssetIoError {
     PathWatchError(PathBuf),
 }
 
+/// An asynchronous reader of the bytes of a file, which can be read incrementally and seeked.
+///
+/// Readers are opened with [`AssetIo::open_path`].
+pub trait Reader: AsyncRead + AsyncSeek + Unpin + Send + Sync {}
+
+impl<T: AsyncRead + AsyncSeek + Unpin + Send + Sync> Reader for T {}
+
 /// A storage provider for an [`AssetServer`].
 ///
 /// An asset I/O is the backend actually providing data for the asset loaders managed by the asset
@@ -56,6 +67,43 @@ pub trait AssetIo: Downcast + Send + Sync + 'static {
     /// Returns a future to load the full file data at the provided path.
     fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>>;
 
+    /// Returns a future opening the file at the provided path for incremental reads.
+    ///
+    /// The default implementation loads the full file with [`AssetIo::load_path`]. Asset I/Os
+    /// able to read parts of their files should override it.
+    fn open_path<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<dyn Reader>, AssetIoError>> {
+        Box::pin(async move {
+            let bytes = self.load_path(path).await?;
+            Ok(Box::new(Cursor::new(bytes)) as Box<dyn Reader>)
+        })
+    }
+
+    /// Returns a future reading the bytes in `range` of the file at the provided path.
+    ///
+    /// Fails with an [`io::ErrorKind::UnexpectedEof`] error if the file ends before the range.
+    fn read_range<'a>(
+        &'a self,
+        path: &'a Path,
+        range: Range<u64>,
+    ) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
+        Box::pin(async move {
+            let mut reader = self.open_path(path).await?;
+            reader.seek(SeekFrom::Start(range.start)).await?;
+            // read into a growing buffer, so that huge ranges past the end of the file don't
+            // allocate more than the file
+            let len = range.end.saturating_sub(range.start);
+            let mut bytes = Vec::new();
+            reader.take(len).read_to_end(&mut bytes).await?;
+            if (bytes.len() as u64) < len {
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+            }
+            Ok(bytes)
+        })
+    }
+
     /// Returns an iterator of directory entry names at the provided path.
     fn read_directory(
         &self,
@@ -86,6 +134,29 @@ pub trait AssetIo: Downcast + Send + Sync + 'static {
             .map(Metadata::is_file)
             .unwrap_or(false)
     }
+
+    /// Returns the [`AssetWriter`] of this asset I/O, or [`None`] if it is read-only.
+    ///
+    /// Assets can only be saved with [`AssetServer::save`] to asset sources with a writer.
+    ///
+    /// [`AssetServer::save`]: crate::AssetServer::save
+    fn writer(&self) -> Option<&dyn AssetWriter> {
+        None
+    }
 }
 
 impl_downcast!(AssetIo);
+
+/// The write capabilities of an [`AssetIo`].
+pub trait AssetWriter: Send + Sync + 'static {
+    /// Returns a future writing `bytes` to the file at the provided path, creating the file and
+    /// its parent directories if needed, and replacing its content otherwise.
+    fn write_path<'a>(
+        &'a self,
+        path: &'a Path,
+        bytes: &'a [u8],
+    ) -> BoxedFuture<'a, Result<(), AssetIoError>>;
+
+    /// Returns a future removing the file at the provided path.
+    fn remove_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<(), AssetIoError>>;
+}
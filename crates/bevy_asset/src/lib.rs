@@ -25,7 +25,12 @@ mod info;
 mod io;
 mod loader;
 mod path;
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+pub mod processor;
 mod reflect;
+mod saver;
+mod settings;
+mod source;
 
 /// The `bevy_asset` prelude.
 pub mod prelude {
@@ -44,10 +49,16 @@ pub use info::*;
 pub use io::*;
 pub use loader::*;
 pub use path::*;
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+pub use processor::{AssetProcessor, Process, ProcessContext, ProcessedAsset};
 pub use reflect::*;
+pub use saver::{save_assets_system, AssetSaveError, AssetSaveEvent, AssetSaver, AssetSavers};
+pub use settings::*;
+pub use source::*;
 
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
+use bevy_utils::Duration;
 
 /// [`SystemSet`]s for asset loading in an [`App`] schedule.
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
@@ -59,6 +70,28 @@ pub enum AssetSet {
     AssetEvents,
 }
 
+/// Whether an app loads asset sources or processed assets, see [`AssetProcessor`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AssetMode {
+    /// Loads asset sources from [`AssetPlugin::asset_folder`], ignoring processors.
+    #[default]
+    Unprocessed,
+    /// Loads processed assets from [`AssetPlugin::processed_asset_folder`], which must have been
+    /// created ahead of time, for example by a build step running the [`AssetProcessor`].
+    ///
+    /// This is the mode to use for shipping builds.
+    Processed,
+    /// Processes the asset sources of [`AssetPlugin::asset_folder`] into
+    /// [`AssetPlugin::processed_asset_folder`] on startup, then loads the processed assets.
+    ///
+    /// Only sources that changed since the last run are processed again. The
+    /// [`AssetProcessor`] is inserted as a resource, so that processors can be registered with
+    /// [`AddAsset::add_asset_processor`].
+    ///
+    /// On wasm32 and android, this behaves like [`AssetMode::Processed`].
+    ProcessedDev,
+}
+
 /// Adds support for [`Assets`] to an App.
 ///
 /// Assets are typed collections with change tracking, which are added as App Resources. Examples of
@@ -67,16 +100,32 @@ pub enum AssetSet {
 pub struct AssetPlugin {
     /// The base folder where assets are loaded from, relative to the executable.
     pub asset_folder: String,
+    /// The folder where processed assets are written to and loaded from, relative to the
+    /// executable. Only used when [`mode`](Self::mode) isn't [`AssetMode::Unprocessed`].
+    pub processed_asset_folder: String,
+    /// Whether to load asset sources or processed assets.
+    pub mode: AssetMode,
     /// Whether to watch for changes in asset files. Requires the `filesystem_watcher` feature,
     /// and cannot be supported on the wasm32 arch nor android os.
     pub watch_for_changes: bool,
+    /// How long a changed file must stay unchanged before its assets are reloaded, when watching
+    /// for changes. See [`AssetServer::set_watch_debounce`].
+    pub watch_debounce: Duration,
+    /// Additional named asset sources, loaded from with paths like `name://path/to/file.ext`.
+    ///
+    /// The `embedded` source is always available, see [`EMBEDDED_ASSET_SOURCE`].
+    pub sources: Vec<AssetSource>,
 }
 
 impl Default for AssetPlugin {
     fn default() -> Self {
         Self {
             asset_folder: "assets".to_string(),
+            processed_asset_folder: "imported_assets".to_string(),
+            mode: AssetMode::Unprocessed,
             watch_for_changes: false,
+            watch_debounce: DEFAULT_WATCH_DEBOUNCE,
+            sources: Vec::new(),
         }
     }
 }
@@ -86,16 +135,39 @@ impl AssetPlugin {
     ///
     /// This is useful when providing a custom `AssetIo` instance that needs to
     /// delegate to the default `AssetIo` for the platform.
+    ///
+    /// It reads from [`processed_asset_folder`](Self::processed_asset_folder) unless
+    /// [`mode`](Self::mode) is [`AssetMode::Unprocessed`].
     pub fn create_platform_default_asset_io(&self) -> Box<dyn AssetIo> {
+        let folder = match self.mode {
+            AssetMode::Unprocessed => &self.asset_folder,
+            AssetMode::Processed | AssetMode::ProcessedDev => &self.processed_asset_folder,
+        };
+        self.create_platform_asset_io(folder)
+    }
+
+    /// Creates an instance of the platform's default `AssetIo` reading from `folder`, which
+    /// watches for changes if [`watch_for_changes`](Self::watch_for_changes) is enabled.
+    pub fn create_platform_asset_io(
+        &self,
+        folder: impl AsRef<std::path::Path>,
+    ) -> Box<dyn AssetIo> {
         #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
-        let source = FileAssetIo::new(&self.asset_folder, self.watch_for_changes);
+        let source = FileAssetIo::new(folder, self.watch_for_changes);
         #[cfg(target_arch = "wasm32")]
-        let source = WasmAssetIo::new(&self.asset_folder);
+        let source = WasmAssetIo::new(folder);
         #[cfg(target_os = "android")]
-        let source = AndroidAssetIo::new(&self.asset_folder);
+        let source = AndroidAssetIo::new(folder);
 
         Box::new(source)
     }
+
+    /// Adds a named asset source.
+    #[must_use]
+    pub fn with_source(mut self, source: AssetSource) -> Self {
+        self.sources.push(source);
+        self
+    }
 }
 
 impl Plugin for AssetPlugin {
@@ -105,6 +177,21 @@ impl Plugin for AssetPlugin {
             let asset_server = AssetServer::with_boxed_io(source);
             app.insert_resource(asset_server);
         }
+        {
+            let asset_server = app.world.resource::<AssetServer>();
+            asset_server.set_watch_debounce(self.watch_debounce);
+            for source in &self.sources {
+                asset_server.add_source(source.name(), source.create_io(self));
+            }
+        }
+
+        #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+        if self.mode == AssetMode::ProcessedDev && !app.world.contains_resource::<AssetProcessor>()
+        {
+            let source = Box::new(FileAssetIo::new(&self.asset_folder, false));
+            let destination = FileAssetIo::get_base_path().join(&self.processed_asset_folder);
+            app.insert_resource(AssetProcessor::new(source, destination));
+        }
 
         app.register_type::<HandleId>();
 
@@ -118,12 +205,36 @@ impl Plugin for AssetPlugin {
                 .after(CoreSet::PostUpdate)
                 .before(CoreSet::Last),
         )
-        .add_system(asset_server::free_unused_assets_system.in_base_set(CoreSet::PreUpdate));
+        .add_event::<AssetLoadFailedEvent>()
+        .add_system(asset_server::free_unused_assets_system.in_base_set(CoreSet::PreUpdate))
+        .add_system(asset_server::asset_load_failed_event_system.in_base_set(AssetSet::LoadAssets))
+        .add_event::<AssetFolderEvent>()
+        .add_system(
+            io::memory_asset_watcher_system
+                .in_base_set(AssetSet::LoadAssets)
+                .before(asset_server::reload_changed_assets_system),
+        )
+        .add_system(asset_server::reload_changed_assets_system.in_base_set(AssetSet::LoadAssets));
 
         #[cfg(all(
             feature = "filesystem_watcher",
             all(not(target_arch = "wasm32"), not(target_os = "android"))
         ))]
-        app.add_system(io::filesystem_watcher_system.in_base_set(AssetSet::LoadAssets));
+        app.add_system(
+            io::filesystem_watcher_system
+                .in_base_set(AssetSet::LoadAssets)
+                .before(asset_server::reload_changed_assets_system),
+        );
+    }
+
+    fn setup(&self, app: &mut App) {
+        // processors are registered by other plugins, so sources are processed once every plugin
+        // is built
+        #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+        if let Some(asset_processor) = app.world.get_resource::<AssetProcessor>() {
+            if let Err(err) = futures_lite::future::block_on(asset_processor.process_all()) {
+                bevy_log::error!("failed to process assets: {}", err);
+            }
+        }
     }
 }
@@ -1,20 +1,26 @@
 use crate::{
-    path::AssetPath, AssetIo, AssetIoError, AssetMeta, AssetServer, Assets, Handle, HandleId,
-    RefChangeChannel,
+    meta_file_path,
+    path::{AssetPath, SourcePathId},
+    AssetIo, AssetIoError, AssetMeta, AssetServer, AssetServerError, AssetSettings,
+    AssetSettingsError, Assets, Handle, HandleId, LoaderHints, Reader, RefChangeChannel,
 };
 use anyhow::Error;
 use anyhow::Result;
 use bevy_ecs::system::{Res, ResMut};
-use bevy_reflect::{TypeUuid, TypeUuidDynamic};
-use bevy_utils::{BoxedFuture, HashMap};
+use bevy_reflect::{TypePath, TypeUuid, TypeUuidDynamic};
+use bevy_utils::{BoxedFuture, HashMap, Uuid};
 use crossbeam_channel::{Receiver, Sender};
 use downcast_rs::{impl_downcast, Downcast};
-use std::path::Path;
+use serde::de::DeserializeOwned;
+use std::{ops::Range, path::Path};
 
 /// A loader for an asset source.
 ///
 /// Types implementing this trait are used by the asset server to load assets into their respective
 /// asset storages.
+///
+/// Per-asset settings can be read from the `.meta` file next to the asset source with
+/// [`LoadContext::loader_settings`].
 pub trait AssetLoader: Send + Sync + 'static {
     /// Processes the asset in an asynchronous closure.
     fn load<'a>(
@@ -25,18 +31,46 @@ pub trait AssetLoader: Send + Sync + 'static {
 
     /// Returns a list of extensions supported by this asset loader, without the preceding dot.
     fn extensions(&self) -> &[&str];
+
+    /// Returns the [`TypeUuid::TYPE_UUID`] of the default asset set by this loader, if it is always
+    /// of the same type.
+    ///
+    /// When several loaders support the extension of an asset loaded with
+    /// [`AssetServer::load`], the loader of the requested asset type is used.
+    fn asset_type_uuid(&self) -> Option<Uuid> {
+        None
+    }
+
+    /// Returns the byte sequences files loaded by this loader start with, if any.
+    ///
+    /// They are used to select a loader for files whose extensions aren't supported by any
+    /// loader, such as files without an extension.
+    fn magic_bytes(&self) -> &[&[u8]] {
+        &[]
+    }
+
+    /// Returns `true` if this loader reads its asset source incrementally, with
+    /// [`LoadContext::open_asset_reader`] or [`LoadContext::read_asset_range`].
+    ///
+    /// The asset server then doesn't read the whole file before loading it, and
+    /// [`AssetLoader::load`] receives empty bytes, unless they were read to select the loader
+    /// by its [`magic_bytes`](AssetLoader::magic_bytes).
+    fn streams_source(&self) -> bool {
+        false
+    }
 }
 
 /// An essential piece of data of an application.
 ///
 /// Assets are the building blocks of games. They can be anything, from images and sounds to scenes
-/// and scripts. In Bevy, an asset is any struct that has an unique type id, as shown below:
+/// and scripts. In Bevy, an asset is any struct that has an unique type id and a stable
+/// [`TypePath`], as shown below:
 ///
 /// ```rust
-/// use bevy_reflect::TypeUuid;
+/// use bevy_reflect::{TypePath, TypeUuid};
 /// use serde::Deserialize;
 ///
-/// #[derive(Debug, Deserialize, TypeUuid)]
+/// #[derive(Debug, Deserialize, TypePath, TypeUuid)]
 /// #[uuid = "39cadc56-aa9c-4543-8640-a018b74b5052"]
 /// pub struct CustomAsset {
 ///     pub value: i32,
@@ -47,13 +81,13 @@ pub trait AssetLoader: Send + Sync + 'static {
 ///
 /// In order to load assets into your game you must either add them manually to an asset storage
 /// with [`Assets::add`] or load them from the filesystem with [`AssetServer::load`].
-pub trait Asset: TypeUuid + AssetDynamic {}
+pub trait Asset: TypeUuid + TypePath + AssetDynamic {}
 
 /// An untyped version of the [`Asset`] trait.
 pub trait AssetDynamic: Downcast + TypeUuidDynamic + Send + Sync + 'static {}
 impl_downcast!(AssetDynamic);
 
-impl<T> Asset for T where T: TypeUuid + AssetDynamic + TypeUuidDynamic {}
+impl<T> Asset for T where T: TypeUuid + TypePath + AssetDynamic + TypeUuidDynamic {}
 
 impl<T> AssetDynamic for T where T: Send + Sync + 'static + TypeUuidDynamic {}
 
@@ -120,26 +154,40 @@ impl<T: Asset> From<LoadedAsset<T>> for BoxedLoadedAsset {
 /// using [`LoadContext::set_default_asset`] and sub-assets are defined with
 /// [`LoadContext::set_labeled_asset`].
 pub struct LoadContext<'a> {
+    pub(crate) asset_server: &'a AssetServer,
     pub(crate) ref_change_channel: &'a RefChangeChannel,
     pub(crate) asset_io: &'a dyn AssetIo,
     pub(crate) labeled_assets: HashMap<Option<String>, BoxedLoadedAsset>,
+    pub(crate) source: Option<&'a str>,
     pub(crate) path: &'a Path,
     pub(crate) version: usize,
+    pub(crate) settings: AssetSettings,
+    /// The asset sources being loaded, from the one loaded by the asset server to this one.
+    pub(crate) loading_sources: Vec<SourcePathId>,
+    /// The asset sources loaded with [`LoadContext::load_direct`], recursively.
+    pub(crate) load_dependencies: Vec<AssetPath<'static>>,
 }
 
 impl<'a> LoadContext<'a> {
     pub(crate) fn new(
+        asset_server: &'a AssetServer,
+        source: Option<&'a str>,
         path: &'a Path,
-        ref_change_channel: &'a RefChangeChannel,
         asset_io: &'a dyn AssetIo,
         version: usize,
+        settings: AssetSettings,
     ) -> Self {
         Self {
-            ref_change_channel,
+            asset_server,
+            ref_change_channel: &asset_server.server.asset_ref_counter.channel,
             asset_io,
             labeled_assets: Default::default(),
             version,
+            source,
             path,
+            settings,
+            loading_sources: vec![SourcePathId::new(source, path)],
+            load_dependencies: Vec::new(),
         }
     }
 
@@ -148,6 +196,28 @@ impl<'a> LoadContext<'a> {
         self.path
     }
 
+    /// Gets the name of the asset source the asset is loaded from, or [`None`] for the default
+    /// source.
+    pub fn source(&self) -> Option<&str> {
+        self.source
+    }
+
+    /// Gets the full asset path of the asset source, including the name of its asset source.
+    pub fn asset_path<'b>(&'b self, label: Option<&'b str>) -> AssetPath<'b> {
+        AssetPath::new_ref(self.path, label).with_source_opt(self.source)
+    }
+
+    /// Gets the settings read from the `.meta` file of the asset source.
+    pub fn settings(&self) -> &AssetSettings {
+        &self.settings
+    }
+
+    /// Deserializes the loader settings from the `.meta` file of the asset source, or returns
+    /// the default settings if there are none.
+    pub fn loader_settings<S: DeserializeOwned + Default>(&self) -> Result<S, AssetSettingsError> {
+        self.settings.loader_settings()
+    }
+
     /// Returns `true` if the load context contains an asset with the specified label.
     pub fn has_labeled_asset(&self, label: &str) -> bool {
         self.labeled_assets.contains_key(&Some(label.to_string()))
@@ -163,7 +233,7 @@ impl<'a> LoadContext<'a> {
         assert!(!label.is_empty());
         self.labeled_assets
             .insert(Some(label.to_string()), asset.into());
-        self.get_handle(AssetPath::new_ref(self.path(), Some(label)))
+        self.get_handle(AssetPath::new_ref(self.path(), Some(label)).with_source_opt(self.source()))
     }
 
     /// Gets a handle to an asset of type `T` from its id.
@@ -172,11 +242,110 @@ impl<'a> LoadContext<'a> {
     }
 
     /// Reads the contents of the file at the specified path through the [`AssetIo`] associated
-    /// with this context.
+    /// with this context, which is the [`AssetIo`] of the asset source the asset is loaded from.
     pub async fn read_asset_bytes<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>, AssetIoError> {
         self.asset_io.load_path(path.as_ref()).await
     }
 
+    /// Opens the file at the specified path for incremental reads through the [`AssetIo`]
+    /// associated with this context.
+    ///
+    /// Loaders of large files, for which [`AssetLoader::streams_source`] returns `true`, can open
+    /// [`LoadContext::path`] to read only the parts of the file they need.
+    pub async fn open_asset_reader<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> Result<Box<dyn Reader>, AssetIoError> {
+        self.asset_io.open_path(path.as_ref()).await
+    }
+
+    /// Reads the bytes in `range` of the file at the specified path through the [`AssetIo`]
+    /// associated with this context.
+    pub async fn read_asset_range<P: AsRef<Path>>(
+        &self,
+        path: P,
+        range: Range<u64>,
+    ) -> Result<Vec<u8>, AssetIoError> {
+        self.asset_io.read_range(path.as_ref(), range).await
+    }
+
+    /// Loads the asset at `path` with its loader and returns it, without adding it to an
+    /// [`Assets`] collection.
+    ///
+    /// This lets a loader use the value of another asset, for example the size of an image,
+    /// while loading its own assets. The asset at `path` is loaded again every time, and the
+    /// asset being loaded is reloaded when it changes.
+    ///
+    /// Only the requested asset is returned: other labeled assets of its source are dropped, so
+    /// handles to them won't resolve. Dependencies of the returned asset are queued for loading.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`AssetServerError::CyclicLoad`] if the asset at `path` is already being loaded
+    /// by this load context or one of the load contexts it was created from, and with
+    /// [`AssetServerError::IncorrectHandleType`] if the asset isn't a `T`.
+    pub async fn load_direct<'b, T: Asset>(
+        &mut self,
+        path: impl Into<AssetPath<'b>>,
+    ) -> Result<T, AssetServerError> {
+        let asset_path = path.into();
+        let source_path_id = asset_path.get_id().source_path_id();
+        if self.loading_sources.contains(&source_path_id) {
+            return Err(AssetServerError::CyclicLoad(asset_path.to_owned()));
+        }
+
+        let asset_server = self.asset_server;
+        let hints = LoaderHints::typed::<T>(&asset_path);
+        let (asset_io, settings, asset_loader, bytes) =
+            asset_server.read_source(&asset_path, &hints).await?;
+        let mut load_context = LoadContext::new(
+            asset_server,
+            asset_path.source(),
+            asset_path.path(),
+            &*asset_io,
+            self.version,
+            settings,
+        );
+        load_context.loading_sources = self.loading_sources.clone();
+        load_context.loading_sources.push(source_path_id);
+        asset_loader
+            .load(&bytes, &mut load_context)
+            .await
+            .map_err(AssetServerError::AssetLoaderError)?;
+
+        asset_io.watch_path_for_changes(asset_path.path())?;
+        if load_context.settings().as_ron().is_some() {
+            asset_io.watch_path_for_changes(&meta_file_path(asset_path.path()))?;
+        }
+        self.load_dependencies.push(
+            AssetPath::new_ref(asset_path.path(), None)
+                .with_source_opt(asset_path.source())
+                .to_owned(),
+        );
+        self.load_dependencies
+            .append(&mut load_context.load_dependencies);
+
+        let loaded_asset = load_context
+            .labeled_assets
+            .remove(&asset_path.label().map(String::from))
+            .and_then(|loaded_asset| {
+                loaded_asset
+                    .value
+                    .map(|value| (value, loaded_asset.dependencies))
+            });
+        let Some((value, dependencies)) = loaded_asset else {
+            return Err(AssetServerError::MissingLabeledAsset(asset_path.to_owned()));
+        };
+        let value = value
+            .downcast::<T>()
+            .map_err(|_| AssetServerError::IncorrectHandleType)?;
+        for dependency in dependencies {
+            let hints = LoaderHints::new(&dependency);
+            asset_server.load_untracked(dependency, false, Some(hints));
+        }
+        Ok(*value)
+    }
+
     /// Generates metadata for the assets managed by this load context.
     pub fn get_asset_metas(&self) -> Vec<AssetMeta> {
         let mut asset_metas = Vec::new();
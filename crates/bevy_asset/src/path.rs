@@ -3,14 +3,24 @@ use bevy_utils::AHasher;
 use serde::{Deserialize, Serialize};
 use std::{
     borrow::Cow,
+    fmt,
     hash::{Hash, Hasher},
     path::{Path, PathBuf},
 };
 
 /// Represents a path to an asset in the file system.
+///
+/// Asset paths are written as `source://path/to/file.ext?loader=LoaderType#label`, where the asset
+/// source name, the [loader override](Self::loader) and the sub-asset label are optional. Paths
+/// without a source are read from the default asset source, see
+/// [`AssetSource`](crate::AssetSource).
 #[derive(Debug, Eq, PartialEq, Hash, Clone, Serialize, Deserialize)]
 pub struct AssetPath<'a> {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    source: Option<Cow<'a, str>>,
     path: Cow<'a, Path>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    loader: Option<Cow<'a, str>>,
     label: Option<Cow<'a, str>>,
 }
 
@@ -19,7 +29,9 @@ impl<'a> AssetPath<'a> {
     #[inline]
     pub fn new_ref(path: &'a Path, label: Option<&'a str>) -> AssetPath<'a> {
         AssetPath {
+            source: None,
             path: Cow::Borrowed(path),
+            loader: None,
             label: label.map(Cow::Borrowed),
         }
     }
@@ -28,11 +40,55 @@ impl<'a> AssetPath<'a> {
     #[inline]
     pub fn new(path: PathBuf, label: Option<String>) -> AssetPath<'a> {
         AssetPath {
+            source: None,
             path: Cow::Owned(path),
+            loader: None,
             label: label.map(Cow::Owned),
         }
     }
 
+    /// Reads this asset path from the asset source with the given name instead of the default
+    /// one.
+    #[inline]
+    #[must_use]
+    pub fn with_source(mut self, source: impl Into<Cow<'a, str>>) -> AssetPath<'a> {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Sets the asset source this asset path is read from. [`None`] is the default source.
+    #[inline]
+    #[must_use]
+    pub fn with_source_opt(mut self, source: Option<&'a str>) -> AssetPath<'a> {
+        self.source = source.map(Cow::Borrowed);
+        self
+    }
+
+    /// Gets the name of the asset source, or [`None`] for the default source.
+    #[inline]
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    /// Loads this asset path with the loader of the given type path, instead of the loader
+    /// selected by its extension or its `.meta` file.
+    #[inline]
+    #[must_use]
+    pub fn with_loader(mut self, loader: impl Into<Cow<'a, str>>) -> AssetPath<'a> {
+        self.loader = Some(loader.into());
+        self
+    }
+
+    /// Gets the type path of the loader overriding the loader of the asset, if any.
+    ///
+    /// The override only applies to the load request that starts loading the asset, and to its
+    /// reloads. It isn't part of the [`AssetPathId`], so loading the same path with another loader
+    /// while it's loaded returns the already loaded asset.
+    #[inline]
+    pub fn loader(&self) -> Option<&str> {
+        self.loader.as_deref()
+    }
+
     /// Constructs an identifier from this asset path.
     #[inline]
     pub fn get_id(&self) -> AssetPathId {
@@ -55,7 +111,15 @@ impl<'a> AssetPath<'a> {
     #[inline]
     pub fn to_owned(&self) -> AssetPath<'static> {
         AssetPath {
+            source: self
+                .source
+                .as_ref()
+                .map(|value| Cow::Owned(value.to_string())),
             path: Cow::Owned(self.path.to_path_buf()),
+            loader: self
+                .loader
+                .as_ref()
+                .map(|value| Cow::Owned(value.to_string())),
             label: self
                 .label
                 .as_ref()
@@ -64,6 +128,22 @@ impl<'a> AssetPath<'a> {
     }
 }
 
+impl<'a> fmt::Display for AssetPath<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(source) = &self.source {
+            write!(f, "{source}://")?;
+        }
+        write!(f, "{}", self.path.display())?;
+        if let Some(loader) = &self.loader {
+            write!(f, "?loader={loader}")?;
+        }
+        if let Some(label) = &self.label {
+            write!(f, "#{label}")?;
+        }
+        Ok(())
+    }
+}
+
 /// An unique identifier to an asset path.
 #[derive(
     Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize, Reflect,
@@ -93,6 +173,24 @@ impl<'a> From<&'a Path> for SourcePathId {
     }
 }
 
+impl SourcePathId {
+    /// Creates the identifier of the source path `path` of the asset source named `source`.
+    ///
+    /// The identifiers of paths of the default asset source don't depend on its name, so they
+    /// are the same as [`SourcePathId::from`] the path.
+    pub fn new(source: Option<&str>, path: &Path) -> Self {
+        match source {
+            None => Self::from(path),
+            Some(source) => {
+                let mut hasher = get_hasher();
+                source.hash(&mut hasher);
+                path.hash(&mut hasher);
+                SourcePathId(hasher.finish())
+            }
+        }
+    }
+}
+
 impl From<AssetPathId> for SourcePathId {
     fn from(id: AssetPathId) -> Self {
         id.source_path_id()
@@ -136,29 +234,43 @@ where
 {
     fn from(value: T) -> Self {
         let asset_path: AssetPath = value.into();
-        AssetPathId(
-            SourcePathId::from(asset_path.path()),
-            LabelId::from(asset_path.label()),
-        )
+        AssetPathId::from(&asset_path)
     }
 }
 
 impl<'a, 'b> From<&'a AssetPath<'b>> for AssetPathId {
     fn from(asset_path: &'a AssetPath<'b>) -> Self {
         AssetPathId(
-            SourcePathId::from(asset_path.path()),
+            SourcePathId::new(asset_path.source(), asset_path.path()),
             LabelId::from(asset_path.label()),
         )
     }
 }
 
+/// Splits `source://path?loader=loader#label` into its parts.
+fn split_asset_path(asset_path: &str) -> (Option<&str>, &str, Option<&str>, Option<&str>) {
+    let (source, rest) = match asset_path.split_once("://") {
+        Some((source, rest)) if !source.is_empty() && !source.contains(['/', '\\', '#']) => {
+            (Some(source), rest)
+        }
+        _ => (None, asset_path),
+    };
+    let mut parts = rest.splitn(2, '#');
+    let path = parts.next().expect("Path must be set.");
+    let label = parts.next();
+    match path.split_once("?loader=") {
+        Some((path, loader)) => (source, path, Some(loader), label),
+        None => (source, path, None, label),
+    }
+}
+
 impl<'a> From<&'a str> for AssetPath<'a> {
     fn from(asset_path: &'a str) -> Self {
-        let mut parts = asset_path.splitn(2, '#');
-        let path = Path::new(parts.next().expect("Path must be set."));
-        let label = parts.next();
+        let (source, path, loader, label) = split_asset_path(asset_path);
         AssetPath {
-            path: Cow::Borrowed(path),
+            source: source.map(Cow::Borrowed),
+            path: Cow::Borrowed(Path::new(path)),
+            loader: loader.map(Cow::Borrowed),
             label: label.map(Cow::Borrowed),
         }
     }
@@ -173,7 +285,9 @@ impl<'a> From<&'a String> for AssetPath<'a> {
 impl<'a> From<&'a Path> for AssetPath<'a> {
     fn from(path: &'a Path) -> Self {
         AssetPath {
+            source: None,
             path: Cow::Borrowed(path),
+            loader: None,
             label: None,
         }
     }
@@ -182,7 +296,9 @@ impl<'a> From<&'a Path> for AssetPath<'a> {
 impl<'a> From<PathBuf> for AssetPath<'a> {
     fn from(path: PathBuf) -> Self {
         AssetPath {
+            source: None,
             path: Cow::Owned(path),
+            loader: None,
             label: None,
         }
     }
@@ -190,12 +306,82 @@ impl<'a> From<PathBuf> for AssetPath<'a> {
 
 impl<'a> From<String> for AssetPath<'a> {
     fn from(asset_path: String) -> Self {
-        let mut parts = asset_path.splitn(2, '#');
-        let path = PathBuf::from(parts.next().expect("Path must be set."));
-        let label = parts.next().map(String::from);
+        let (source, path, loader, label) = split_asset_path(&asset_path);
         AssetPath {
-            path: Cow::Owned(path),
-            label: label.map(Cow::Owned),
+            source: source.map(|source| Cow::Owned(source.to_string())),
+            path: Cow::Owned(PathBuf::from(path)),
+            loader: loader.map(|loader| Cow::Owned(loader.to_string())),
+            label: label.map(|label| Cow::Owned(label.to_string())),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_asset_path() {
+        let path = AssetPath::from("textures/player.png");
+        assert_eq!(path.source(), None);
+        assert_eq!(path.path(), Path::new("textures/player.png"));
+        assert_eq!(path.label(), None);
+
+        let path = AssetPath::from("mods://models/ship.gltf#Mesh0");
+        assert_eq!(path.source(), Some("mods"));
+        assert_eq!(path.path(), Path::new("models/ship.gltf"));
+        assert_eq!(path.label(), Some("Mesh0"));
+        assert_eq!(
+            path,
+            AssetPath::from(String::from("mods://models/ship.gltf#Mesh0"))
+        );
+        assert_eq!(path.to_string(), "mods://models/ship.gltf#Mesh0");
+
+        // `://` after a path separator or a label isn't a source
+        let path = AssetPath::from("scene.ron#http://example.com");
+        assert_eq!(path.source(), None);
+        assert_eq!(path.label(), Some("http://example.com"));
+    }
+
+    #[test]
+    fn parse_loader_override() {
+        let path = AssetPath::from("mods://data/items.ron?loader=my_mod::ItemLoader#Sword");
+        assert_eq!(path.source(), Some("mods"));
+        assert_eq!(path.path(), Path::new("data/items.ron"));
+        assert_eq!(path.loader(), Some("my_mod::ItemLoader"));
+        assert_eq!(path.label(), Some("Sword"));
+        assert_eq!(
+            path,
+            AssetPath::new_ref(Path::new("data/items.ron"), Some("Sword"))
+                .with_source("mods")
+                .with_loader("my_mod::ItemLoader")
+        );
+        assert_eq!(
+            path.to_string(),
+            "mods://data/items.ron?loader=my_mod::ItemLoader#Sword"
+        );
+
+        // the loader doesn't change which asset the path points to
+        assert_eq!(
+            path.get_id(),
+            AssetPath::from("mods://data/items.ron#Sword").get_id()
+        );
+    }
+
+    #[test]
+    fn source_changes_id() {
+        let default_path = AssetPath::from("shader.wgsl");
+        let embedded_path = AssetPath::from("embedded://shader.wgsl");
+        assert_ne!(default_path.get_id(), embedded_path.get_id());
+        assert_eq!(
+            default_path.get_id().source_path_id(),
+            SourcePathId::from(Path::new("shader.wgsl"))
+        );
+        assert_eq!(
+            embedded_path.get_id(),
+            AssetPath::from("shader.wgsl")
+                .with_source("embedded")
+                .get_id()
+        );
+    }
+}
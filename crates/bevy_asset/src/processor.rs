@@ -0,0 +1,751 @@
+//! Transforms asset sources into an optimized form ahead of time.
+//!
+//! The [`AssetProcessor`] reads every source from an [`AssetIo`], runs the [`Process`] selected
+//! by its `.meta` file (or the default processor of its extension) and writes the result to a
+//! destination folder, conventionally named `imported_assets`. An [`AssetServer`] reading from
+//! that folder then loads the processed assets instead of the sources.
+//!
+//! Sources are only reprocessed when they changed: the processor keeps a log with a hash of each
+//! source, its settings and the files its processor read through
+//! [`ProcessContext::read_dependency_bytes`].
+//!
+//! [`AssetServer`]: crate::AssetServer
+
+use crate::{
+    meta_file_path, AssetIo, AssetIoError, AssetLoader, AssetSettings, AssetSettingsError,
+};
+use anyhow::Error;
+use bevy_ecs::system::Resource;
+use bevy_log::{error, info};
+use bevy_reflect::TypePath;
+use bevy_utils::{BoxedFuture, HashMap};
+use parking_lot::RwLock;
+use serde::{de::DeserializeOwned, ser::SerializeStruct, Deserialize, Serialize, Serializer};
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use thiserror::Error;
+
+/// The name of the file in which the [`AssetProcessor`] records what it processed.
+pub const PROCESSOR_LOG_FILE: &str = "asset_processor.log";
+
+/// Transforms the bytes of an asset source into a processed asset.
+///
+/// Processors are registered on the [`AssetProcessor`] and identified by their [type path], which
+/// is referenced by the `processor` field of `.meta` files.
+///
+/// [type path]: TypePath::type_path
+pub trait Process: Send + Sync + 'static {
+    /// Processes the asset source described by `context`.
+    fn process<'a>(
+        &'a self,
+        context: &'a mut ProcessContext,
+    ) -> BoxedFuture<'a, Result<ProcessedAsset, Error>>;
+}
+
+/// The output of a [`Process`].
+pub struct ProcessedAsset {
+    bytes: Vec<u8>,
+    loader: Option<&'static str>,
+    loader_settings: Option<Box<dyn LoaderSettings>>,
+}
+
+impl ProcessedAsset {
+    /// Creates a processed asset from its bytes. It will be loaded with the loader matching the
+    /// extension of the source, with default settings.
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self {
+            bytes,
+            loader: None,
+            loader_settings: None,
+        }
+    }
+
+    /// Loads the processed asset with the loader `L` instead of the loader matching the
+    /// extension of the source.
+    #[must_use]
+    pub fn with_loader<L: AssetLoader + TypePath>(mut self) -> Self {
+        self.loader = Some(L::type_path());
+        self
+    }
+
+    /// Passes `settings` to the loader of the processed asset.
+    #[must_use]
+    pub fn with_loader_settings<S: Serialize + Send + Sync + 'static>(
+        mut self,
+        settings: S,
+    ) -> Self {
+        self.loader_settings = Some(Box::new(settings));
+        self
+    }
+
+    /// Writes the `.meta` file of the processed asset, or returns [`None`] if it doesn't need one.
+    fn meta(&self) -> Result<Option<String>, AssetSettingsError> {
+        let meta = match &self.loader_settings {
+            Some(settings) => settings.write_meta(self.loader)?,
+            None if self.loader.is_some() => write_meta::<()>(self.loader, None)?,
+            None => return Ok(None),
+        };
+        Ok(Some(meta))
+    }
+}
+
+/// Loader settings of a [`ProcessedAsset`], kept until its `.meta` file is written.
+trait LoaderSettings: Send + Sync {
+    fn write_meta(&self, loader: Option<&str>) -> Result<String, ron::Error>;
+}
+
+impl<S: Serialize + Send + Sync> LoaderSettings for S {
+    fn write_meta(&self, loader: Option<&str>) -> Result<String, ron::Error> {
+        write_meta(loader, Some(self))
+    }
+}
+
+fn write_meta<S: Serialize>(
+    loader: Option<&str>,
+    loader_settings: Option<&S>,
+) -> Result<String, ron::Error> {
+    ron::ser::to_string_pretty(
+        &ProcessedMeta {
+            loader,
+            loader_settings,
+        },
+        ron::ser::PrettyConfig::default(),
+    )
+}
+
+/// The `.meta` file of a processed asset, in the format read by [`AssetSettings`].
+struct ProcessedMeta<'a, S> {
+    loader: Option<&'a str>,
+    loader_settings: Option<&'a S>,
+}
+
+impl<'a, S: Serialize> Serialize for ProcessedMeta<'a, S> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        // fields are skipped rather than written as `None`, like they are omitted in `.meta`
+        // files written by hand
+        let mut state = serializer.serialize_struct("ProcessedMeta", 2)?;
+        match self.loader {
+            Some(loader) => state.serialize_field("loader", loader)?,
+            None => state.skip_field("loader")?,
+        }
+        match self.loader_settings {
+            Some(loader_settings) => state.serialize_field("loader_settings", loader_settings)?,
+            None => state.skip_field("loader_settings")?,
+        }
+        state.end()
+    }
+}
+
+/// The context passed to a [`Process`].
+pub struct ProcessContext<'a> {
+    path: &'a Path,
+    bytes: &'a [u8],
+    settings: &'a AssetSettings,
+    asset_io: &'a dyn AssetIo,
+    dependencies: BTreeMap<PathBuf, u64>,
+}
+
+impl<'a> ProcessContext<'a> {
+    /// Gets the path of the asset source being processed.
+    pub fn path(&self) -> &Path {
+        self.path
+    }
+
+    /// Gets the bytes of the asset source being processed.
+    pub fn asset_bytes(&self) -> &[u8] {
+        self.bytes
+    }
+
+    /// Gets the settings read from the `.meta` file of the asset source.
+    pub fn settings(&self) -> &AssetSettings {
+        self.settings
+    }
+
+    /// Deserializes the processor settings from the `.meta` file of the asset source, or returns
+    /// the default settings if there are none.
+    pub fn processor_settings<S: DeserializeOwned + Default>(
+        &self,
+    ) -> Result<S, AssetSettingsError> {
+        self.settings.processor_settings()
+    }
+
+    /// Reads another asset source that the processed output depends on.
+    ///
+    /// The asset source being processed is processed again whenever that file changes.
+    pub async fn read_dependency_bytes<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<Vec<u8>, AssetIoError> {
+        let path = path.as_ref();
+        let bytes = self.asset_io.load_path(path).await?;
+        self.dependencies
+            .insert(path.to_owned(), hash_bytes(&[&bytes]));
+        Ok(bytes)
+    }
+}
+
+/// Errors that occur while processing assets.
+#[derive(Error, Debug)]
+pub enum AssetProcessorError {
+    /// Encountered an error while reading an asset source.
+    #[error("encountered an error while reading an asset source: {0}")]
+    AssetIoError(#[from] AssetIoError),
+    /// Encountered an error while writing a processed asset.
+    #[error("encountered an error while writing {path}: {error}")]
+    WriteError {
+        /// The path that couldn't be written.
+        path: PathBuf,
+        /// The underlying error.
+        error: io::Error,
+    },
+    /// The settings of an asset source could not be read.
+    #[error("encountered an error while reading the settings of {path}: {error}")]
+    AssetSettingsError {
+        /// The path of the asset source.
+        path: PathBuf,
+        /// The underlying error.
+        error: AssetSettingsError,
+    },
+    /// The processor requested by the settings of an asset source is not registered.
+    #[error("no processor registered with the type path {0}")]
+    MissingProcessor(String),
+    /// A processor returned an error.
+    #[error("failed to process {path}: {error}")]
+    ProcessError {
+        /// The path of the asset source.
+        path: PathBuf,
+        /// The error returned by the processor.
+        error: Error,
+    },
+}
+
+/// What happened during a call to [`AssetProcessor::process_all`].
+#[derive(Debug, Default)]
+pub struct ProcessSummary {
+    /// Asset sources that were processed, or copied because they have no processor.
+    pub processed: Vec<PathBuf>,
+    /// Asset sources whose processed output was up to date.
+    pub up_to_date: Vec<PathBuf>,
+    /// Processed assets that were removed because their source doesn't exist anymore.
+    pub removed: Vec<PathBuf>,
+    /// Asset sources that failed to be processed.
+    pub failed: Vec<(PathBuf, AssetProcessorError)>,
+}
+
+/// What the [`AssetProcessor`] recorded about a processed asset source.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+struct ProcessedInfo {
+    /// Hash of the source, its `.meta` file and the name of its processor.
+    hash: u64,
+    /// Hashes of the files read by the processor.
+    dependencies: BTreeMap<PathBuf, u64>,
+}
+
+struct AssetProcessorData {
+    source_io: Box<dyn AssetIo>,
+    destination: PathBuf,
+    processors: RwLock<HashMap<&'static str, Arc<dyn Process>>>,
+    default_processors: RwLock<HashMap<String, &'static str>>,
+}
+
+/// Processes asset sources into an optimized form ahead of time.
+///
+/// See the [module documentation](crate::processor) for details.
+#[derive(Clone, Resource)]
+pub struct AssetProcessor {
+    data: Arc<AssetProcessorData>,
+}
+
+impl AssetProcessor {
+    /// Creates an asset processor reading sources from `source_io` and writing processed assets
+    /// to the `destination` folder.
+    pub fn new(source_io: Box<dyn AssetIo>, destination: impl Into<PathBuf>) -> Self {
+        Self {
+            data: Arc::new(AssetProcessorData {
+                source_io,
+                destination: destination.into(),
+                processors: Default::default(),
+                default_processors: Default::default(),
+            }),
+        }
+    }
+
+    /// Returns the folder processed assets are written to.
+    pub fn destination(&self) -> &Path {
+        &self.data.destination
+    }
+
+    /// Returns the asset I/O asset sources are read from.
+    pub fn source_io(&self) -> &dyn AssetIo {
+        &*self.data.source_io
+    }
+
+    /// Registers a processor, referenced in `.meta` files by its [type path].
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn add_processor<P: Process + TypePath>(&self, processor: P) {
+        self.data
+            .processors
+            .write()
+            .insert(P::type_path(), Arc::new(processor));
+    }
+
+    /// Processes the asset sources with the given `extension` that don't select a processor in
+    /// their `.meta` file with the processor `P`, which must be registered.
+    pub fn set_default_processor<P: Process + TypePath>(&self, extension: &str) {
+        self.data
+            .default_processors
+            .write()
+            .insert(extension.to_lowercase(), P::type_path());
+    }
+
+    fn get_processor(&self, type_path: &str) -> Result<Arc<dyn Process>, AssetProcessorError> {
+        self.data
+            .processors
+            .read()
+            .get(type_path)
+            .cloned()
+            .ok_or_else(|| AssetProcessorError::MissingProcessor(type_path.to_string()))
+    }
+
+    fn default_processor_name(&self, path: &Path) -> Option<&'static str> {
+        let file_name = path.file_name()?.to_str()?.to_lowercase();
+        let default_processors = self.data.default_processors.read();
+        let mut ext = file_name.as_str();
+        while let Some(idx) = ext.find('.') {
+            ext = &ext[idx + 1..];
+            if let Some(name) = default_processors.get(ext) {
+                return Some(name);
+            }
+        }
+        None
+    }
+
+    /// Processes every asset source whose processed output is missing or out of date, and
+    /// removes the processed assets of sources that don't exist anymore.
+    ///
+    /// Failures to process individual sources are reported in the returned [`ProcessSummary`].
+    pub async fn process_all(&self) -> Result<ProcessSummary, AssetProcessorError> {
+        let mut log = self.read_log();
+        let mut summary = ProcessSummary::default();
+
+        let mut sources = Vec::new();
+        self.collect_sources(Path::new(""), &mut sources)?;
+
+        // sources are read one at a time, and only their hashes are kept so that dependencies
+        // shared by several sources are only read once
+        let mut source_hashes = HashMap::default();
+        let mut new_log = BTreeMap::new();
+        for path in sources {
+            let bytes = match self.data.source_io.load_path(&path).await {
+                Ok(bytes) => bytes,
+                Err(err) => {
+                    // like sources that fail to be processed, the source is processed again by
+                    // the next run, and its previous output is kept
+                    log.remove(&path);
+                    let err = AssetProcessorError::from(err);
+                    error!("{}", err);
+                    summary.failed.push((path, err));
+                    continue;
+                }
+            };
+            source_hashes.insert(path.clone(), hash_bytes(&[&bytes]));
+            match self
+                .process_source(&path, &bytes, log.remove(&path), &mut source_hashes)
+                .await
+            {
+                Ok((info, processed)) => {
+                    new_log.insert(path.clone(), info);
+                    if processed {
+                        summary.processed.push(path);
+                    } else {
+                        summary.up_to_date.push(path);
+                    }
+                }
+                Err(err) => {
+                    error!("{}", err);
+                    summary.failed.push((path, err));
+                }
+            }
+        }
+
+        // the remaining entries of the old log are sources that were removed
+        for path in log.into_keys() {
+            for output in [
+                self.destination().join(&path),
+                self.destination().join(meta_file_path(&path)),
+            ] {
+                if let Err(err) = fs::remove_file(&output) {
+                    if err.kind() != io::ErrorKind::NotFound {
+                        error!("failed to remove {:?}: {}", output, err);
+                    }
+                }
+            }
+            summary.removed.push(path);
+        }
+
+        self.write_log(&new_log)?;
+        info!(
+            "processed {} asset(s), {} up to date, {} removed, {} failed",
+            summary.processed.len(),
+            summary.up_to_date.len(),
+            summary.removed.len(),
+            summary.failed.len()
+        );
+        Ok(summary)
+    }
+
+    fn collect_sources(
+        &self,
+        path: &Path,
+        sources: &mut Vec<PathBuf>,
+    ) -> Result<(), AssetProcessorError> {
+        for child in self.data.source_io.read_directory(path)? {
+            if self.data.source_io.is_dir(&child) {
+                self.collect_sources(&child, sources)?;
+            } else if child.extension().and_then(|ext| ext.to_str())
+                != Some(crate::META_FILE_EXTENSION)
+            {
+                sources.push(child);
+            }
+        }
+        Ok(())
+    }
+
+    /// Processes a single source if needed. Returns what should be logged for it, and whether
+    /// it was processed.
+    async fn process_source(
+        &self,
+        path: &Path,
+        bytes: &[u8],
+        previous: Option<ProcessedInfo>,
+        source_hashes: &mut HashMap<PathBuf, u64>,
+    ) -> Result<(ProcessedInfo, bool), AssetProcessorError> {
+        let meta_path = meta_file_path(path);
+        let meta_bytes = match self.data.source_io.load_path(&meta_path).await {
+            Ok(meta_bytes) => Some(meta_bytes),
+            Err(AssetIoError::NotFound(_)) => None,
+            Err(err) => return Err(err.into()),
+        };
+        let settings = match &meta_bytes {
+            Some(meta_bytes) => AssetSettings::from_bytes(meta_bytes).map_err(|error| {
+                AssetProcessorError::AssetSettingsError {
+                    path: path.to_owned(),
+                    error,
+                }
+            })?,
+            None => AssetSettings::default(),
+        };
+        let processor_name = settings
+            .processor()
+            .or_else(|| self.default_processor_name(path));
+
+        let hash = hash_bytes(&[
+            bytes,
+            meta_bytes.as_deref().unwrap_or_default(),
+            processor_name.unwrap_or_default().as_bytes(),
+        ]);
+        let output_path = self.destination().join(path);
+        if let Some(previous) = previous {
+            let mut dependencies_unchanged = true;
+            for (dependency, dependency_hash) in &previous.dependencies {
+                if self.source_hash(dependency, source_hashes).await != Some(*dependency_hash) {
+                    dependencies_unchanged = false;
+                    break;
+                }
+            }
+            if previous.hash == hash && dependencies_unchanged && output_path.exists() {
+                return Ok((previous, false));
+            }
+        }
+
+        let output_meta_path = self.destination().join(&meta_path);
+        let (output, output_meta, dependencies) = match processor_name {
+            Some(processor_name) => {
+                let processor = self.get_processor(processor_name)?;
+                let mut context = ProcessContext {
+                    path,
+                    bytes,
+                    settings: &settings,
+                    asset_io: &*self.data.source_io,
+                    dependencies: BTreeMap::new(),
+                };
+                let processed = processor.process(&mut context).await.map_err(|error| {
+                    AssetProcessorError::ProcessError {
+                        path: path.to_owned(),
+                        error,
+                    }
+                })?;
+                let meta =
+                    processed
+                        .meta()
+                        .map_err(|error| AssetProcessorError::AssetSettingsError {
+                            path: path.to_owned(),
+                            error,
+                        })?;
+                (
+                    processed.bytes,
+                    meta.map(String::into_bytes),
+                    context.dependencies,
+                )
+            }
+            // sources without a processor are copied as is
+            None => (bytes.to_vec(), meta_bytes, BTreeMap::new()),
+        };
+
+        write_file(&output_path, &output)?;
+        match output_meta {
+            Some(output_meta) => write_file(&output_meta_path, &output_meta)?,
+            None => {
+                if let Err(error) = fs::remove_file(&output_meta_path) {
+                    if error.kind() != io::ErrorKind::NotFound {
+                        return Err(AssetProcessorError::WriteError {
+                            path: output_meta_path,
+                            error,
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok((ProcessedInfo { hash, dependencies }, true))
+    }
+
+    /// Returns the hash of the source at `path`, reading it unless it was already hashed, or
+    /// [`None`] if it can't be read.
+    async fn source_hash(
+        &self,
+        path: &Path,
+        source_hashes: &mut HashMap<PathBuf, u64>,
+    ) -> Option<u64> {
+        if let Some(hash) = source_hashes.get(path) {
+            return Some(*hash);
+        }
+        let bytes = self.data.source_io.load_path(path).await.ok()?;
+        let hash = hash_bytes(&[&bytes]);
+        source_hashes.insert(path.to_owned(), hash);
+        Some(hash)
+    }
+
+    fn read_log(&self) -> BTreeMap<PathBuf, ProcessedInfo> {
+        fs::read(self.destination().join(PROCESSOR_LOG_FILE))
+            .ok()
+            .and_then(|bytes| ron::de::from_bytes(&bytes).ok())
+            .unwrap_or_default()
+    }
+
+    fn write_log(&self, log: &BTreeMap<PathBuf, ProcessedInfo>) -> Result<(), AssetProcessorError> {
+        let log = ron::ser::to_string_pretty(log, ron::ser::PrettyConfig::default())
+            .expect("processor log should be serializable");
+        write_file(&self.destination().join(PROCESSOR_LOG_FILE), log.as_bytes())
+    }
+}
+
+fn write_file(path: &Path, bytes: &[u8]) -> Result<(), AssetProcessorError> {
+    let write = || {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, bytes)
+    };
+    write().map_err(|error| AssetProcessorError::WriteError {
+        path: path.to_owned(),
+        error,
+    })
+}
+
+/// Hashes `parts` with the 64 bit FNV-1a algorithm, which unlike the hashers of the standard
+/// library is stable, as the hashes are persisted in the processor log.
+fn hash_bytes(parts: &[&[u8]]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in parts {
+        for byte in (part.len() as u64).to_le_bytes().iter().chain(*part) {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FileAssetIo, LoadContext, LoadedAsset};
+    use futures_lite::future::block_on;
+
+    /// Uppercases text assets, and appends the content of the file named by the settings.
+    #[derive(TypePath)]
+    struct Uppercase;
+
+    #[derive(Deserialize, Default)]
+    struct UppercaseSettings {
+        suffix_file: Option<String>,
+    }
+
+    impl Process for Uppercase {
+        fn process<'a>(
+            &'a self,
+            context: &'a mut ProcessContext,
+        ) -> BoxedFuture<'a, Result<ProcessedAsset, Error>> {
+            Box::pin(async move {
+                let settings: UppercaseSettings = context.processor_settings()?;
+                let mut text = String::from_utf8(context.asset_bytes().to_vec())?.to_uppercase();
+                if let Some(suffix_file) = settings.suffix_file {
+                    let suffix = context.read_dependency_bytes(suffix_file).await?;
+                    text.push_str(&String::from_utf8(suffix)?);
+                }
+                Ok(ProcessedAsset::new(text.into_bytes())
+                    .with_loader::<TextLoader>()
+                    .with_loader_settings(TextSettings { trim: true }))
+            })
+        }
+    }
+
+    #[derive(Serialize, Deserialize, Default)]
+    struct TextSettings {
+        trim: bool,
+    }
+
+    #[derive(TypePath)]
+    struct TextLoader;
+
+    impl AssetLoader for TextLoader {
+        fn load<'a>(
+            &'a self,
+            _bytes: &'a [u8],
+            load_context: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), Error>> {
+            Box::pin(async move {
+                load_context.set_default_asset(LoadedAsset::new(()));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["txt"]
+        }
+    }
+
+    #[test]
+    fn sources_are_processed_when_changed() {
+        let source_dir = tempfile::tempdir().unwrap();
+        let destination_dir = tempfile::tempdir().unwrap();
+        let source = source_dir.path();
+        let destination = destination_dir.path();
+
+        fs::write(source.join("plain.txt"), "plain").unwrap();
+        fs::write(
+            source.join("plain.txt.meta"),
+            "(loader_settings: (trim: false))",
+        )
+        .unwrap();
+        fs::create_dir(source.join("nested")).unwrap();
+        fs::write(source.join("nested/shout.txt"), "hello").unwrap();
+        fs::write(
+            source.join("nested/shout.txt.meta"),
+            format!(
+                "(processor: {:?}, processor_settings: (suffix_file: \"suffix.dat\"))",
+                Uppercase::type_path()
+            ),
+        )
+        .unwrap();
+        fs::write(source.join("suffix.dat"), "!").unwrap();
+
+        let processor = AssetProcessor::new(Box::new(FileAssetIo::new(source, false)), destination);
+        processor.add_processor(Uppercase);
+
+        let summary = block_on(processor.process_all()).unwrap();
+        assert_eq!(summary.processed.len(), 3);
+        assert!(summary.failed.is_empty());
+        assert_eq!(
+            fs::read_to_string(destination.join("plain.txt")).unwrap(),
+            "plain"
+        );
+        assert_eq!(
+            fs::read_to_string(destination.join("plain.txt.meta")).unwrap(),
+            "(loader_settings: (trim: false))"
+        );
+        assert_eq!(
+            fs::read_to_string(destination.join("nested/shout.txt")).unwrap(),
+            "HELLO!"
+        );
+        let meta = fs::read(destination.join("nested/shout.txt.meta")).unwrap();
+        let meta = AssetSettings::from_bytes(&meta).unwrap();
+        assert_eq!(meta.loader(), Some(TextLoader::type_path()));
+        assert!(meta.loader_settings::<TextSettings>().unwrap().trim);
+
+        // nothing changed
+        let summary = block_on(processor.process_all()).unwrap();
+        assert!(summary.processed.is_empty());
+        assert_eq!(summary.up_to_date.len(), 3);
+
+        // changing a dependency reprocesses the assets depending on it
+        fs::write(source.join("suffix.dat"), "?").unwrap();
+        let summary = block_on(processor.process_all()).unwrap();
+        assert_eq!(summary.processed.len(), 2);
+        assert!(summary
+            .processed
+            .contains(&PathBuf::from("nested/shout.txt")));
+        assert_eq!(
+            fs::read_to_string(destination.join("nested/shout.txt")).unwrap(),
+            "HELLO?"
+        );
+
+        // removed sources are removed from the output
+        fs::remove_file(source.join("plain.txt")).unwrap();
+        let summary = block_on(processor.process_all()).unwrap();
+        assert_eq!(summary.removed, vec![PathBuf::from("plain.txt")]);
+        assert!(!destination.join("plain.txt").exists());
+        assert!(!destination.join("plain.txt.meta").exists());
+    }
+
+    #[test]
+    fn missing_processor_is_reported() {
+        let source_dir = tempfile::tempdir().unwrap();
+        let destination_dir = tempfile::tempdir().unwrap();
+        fs::write(source_dir.path().join("a.txt"), "a").unwrap();
+        fs::write(
+            source_dir.path().join("a.txt.meta"),
+            "(processor: \"Missing\")",
+        )
+        .unwrap();
+
+        let processor = AssetProcessor::new(
+            Box::new(FileAssetIo::new(source_dir.path(), false)),
+            destination_dir.path(),
+        );
+        let summary = block_on(processor.process_all()).unwrap();
+        assert!(matches!(
+            summary.failed.as_slice(),
+            [(_, AssetProcessorError::MissingProcessor(name))] if name == "Missing"
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unreadable_sources_are_reported() {
+        let source_dir = tempfile::tempdir().unwrap();
+        let destination_dir = tempfile::tempdir().unwrap();
+        let source = source_dir.path();
+        let destination = destination_dir.path();
+        fs::write(source.join("a.txt"), "a").unwrap();
+        std::os::unix::fs::symlink(source.join("missing.txt"), source.join("broken.txt")).unwrap();
+
+        let processor = AssetProcessor::new(Box::new(FileAssetIo::new(source, false)), destination);
+        let summary = block_on(processor.process_all()).unwrap();
+        assert_eq!(summary.processed, vec![PathBuf::from("a.txt")]);
+        assert!(matches!(
+            summary.failed.as_slice(),
+            [(path, AssetProcessorError::AssetIoError(_))] if path == Path::new("broken.txt")
+        ));
+
+        // the log was still written for the sources that could be read
+        let summary = block_on(processor.process_all()).unwrap();
+        assert_eq!(summary.up_to_date, vec![PathBuf::from("a.txt")]);
+        assert_eq!(summary.failed.len(), 1);
+    }
+}
@@ -0,0 +1,322 @@
+use crate::{
+    Asset, AssetIoError, AssetPath, AssetServer, AssetServerError, Assets, Handle, HandleId,
+};
+use anyhow::Error;
+use bevy_ecs::{
+    event::EventWriter,
+    system::{Res, Resource},
+};
+use bevy_tasks::IoTaskPool;
+use crossbeam_channel::{Receiver, Sender};
+use std::{fmt::Debug, path::Path, sync::Arc};
+use thiserror::Error;
+
+/// A saver for an asset of type [`AssetSaver::Asset`], the counterpart of an
+/// [`AssetLoader`](crate::AssetLoader).
+///
+/// Savers are registered with [`AddAsset::add_asset_saver`](crate::AddAsset::add_asset_saver) and
+/// used by [`AssetServer::save`]. The saver is selected by the extension of the path the asset is
+/// saved to.
+pub trait AssetSaver: Send + Sync + 'static {
+    /// The type of asset saved by this saver.
+    type Asset: Asset;
+
+    /// Serializes `asset`, which is saved to `path`.
+    ///
+    /// This runs in the app's schedule, while the bytes are written in the background.
+    fn save(&self, asset: &Self::Asset, path: &Path) -> Result<Vec<u8>, Error>;
+
+    /// Returns a list of extensions supported by this asset saver, without the preceding dot.
+    fn extensions(&self) -> &[&str];
+}
+
+/// Errors that occur while saving assets with [`AssetServer::save`].
+#[derive(Error, Debug)]
+pub enum AssetSaveError {
+    /// The asset to save doesn't exist.
+    #[error("the asset to save doesn't exist")]
+    MissingAsset,
+
+    /// No asset saver was found for the extensions of the path the asset is saved to.
+    #[error("no `AssetSaver` found for the following extensions: {}", .extensions.join(", "))]
+    MissingAssetSaver {
+        /// The list of extensions detected on the asset path.
+        extensions: Vec<String>,
+    },
+
+    /// The asset saver returned an error.
+    #[error("encountered an error while saving an asset: {0}")]
+    AssetSaverError(Error),
+
+    /// The asset source of the path couldn't be found.
+    #[error(transparent)]
+    AssetServerError(#[from] AssetServerError),
+
+    /// The asset source of the path can't be written to.
+    #[error("the asset source `{}` is read-only", .0.as_deref().unwrap_or("default"))]
+    ReadOnlyAssetSource(Option<String>),
+
+    /// Encountered an error while writing the saved asset.
+    #[error("encountered an error while writing an asset: {0}")]
+    AssetIoError(#[from] AssetIoError),
+}
+
+/// An event sent when an asset queued with [`AssetServer::save`] was saved, or failed to be saved.
+pub enum AssetSaveEvent<T: Asset> {
+    /// The asset was saved.
+    Saved {
+        /// A weak handle to the saved asset.
+        handle: Handle<T>,
+        /// The path the asset was saved to.
+        path: AssetPath<'static>,
+    },
+    /// The asset couldn't be saved.
+    Failed {
+        /// A weak handle to the asset.
+        handle: Handle<T>,
+        /// The path the asset should have been saved to.
+        path: AssetPath<'static>,
+        /// Why the asset couldn't be saved.
+        error: Arc<AssetSaveError>,
+    },
+}
+
+impl<T: Asset> Debug for AssetSaveEvent<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AssetSaveEvent::Saved { handle, path } => f
+                .debug_struct(&format!(
+                    "AssetSaveEvent<{}>::Saved",
+                    std::any::type_name::<T>()
+                ))
+                .field("handle", &handle.id())
+                .field("path", path)
+                .finish(),
+            AssetSaveEvent::Failed {
+                handle,
+                path,
+                error,
+            } => f
+                .debug_struct(&format!(
+                    "AssetSaveEvent<{}>::Failed",
+                    std::any::type_name::<T>()
+                ))
+                .field("handle", &handle.id())
+                .field("path", path)
+                .field("error", error)
+                .finish(),
+        }
+    }
+}
+
+impl<T: Asset> AssetSaveEvent<T> {
+    fn new(
+        handle: Handle<T>,
+        path: AssetPath<'static>,
+        result: Result<(), AssetSaveError>,
+    ) -> Self {
+        match result {
+            Ok(()) => Self::Saved { handle, path },
+            Err(error) => Self::Failed {
+                handle,
+                path,
+                error: Arc::new(error),
+            },
+        }
+    }
+}
+
+/// A request to save an asset, queued by [`AssetServer::save`].
+pub(crate) struct SaveRequest {
+    pub(crate) id: HandleId,
+    pub(crate) path: AssetPath<'static>,
+}
+
+/// The asset savers registered for assets of type `T`.
+#[derive(Resource)]
+pub struct AssetSavers<T: Asset> {
+    savers: Vec<Arc<dyn AssetSaver<Asset = T>>>,
+    sender: Sender<AssetSaveEvent<T>>,
+    receiver: Receiver<AssetSaveEvent<T>>,
+}
+
+impl<T: Asset> Default for AssetSavers<T> {
+    fn default() -> Self {
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        Self {
+            savers: Vec::new(),
+            sender,
+            receiver,
+        }
+    }
+}
+
+impl<T: Asset> AssetSavers<T> {
+    /// Adds a saver. Savers are looked up from the last added, so for extensions supported by
+    /// several savers, the one added last is used.
+    pub fn add<S: AssetSaver<Asset = T>>(&mut self, saver: S) {
+        self.savers.push(Arc::new(saver));
+    }
+
+    /// Gets the saver for the extensions of `path`.
+    ///
+    /// Like with loaders, each level of a path with multiple dots is considered an extension, so
+    /// `level.scn.ron` is saved by a saver for `scn.ron` or `ron`.
+    pub fn get_path_saver(
+        &self,
+        path: &Path,
+    ) -> Result<Arc<dyn AssetSaver<Asset = T>>, AssetSaveError> {
+        let file_name = path
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .map(str::to_lowercase)
+            .unwrap_or_default();
+
+        let mut extensions = Vec::new();
+        let mut ext = file_name.as_str();
+        while let Some(idx) = ext.find('.') {
+            ext = &ext[idx + 1..];
+            extensions.push(ext.to_string());
+            if let Some(saver) = self
+                .savers
+                .iter()
+                .rev()
+                .find(|saver| saver.extensions().contains(&ext))
+            {
+                return Ok(saver.clone());
+            }
+        }
+        Err(AssetSaveError::MissingAssetSaver { extensions })
+    }
+}
+
+/// Saves the assets of type `T` queued with [`AssetServer::save`], and sends the
+/// [`AssetSaveEvent`]s of the saves that completed.
+///
+/// Writes happen in the background, and are reported by the first run of this system after they
+/// completed.
+pub fn save_assets_system<T: Asset>(
+    asset_server: Res<AssetServer>,
+    assets: Res<Assets<T>>,
+    savers: Res<AssetSavers<T>>,
+    mut events: EventWriter<AssetSaveEvent<T>>,
+) {
+    events.send_batch(savers.receiver.try_iter());
+
+    for request in asset_server.take_save_requests(T::TYPE_UUID) {
+        let handle = Handle::<T>::weak(request.id);
+        let serialized = assets
+            .get(&handle)
+            .ok_or(AssetSaveError::MissingAsset)
+            .and_then(|asset| {
+                let saver = savers.get_path_saver(request.path.path())?;
+                let bytes = saver
+                    .save(asset, request.path.path())
+                    .map_err(AssetSaveError::AssetSaverError)?;
+                let asset_io = asset_server.source_io(request.path.source())?;
+                Ok((asset_io, bytes))
+            });
+        let (asset_io, bytes) = match serialized {
+            Ok(serialized) => serialized,
+            Err(error) => {
+                events.send(AssetSaveEvent::new(handle, request.path, Err(error)));
+                continue;
+            }
+        };
+
+        let sender = savers.sender.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let result = match asset_io.writer() {
+                    Some(writer) => writer
+                        .write_path(request.path.path(), &bytes)
+                        .await
+                        .map_err(AssetSaveError::from),
+                    None => Err(AssetSaveError::ReadOnlyAssetSource(
+                        request.path.source().map(String::from),
+                    )),
+                };
+                // the receiver is only dropped with the app
+                let _ = sender.send(AssetSaveEvent::new(handle, request.path, result));
+            })
+            .detach();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AddAsset, AssetIo, MemoryAssetIo};
+    use bevy_app::App;
+    use bevy_ecs::event::Events;
+    use bevy_reflect::{TypePath, TypeUuid};
+
+    #[derive(Debug, TypePath, TypeUuid)]
+    #[uuid = "44115972-f31b-46e5-be5c-2b9aece6a8c3"]
+    struct Text(String);
+
+    struct TextSaver;
+
+    impl AssetSaver for TextSaver {
+        type Asset = Text;
+
+        fn save(&self, asset: &Text, _path: &Path) -> Result<Vec<u8>, Error> {
+            Ok(asset.0.clone().into_bytes())
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["txt"]
+        }
+    }
+
+    fn take_events(app: &mut App) -> Vec<AssetSaveEvent<Text>> {
+        app.world
+            .resource_mut::<Events<AssetSaveEvent<Text>>>()
+            .drain()
+            .collect()
+    }
+
+    #[test]
+    fn save_asset() {
+        IoTaskPool::init(Default::default);
+        let memory = MemoryAssetIo::new();
+        let mut app = App::new();
+        app.insert_resource(AssetServer::new(memory.clone()))
+            .add_asset::<Text>()
+            .add_asset_saver(TextSaver);
+
+        let handle = app
+            .world
+            .resource_mut::<Assets<Text>>()
+            .add(Text("saved".to_string()));
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        asset_server.save(&handle, "notes/a.txt");
+        asset_server.save(&handle, "notes/a.png");
+
+        app.update();
+        // the failure is known right away, the write happens in the background
+        assert!(matches!(
+            take_events(&mut app).as_slice(),
+            [AssetSaveEvent::Failed { error, path, .. }]
+                if matches!(**error, AssetSaveError::MissingAssetSaver { .. })
+                    && path.path() == Path::new("notes/a.png")
+        ));
+
+        // wait for the write to complete, without taking its result from the system
+        {
+            let mut select = crossbeam_channel::Select::new();
+            select.recv(&app.world.resource::<AssetSavers<Text>>().receiver);
+            select.ready();
+        }
+        app.update();
+        assert!(matches!(
+            take_events(&mut app).as_slice(),
+            [AssetSaveEvent::Saved { handle: saved, path }]
+                if saved.id() == handle.id() && path.path() == Path::new("notes/a.txt")
+        ));
+        assert_eq!(
+            futures_lite::future::block_on(memory.load_path(Path::new("notes/a.txt"))).unwrap(),
+            b"saved"
+        );
+    }
+}
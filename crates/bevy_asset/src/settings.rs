@@ -0,0 +1,207 @@
+use ron::extensions::Extensions;
+use serde::{de::DeserializeOwned, Deserialize};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The extension of the settings files stored next to asset sources.
+///
+/// The settings of `textures/player.png` are read from `textures/player.png.meta`.
+pub const META_FILE_EXTENSION: &str = "meta";
+
+/// Returns the path of the `.meta` file holding the settings of the asset source at `path`.
+pub fn meta_file_path(path: &Path) -> PathBuf {
+    let mut meta_path = path.as_os_str().to_owned();
+    meta_path.push(".");
+    meta_path.push(META_FILE_EXTENSION);
+    meta_path.into()
+}
+
+/// Errors that occur while reading [`AssetSettings`].
+#[derive(Error, Debug)]
+pub enum AssetSettingsError {
+    /// The `.meta` file is not valid RON, or doesn't match the expected settings type.
+    #[error("invalid asset settings: {0}")]
+    InvalidRon(#[from] ron::error::SpannedError),
+    /// The settings could not be serialized to RON.
+    #[error("failed to serialize asset settings: {0}")]
+    Serialize(#[from] ron::Error),
+}
+
+#[derive(Deserialize)]
+struct AssetSettingsHeader {
+    #[serde(default)]
+    loader: Option<String>,
+    #[serde(default)]
+    processor: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct LoaderSettingsField<S: Default> {
+    #[serde(default)]
+    loader_settings: S,
+}
+
+#[derive(Deserialize)]
+struct ProcessorSettingsField<S: Default> {
+    #[serde(default)]
+    processor_settings: S,
+}
+
+/// The settings of an asset source, read from the `.meta` file next to it.
+///
+/// A `.meta` file is a RON struct in which every field is optional:
+///
+/// ```ron
+/// (
+///     // the type path of the `AssetLoader` to use instead of the one matching the extension
+///     loader: "my_game::CompressedTextureLoader",
+///     // settings passed to the loader, see `LoadContext::loader_settings`
+///     loader_settings: (
+///         is_srgb: false,
+///     ),
+///     // the type path of the `Process` run on the source by the `AssetProcessor`
+///     processor: "my_game::CompressTexture",
+///     // settings passed to the processor, see `ProcessContext::processor_settings`
+///     processor_settings: (
+///         quality: High,
+///     ),
+/// )
+/// ```
+///
+/// Sources without a `.meta` file use the default settings of their loader and processor.
+#[derive(Clone, Debug, Default)]
+pub struct AssetSettings {
+    ron: Option<String>,
+    loader: Option<String>,
+    processor: Option<String>,
+}
+
+impl AssetSettings {
+    fn options() -> ron::Options {
+        ron::Options::default().with_default_extension(Extensions::IMPLICIT_SOME)
+    }
+
+    /// Parses the content of a `.meta` file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AssetSettingsError> {
+        let header: AssetSettingsHeader = Self::options().from_bytes(bytes)?;
+        Ok(Self {
+            ron: Some(String::from_utf8_lossy(bytes).into_owned()),
+            loader: header.loader,
+            processor: header.processor,
+        })
+    }
+
+    /// Returns the content of the `.meta` file, or [`None`] if the source doesn't have one.
+    pub fn as_ron(&self) -> Option<&str> {
+        self.ron.as_deref()
+    }
+
+    /// Returns the type path of the [`AssetLoader`](crate::AssetLoader) the source should be
+    /// loaded with, if it overrides the loader matching its extension.
+    pub fn loader(&self) -> Option<&str> {
+        self.loader.as_deref()
+    }
+
+    /// Returns the type path of the [`Process`](crate::Process) the source should be processed
+    /// with, if any.
+    pub fn processor(&self) -> Option<&str> {
+        self.processor.as_deref()
+    }
+
+    /// Deserializes the `loader_settings` field, or returns the default settings if there is none.
+    pub fn loader_settings<S: DeserializeOwned + Default>(&self) -> Result<S, AssetSettingsError> {
+        match &self.ron {
+            Some(ron) => Ok(Self::options()
+                .from_str::<LoaderSettingsField<S>>(ron)?
+                .loader_settings),
+            None => Ok(S::default()),
+        }
+    }
+
+    /// Deserializes the `processor_settings` field, or returns the default settings if there is
+    /// none.
+    pub fn processor_settings<S: DeserializeOwned + Default>(
+        &self,
+    ) -> Result<S, AssetSettingsError> {
+        match &self.ron {
+            Some(ron) => Ok(Self::options()
+                .from_str::<ProcessorSettingsField<S>>(ron)?
+                .processor_settings),
+            None => Ok(S::default()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize, Default, Debug, PartialEq)]
+    enum Filter {
+        #[default]
+        Linear,
+        Nearest,
+    }
+
+    #[derive(Deserialize, Default, Debug, PartialEq)]
+    #[serde(default)]
+    struct ImageSettings {
+        is_srgb: bool,
+        filter: Filter,
+        scale: Option<f32>,
+    }
+
+    #[test]
+    fn meta_file_path_appends_extension() {
+        assert_eq!(
+            meta_file_path(Path::new("textures/player.png")),
+            PathBuf::from("textures/player.png.meta")
+        );
+    }
+
+    #[test]
+    fn missing_settings_are_defaulted() {
+        let settings = AssetSettings::default();
+        assert_eq!(settings.loader(), None);
+        assert_eq!(settings.processor(), None);
+        assert_eq!(
+            settings.loader_settings::<ImageSettings>().unwrap(),
+            ImageSettings::default()
+        );
+
+        let settings = AssetSettings::from_bytes(b"(processor: \"Compress\")").unwrap();
+        assert_eq!(settings.processor(), Some("Compress"));
+        assert_eq!(
+            settings.loader_settings::<ImageSettings>().unwrap(),
+            ImageSettings::default()
+        );
+    }
+
+    #[test]
+    fn settings_are_deserialized() {
+        let settings = AssetSettings::from_bytes(
+            br#"(
+                loader: "ImageLoader",
+                loader_settings: (is_srgb: true, filter: Nearest, scale: 2.0),
+                processor_settings: (is_srgb: "yes"),
+            )"#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.loader(), Some("ImageLoader"));
+        assert_eq!(
+            settings.loader_settings::<ImageSettings>().unwrap(),
+            ImageSettings {
+                is_srgb: true,
+                filter: Filter::Nearest,
+                scale: Some(2.0),
+            }
+        );
+        assert!(settings.processor_settings::<ImageSettings>().is_err());
+    }
+
+    #[test]
+    fn invalid_meta_is_an_error() {
+        assert!(AssetSettings::from_bytes(b"(loader: ").is_err());
+    }
+}
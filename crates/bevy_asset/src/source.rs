@@ -0,0 +1,71 @@
+use crate::{AssetIo, AssetPlugin};
+use std::{fmt, path::PathBuf, sync::Arc};
+
+/// The name of the built-in asset source backed by assets compiled into the binary.
+///
+/// See [`MemoryAssetIo`](crate::MemoryAssetIo).
+pub const EMBEDDED_ASSET_SOURCE: &str = "embedded";
+
+/// A named asset source, registered on the [`AssetPlugin`].
+///
+/// Every asset source has its own [`AssetIo`], and assets are loaded from it with asset paths
+/// like `source_name://path/to/file.ext`. Paths without a source name are loaded from the default
+/// source, which reads from [`AssetPlugin::asset_folder`].
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_asset::{AssetPlugin, AssetSource};
+/// App::new().add_plugin(AssetPlugin {
+///     sources: vec![AssetSource::folder("mods", "mods")],
+///     ..Default::default()
+/// });
+/// // assets can then be loaded with `asset_server.load("mods://ships/frigate.gltf")`
+/// ```
+#[derive(Clone)]
+pub struct AssetSource {
+    name: String,
+    create_io: Arc<CreateAssetIo>,
+}
+
+type CreateAssetIo = dyn Fn(&AssetPlugin) -> Box<dyn AssetIo> + Send + Sync;
+
+impl AssetSource {
+    /// Creates an asset source whose [`AssetIo`] is created by `create_io` when the
+    /// [`AssetPlugin`] is built.
+    pub fn new(
+        name: impl Into<String>,
+        create_io: impl Fn(&AssetPlugin) -> Box<dyn AssetIo> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            create_io: Arc::new(create_io),
+        }
+    }
+
+    /// Creates an asset source reading from `folder` with the platform's default [`AssetIo`].
+    ///
+    /// Like the default source, it watches for changes if
+    /// [`AssetPlugin::watch_for_changes`] is enabled.
+    pub fn folder(name: impl Into<String>, folder: impl Into<PathBuf>) -> Self {
+        let folder = folder.into();
+        Self::new(name, move |plugin| plugin.create_platform_asset_io(&folder))
+    }
+
+    /// Returns the name of the asset source.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Creates the [`AssetIo`] of the asset source.
+    pub fn create_io(&self, plugin: &AssetPlugin) -> Box<dyn AssetIo> {
+        (self.create_io)(plugin)
+    }
+}
+
+impl fmt::Debug for AssetSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AssetSource")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
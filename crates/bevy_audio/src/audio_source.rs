@@ -1,11 +1,11 @@
 use anyhow::Result;
 use bevy_asset::{Asset, AssetLoader, LoadContext, LoadedAsset};
-use bevy_reflect::TypeUuid;
-use bevy_utils::BoxedFuture;
+use bevy_reflect::{TypePath, TypeUuid};
+use bevy_utils::{BoxedFuture, Uuid};
 use std::{io::Cursor, sync::Arc};
 
 /// A source of audio data
-#[derive(Debug, Clone, TypeUuid)]
+#[derive(Debug, Clone, TypePath, TypeUuid)]
 #[uuid = "7a14806a-672b-443b-8d16-4f18afefa463"]
 pub struct AudioSource {
     /// Raw data of the audio source.
@@ -34,7 +34,7 @@ impl AsRef<[u8]> for AudioSource {
 /// `.mp3` with `bevy/mp3`
 /// `.flac` with `bevy/flac`
 /// `.wav` with `bevy/wav`
-#[derive(Default)]
+#[derive(Default, TypePath)]
 pub struct AudioLoader;
 
 impl AssetLoader for AudioLoader {
@@ -61,6 +61,10 @@ impl AssetLoader for AudioLoader {
             "spx",
         ]
     }
+
+    fn asset_type_uuid(&self) -> Option<Uuid> {
+        Some(AudioSource::TYPE_UUID)
+    }
 }
 
 /// A type implementing this trait can be converted to a [`rodio::Source`] type.
@@ -1,5 +1,5 @@
 use bevy_math::Vec3;
-use bevy_reflect::TypeUuid;
+use bevy_reflect::{TypePath, TypeUuid};
 use bevy_transform::prelude::Transform;
 use rodio::{Sink, SpatialSink};
 
@@ -84,7 +84,7 @@ pub trait AudioSinkPlayback {
 /// }
 /// ```
 ///
-#[derive(TypeUuid)]
+#[derive(TypePath, TypeUuid)]
 #[uuid = "8BEE570C-57C2-4FC0-8CFB-983A22F7D981"]
 pub struct AudioSink {
     // This field is an Option in order to allow us to have a safe drop that will detach the sink.
@@ -151,7 +151,7 @@ impl AudioSinkPlayback for AudioSink {
 /// }
 /// ```
 ///
-#[derive(TypeUuid)]
+#[derive(TypePath, TypeUuid)]
 #[uuid = "F3CA4C47-595E-453B-96A7-31C3DDF2A177"]
 pub struct SpatialAudioSink {
     // This field is an Option in order to allow us to have a safe drop that will detach the sink.
@@ -48,6 +48,9 @@ pub struct TaskPoolOptions {
     pub async_compute: TaskPoolThreadAssignmentPolicy,
     /// Used to determine number of compute threads to allocate
     pub compute: TaskPoolThreadAssignmentPolicy,
+    /// If true, idle compute threads will run tasks queued on the async compute and IO pools.
+    /// This helps when the other pools are saturated while the compute pool is waiting for work.
+    pub compute_work_stealing: bool,
 }
 
 impl Default for TaskPoolOptions {
@@ -77,6 +80,8 @@ impl Default for TaskPoolOptions {
                 max_threads: std::usize::MAX,
                 percent: 1.0, // This 1.0 here means "whatever is left over"
             },
+
+            compute_work_stealing: false,
         }
     }
 }
@@ -143,10 +148,15 @@ impl TaskPoolOptions {
             trace!("Compute Threads: {}", compute_threads);
 
             ComputeTaskPool::init(|| {
-                TaskPoolBuilder::default()
+                let mut builder = TaskPoolBuilder::default()
                     .num_threads(compute_threads)
-                    .thread_name("Compute Task Pool".to_string())
-                    .build()
+                    .thread_name("Compute Task Pool".to_string());
+                if self.compute_work_stealing {
+                    builder = builder
+                        .steal_from(AsyncComputeTaskPool::get())
+                        .steal_from(IoTaskPool::get());
+                }
+                builder.build()
             });
         }
     }
@@ -0,0 +1,134 @@
+use crate::{Diagnostic, DiagnosticId, Diagnostics};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_tasks::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool, TaskPool, TaskPoolStatistics};
+
+/// Adds task pool diagnostics to an App: the number of queued tasks, the number of active threads,
+/// the utilization (in %) and the average scope time (in ms) of the compute, async compute and IO
+/// task pools.
+#[derive(Default)]
+pub struct TaskPoolDiagnosticsPlugin;
+
+impl Plugin for TaskPoolDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_startup_system(Self::setup_system)
+            .add_system(Self::diagnostic_system);
+    }
+}
+
+/// The diagnostics reported for a single task pool.
+struct PoolDiagnosticIds {
+    queued_tasks: DiagnosticId,
+    active_threads: DiagnosticId,
+    utilization: DiagnosticId,
+    scope_time: DiagnosticId,
+}
+
+impl TaskPoolDiagnosticsPlugin {
+    pub const COMPUTE_QUEUED_TASKS: DiagnosticId =
+        DiagnosticId::from_u128(240187815280494982166827574873561841477);
+    pub const COMPUTE_ACTIVE_THREADS: DiagnosticId =
+        DiagnosticId::from_u128(67781904084923148240487182902274908316);
+    pub const COMPUTE_UTILIZATION: DiagnosticId =
+        DiagnosticId::from_u128(285139610610594430880472129241031530693);
+    pub const COMPUTE_SCOPE_TIME: DiagnosticId =
+        DiagnosticId::from_u128(166688771943915890785540821104368432008);
+    pub const ASYNC_COMPUTE_QUEUED_TASKS: DiagnosticId =
+        DiagnosticId::from_u128(178774268731731547662540713425761362576);
+    pub const ASYNC_COMPUTE_ACTIVE_THREADS: DiagnosticId =
+        DiagnosticId::from_u128(224675586626071048613598752228989811804);
+    pub const ASYNC_COMPUTE_UTILIZATION: DiagnosticId =
+        DiagnosticId::from_u128(312496456204638555487126732128503270284);
+    pub const ASYNC_COMPUTE_SCOPE_TIME: DiagnosticId =
+        DiagnosticId::from_u128(102541858350382575799205469724823126815);
+    pub const IO_QUEUED_TASKS: DiagnosticId =
+        DiagnosticId::from_u128(232365163691927692719033186890215523490);
+    pub const IO_ACTIVE_THREADS: DiagnosticId =
+        DiagnosticId::from_u128(119388064033057145300176504491765337993);
+    pub const IO_UTILIZATION: DiagnosticId =
+        DiagnosticId::from_u128(128787430777923328073076174834772492478);
+    pub const IO_SCOPE_TIME: DiagnosticId =
+        DiagnosticId::from_u128(152229758018769937039713688169762273271);
+
+    const POOLS: [(&'static str, PoolDiagnosticIds); 3] = [
+        (
+            "compute",
+            PoolDiagnosticIds {
+                queued_tasks: Self::COMPUTE_QUEUED_TASKS,
+                active_threads: Self::COMPUTE_ACTIVE_THREADS,
+                utilization: Self::COMPUTE_UTILIZATION,
+                scope_time: Self::COMPUTE_SCOPE_TIME,
+            },
+        ),
+        (
+            "async_compute",
+            PoolDiagnosticIds {
+                queued_tasks: Self::ASYNC_COMPUTE_QUEUED_TASKS,
+                active_threads: Self::ASYNC_COMPUTE_ACTIVE_THREADS,
+                utilization: Self::ASYNC_COMPUTE_UTILIZATION,
+                scope_time: Self::ASYNC_COMPUTE_SCOPE_TIME,
+            },
+        ),
+        (
+            "io",
+            PoolDiagnosticIds {
+                queued_tasks: Self::IO_QUEUED_TASKS,
+                active_threads: Self::IO_ACTIVE_THREADS,
+                utilization: Self::IO_UTILIZATION,
+                scope_time: Self::IO_SCOPE_TIME,
+            },
+        ),
+    ];
+
+    pub fn setup_system(mut diagnostics: ResMut<Diagnostics>) {
+        for (pool, ids) in &Self::POOLS {
+            diagnostics.add(Diagnostic::new(
+                ids.queued_tasks,
+                format!("{pool}_queued_tasks"),
+                20,
+            ));
+            diagnostics.add(Diagnostic::new(
+                ids.active_threads,
+                format!("{pool}_active_threads"),
+                20,
+            ));
+            diagnostics.add(
+                Diagnostic::new(ids.utilization, format!("{pool}_utilization"), 20)
+                    .with_suffix("%"),
+            );
+            diagnostics.add(
+                Diagnostic::new(ids.scope_time, format!("{pool}_scope_time"), 20).with_suffix("ms"),
+            );
+        }
+    }
+
+    pub fn diagnostic_system(
+        mut diagnostics: ResMut<Diagnostics>,
+        mut previous: Local<Option<[TaskPoolStatistics; 3]>>,
+    ) {
+        let pools: [&TaskPool; 3] = [
+            ComputeTaskPool::get(),
+            AsyncComputeTaskPool::get(),
+            IoTaskPool::get(),
+        ];
+        let current = pools.map(TaskPool::statistics);
+
+        if let Some(previous) = previous.as_ref() {
+            for (((_, ids), current), previous) in
+                Self::POOLS.iter().zip(&current).zip(previous.iter())
+            {
+                diagnostics.add_measurement(ids.queued_tasks, || current.queued_tasks as f64);
+                diagnostics.add_measurement(ids.active_threads, || current.active_threads as f64);
+                diagnostics.add_measurement(ids.utilization, || {
+                    current.utilization_since(previous) * 100.0
+                });
+                if let Some(scope_time) = current.average_scope_duration_since(previous) {
+                    diagnostics
+                        .add_measurement(ids.scope_time, || scope_time.as_secs_f64() * 1000.0);
+                }
+            }
+        }
+
+        *previous = Some(current);
+    }
+}
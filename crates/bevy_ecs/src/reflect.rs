@@ -403,7 +403,7 @@ impl<C: Resource + Reflect + FromWorld> FromType<C> for ReflectResource {
     }
 }
 
-impl_reflect_value!(Entity(Hash, PartialEq, Serialize, Deserialize));
+impl_reflect_value!(::bevy_ecs::entity::Entity(Hash, PartialEq, Serialize, Deserialize));
 impl_from_reflect_value!(Entity);
 
 #[derive(Clone)]
@@ -435,3 +435,34 @@ impl<C: Component + MapEntities> FromType<C> for ReflectMapEntities {
         }
     }
 }
+
+/// For a specific type of resource, this maps any fields with values of type [`Entity`] to a new
+/// world, like [`ReflectMapEntities`] does for components.
+#[derive(Clone)]
+pub struct ReflectMapEntitiesResource {
+    map_entities: fn(&mut World, &EntityMap) -> Result<(), MapEntitiesError>,
+}
+
+impl ReflectMapEntitiesResource {
+    /// Maps the entities stored in the resource of `world`, if it has one, using `entity_map`.
+    pub fn map_entities(
+        &self,
+        world: &mut World,
+        entity_map: &EntityMap,
+    ) -> Result<(), MapEntitiesError> {
+        (self.map_entities)(world, entity_map)
+    }
+}
+
+impl<R: Resource + MapEntities> FromType<R> for ReflectMapEntitiesResource {
+    fn from_type() -> Self {
+        ReflectMapEntitiesResource {
+            map_entities: |world, entity_map| {
+                if let Some(mut resource) = world.get_resource_mut::<R>() {
+                    resource.map_entities(entity_map)?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
@@ -1,7 +1,5 @@
 use anyhow::Result;
-use bevy_asset::{
-    AssetIoError, AssetLoader, AssetPath, BoxedFuture, Handle, LoadContext, LoadedAsset,
-};
+use bevy_asset::{AssetIoError, AssetLoader, BoxedFuture, Handle, LoadContext, LoadedAsset};
 use bevy_core::Name;
 use bevy_core_pipeline::prelude::Camera3d;
 use bevy_ecs::{entity::Entity, prelude::FromWorld, world::World};
@@ -12,6 +10,7 @@ use bevy_pbr::{
     AlphaMode, DirectionalLight, DirectionalLightBundle, PbrBundle, PointLight, PointLightBundle,
     SpotLight, SpotLightBundle, StandardMaterial,
 };
+use bevy_reflect::{TypePath, TypeUuid};
 use bevy_render::{
     camera::{
         Camera, CameraRenderGraph, OrthographicProjection, PerspectiveProjection, Projection,
@@ -34,7 +33,7 @@ use bevy_scene::Scene;
 use bevy_tasks::IoTaskPool;
 use bevy_transform::components::Transform;
 
-use bevy_utils::{HashMap, HashSet};
+use bevy_utils::{HashMap, HashSet, Uuid};
 use gltf::{
     mesh::Mode,
     texture::{MagFilter, MinFilter, WrappingMode},
@@ -71,6 +70,7 @@ pub enum GltfError {
 }
 
 /// Loads glTF files with all of their data as their corresponding bevy representations.
+#[derive(TypePath)]
 pub struct GltfLoader {
     supported_compressed_formats: CompressedImageFormats,
 }
@@ -89,6 +89,15 @@ impl AssetLoader for GltfLoader {
     fn extensions(&self) -> &[&str] {
         &["gltf", "glb"]
     }
+
+    fn asset_type_uuid(&self) -> Option<Uuid> {
+        Some(Gltf::TYPE_UUID)
+    }
+
+    fn magic_bytes(&self) -> &[&[u8]] {
+        // binary glTF files start with the `glTF` magic
+        &[b"glTF"]
+    }
 }
 
 impl FromWorld for GltfLoader {
@@ -641,7 +650,7 @@ fn load_material(material: &Material, load_context: &mut LoadContext) -> Handle<
     let base_color_texture = pbr.base_color_texture().map(|info| {
         // TODO: handle info.tex_coord() (the *set* index for the right texcoords)
         let label = texture_label(&info.texture());
-        let path = AssetPath::new_ref(load_context.path(), Some(&label));
+        let path = load_context.asset_path(Some(&label));
         load_context.get_handle(path)
     });
 
@@ -650,14 +659,14 @@ fn load_material(material: &Material, load_context: &mut LoadContext) -> Handle<
             // TODO: handle normal_texture.scale
             // TODO: handle normal_texture.tex_coord() (the *set* index for the right texcoords)
             let label = texture_label(&normal_texture.texture());
-            let path = AssetPath::new_ref(load_context.path(), Some(&label));
+            let path = load_context.asset_path(Some(&label));
             load_context.get_handle(path)
         });
 
     let metallic_roughness_texture = pbr.metallic_roughness_texture().map(|info| {
         // TODO: handle info.tex_coord() (the *set* index for the right texcoords)
         let label = texture_label(&info.texture());
-        let path = AssetPath::new_ref(load_context.path(), Some(&label));
+        let path = load_context.asset_path(Some(&label));
         load_context.get_handle(path)
     });
 
@@ -665,7 +674,7 @@ fn load_material(material: &Material, load_context: &mut LoadContext) -> Handle<
         // TODO: handle occlusion_texture.tex_coord() (the *set* index for the right texcoords)
         // TODO: handle occlusion_texture.strength() (a scalar multiplier for occlusion strength)
         let label = texture_label(&occlusion_texture.texture());
-        let path = AssetPath::new_ref(load_context.path(), Some(&label));
+        let path = load_context.asset_path(Some(&label));
         load_context.get_handle(path)
     });
 
@@ -674,7 +683,7 @@ fn load_material(material: &Material, load_context: &mut LoadContext) -> Handle<
         // TODO: handle occlusion_texture.tex_coord() (the *set* index for the right texcoords)
         // TODO: handle occlusion_texture.strength() (a scalar multiplier for occlusion strength)
         let label = texture_label(&info.texture());
-        let path = AssetPath::new_ref(load_context.path(), Some(&label));
+        let path = load_context.asset_path(Some(&label));
         load_context.get_handle(path)
     });
 
@@ -791,10 +800,8 @@ fn load_node(
 
                 let primitive_label = primitive_label(&mesh, &primitive);
                 let bounds = primitive.bounding_box();
-                let mesh_asset_path =
-                    AssetPath::new_ref(load_context.path(), Some(&primitive_label));
-                let material_asset_path =
-                    AssetPath::new_ref(load_context.path(), Some(&material_label));
+                let mesh_asset_path = load_context.asset_path(Some(&primitive_label));
+                let material_asset_path = load_context.asset_path(Some(&material_label));
 
                 let mut mesh_entity = parent.spawn(PbrBundle {
                     mesh: load_context.get_handle(mesh_asset_path),
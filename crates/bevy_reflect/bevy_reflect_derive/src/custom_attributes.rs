@@ -0,0 +1,79 @@
+//! Contains code related to custom attributes for reflected types.
+//!
+//! A custom attribute is an arbitrary value attached to a type, field or variant
+//! by prefixing an expression with `@` in the derive helper attribute for `Reflect`,
+//! such as `#[reflect(@0.0..=1.0)]`. The value is stored in the corresponding type info.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{Attribute, Expr, NestedMeta, Path, Token};
+
+/// The custom attributes defined on a reflected type, field or variant.
+#[derive(Default)]
+pub(crate) struct CustomAttributes {
+    attributes: Vec<Expr>,
+}
+
+impl CustomAttributes {
+    /// Adds the expression of a custom attribute.
+    pub fn push(&mut self, attribute: Expr) {
+        self.attributes.push(attribute);
+    }
+
+    /// Returns the `.with_custom_attributes(...)` builder call to append to a type info,
+    /// field or variant constructor, or `None` if there are no custom attributes.
+    pub fn to_builder_call(&self, bevy_reflect_path: &Path) -> Option<TokenStream> {
+        if self.attributes.is_empty() {
+            return None;
+        }
+
+        let attributes = &self.attributes;
+        Some(quote! {
+            .with_custom_attributes(
+                #bevy_reflect_path::CustomAttributes::default()
+                    #(.with_attribute(#attributes))*
+            )
+        })
+    }
+}
+
+/// A single argument of a `#[reflect(...)]` attribute.
+enum ReflectArg {
+    /// A custom attribute, such as `@0.0..=1.0`.
+    Custom(Expr),
+    /// Any other argument, such as `ignore` or `PartialEq`.
+    Meta(NestedMeta),
+}
+
+impl Parse for ReflectArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![@]) {
+            input.parse::<Token![@]>()?;
+            Ok(Self::Custom(input.parse()?))
+        } else {
+            Ok(Self::Meta(input.parse()?))
+        }
+    }
+}
+
+/// Parses the arguments of a `#[reflect(...)]` attribute.
+///
+/// Custom attributes are added to `custom_attributes`, and the remaining arguments are returned.
+pub(crate) fn parse_reflect_args(
+    attr: &Attribute,
+    custom_attributes: &mut CustomAttributes,
+) -> Result<Punctuated<NestedMeta, Comma>, syn::Error> {
+    let args = attr.parse_args_with(Punctuated::<ReflectArg, Comma>::parse_terminated)?;
+
+    let mut nested = Punctuated::new();
+    for arg in args {
+        match arg {
+            ReflectArg::Custom(expr) => custom_attributes.push(expr),
+            ReflectArg::Meta(meta) => nested.push(meta),
+        }
+    }
+    Ok(nested)
+}
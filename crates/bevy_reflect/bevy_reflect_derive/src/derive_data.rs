@@ -1,6 +1,8 @@
 use crate::container_attributes::ReflectTraits;
+use crate::custom_attributes::{parse_reflect_args, CustomAttributes};
 use crate::field_attributes::{parse_field_attrs, ReflectFieldAttr};
 use crate::fq_std::{FQAny, FQDefault, FQSend, FQSync};
+use crate::type_path::TypePathOptions;
 use crate::utility::{members_to_serialization_denylist, WhereClauseOptions};
 use bit_set::BitSet;
 use quote::quote;
@@ -38,6 +40,10 @@ pub(crate) struct ReflectMeta<'a> {
     type_name: &'a Ident,
     /// The generics defined on this type.
     generics: &'a Generics,
+    /// The path and name given to this type by its `TypePath` impl.
+    type_path: TypePathOptions,
+    /// Custom attributes created with `#[reflect(@...)]`.
+    custom_attributes: CustomAttributes,
     /// A cached instance of the path to the `bevy_reflect` crate.
     bevy_reflect_path: Path,
     /// The documentation for this type, if any
@@ -102,7 +108,6 @@ pub(crate) struct EnumVariant<'a> {
     /// The fields within this variant.
     pub fields: EnumVariantFields<'a>,
     /// The reflection-based attributes on the variant.
-    #[allow(dead_code)]
     pub attrs: ReflectFieldAttr,
     /// The index of this variant within the enum.
     #[allow(dead_code)]
@@ -130,26 +135,34 @@ enum ReflectMode {
 impl<'a> ReflectDerive<'a> {
     pub fn from_input(input: &'a DeriveInput) -> Result<Self, syn::Error> {
         let mut traits = ReflectTraits::default();
+        let mut custom_attributes = CustomAttributes::default();
         // Should indicate whether `#[reflect_value]` was used
         let mut reflect_mode = None;
 
         #[cfg(feature = "documentation")]
         let mut doc = crate::documentation::Documentation::default();
 
-        for attribute in input.attrs.iter().filter_map(|attr| attr.parse_meta().ok()) {
-            match attribute {
-                Meta::List(meta_list) if meta_list.path.is_ident(REFLECT_ATTRIBUTE_NAME) => {
-                    if !matches!(reflect_mode, None | Some(ReflectMode::Normal)) {
-                        return Err(syn::Error::new(
-                            meta_list.span(),
-                            format_args!("cannot use both `#[{REFLECT_ATTRIBUTE_NAME}]` and `#[{REFLECT_VALUE_ATTRIBUTE_NAME}]`"),
-                        ));
-                    }
-
-                    reflect_mode = Some(ReflectMode::Normal);
-                    let new_traits = ReflectTraits::from_nested_metas(&meta_list.nested)?;
-                    traits = traits.merge(new_traits)?;
+        for attribute in &input.attrs {
+            // `#[reflect(...)]` may contain custom attributes, which aren't valid meta items
+            if attribute.path.is_ident(REFLECT_ATTRIBUTE_NAME) {
+                if !matches!(reflect_mode, None | Some(ReflectMode::Normal)) {
+                    return Err(syn::Error::new(
+                        attribute.span(),
+                        format_args!("cannot use both `#[{REFLECT_ATTRIBUTE_NAME}]` and `#[{REFLECT_VALUE_ATTRIBUTE_NAME}]`"),
+                    ));
                 }
+
+                reflect_mode = Some(ReflectMode::Normal);
+                let nested = parse_reflect_args(attribute, &mut custom_attributes)?;
+                let new_traits = ReflectTraits::from_nested_metas(&nested)?;
+                traits = traits.merge(new_traits)?;
+                continue;
+            }
+
+            let Ok(attribute) = attribute.parse_meta() else {
+                continue;
+            };
+            match attribute {
                 Meta::List(meta_list) if meta_list.path.is_ident(REFLECT_VALUE_ATTRIBUTE_NAME) => {
                     if !matches!(reflect_mode, None | Some(ReflectMode::Value)) {
                         return Err(syn::Error::new(
@@ -182,7 +195,9 @@ impl<'a> ReflectDerive<'a> {
             }
         }
 
-        let meta = ReflectMeta::new(&input.ident, &input.generics, traits);
+        let meta = ReflectMeta::new(&input.ident, &input.generics, traits)
+            .with_type_path(TypePathOptions::from_attributes(&input.attrs)?)
+            .with_custom_attributes(custom_attributes);
 
         #[cfg(feature = "documentation")]
         let meta = meta.with_docs(doc);
@@ -284,6 +299,8 @@ impl<'a> ReflectMeta<'a> {
             traits,
             type_name,
             generics,
+            type_path: TypePathOptions::default(),
+            custom_attributes: CustomAttributes::default(),
             bevy_reflect_path: utility::get_bevy_reflect_path(),
             #[cfg(feature = "documentation")]
             docs: Default::default(),
@@ -296,6 +313,19 @@ impl<'a> ReflectMeta<'a> {
         Self { docs, ..self }
     }
 
+    /// Sets the path and name given to this type by its `TypePath` impl.
+    pub fn with_type_path(self, type_path: TypePathOptions) -> Self {
+        Self { type_path, ..self }
+    }
+
+    /// Sets the custom attributes for this type.
+    pub fn with_custom_attributes(self, custom_attributes: CustomAttributes) -> Self {
+        Self {
+            custom_attributes,
+            ..self
+        }
+    }
+
     /// The registered reflect traits on this struct.
     pub fn traits(&self) -> &ReflectTraits {
         &self.traits
@@ -311,11 +341,26 @@ impl<'a> ReflectMeta<'a> {
         self.generics
     }
 
+    /// The custom attributes for this type.
+    pub fn custom_attributes(&self) -> &CustomAttributes {
+        &self.custom_attributes
+    }
+
     /// The cached `bevy_reflect` path.
     pub fn bevy_reflect_path(&self) -> &Path {
         &self.bevy_reflect_path
     }
 
+    /// Returns the `TypePath` impl as a `TokenStream`.
+    pub fn get_type_path_impl(&self) -> proc_macro2::TokenStream {
+        crate::type_path::impl_type_path(
+            self.type_name,
+            self.generics,
+            &self.type_path,
+            &self.bevy_reflect_path,
+        )
+    }
+
     /// Returns the `GetTypeRegistration` impl as a `TokenStream`.
     pub fn get_type_registration(
         &self,
@@ -4,6 +4,7 @@
 //! as opposed to an entire struct or enum. An example of such an attribute is
 //! the derive helper attribute for `Reflect`, which looks like: `#[reflect(ignore)]`.
 
+use crate::custom_attributes::{parse_reflect_args, CustomAttributes};
 use crate::REFLECT_ATTRIBUTE_NAME;
 use quote::ToTokens;
 use syn::spanned::Spanned;
@@ -52,6 +53,8 @@ pub(crate) struct ReflectFieldAttr {
     pub ignore: ReflectIgnoreBehavior,
     /// Sets the default behavior of this field.
     pub default: DefaultBehavior,
+    /// Custom attributes created with `#[reflect(@...)]`.
+    pub custom_attributes: CustomAttributes,
 }
 
 /// Controls how the default value is determined for a field.
@@ -69,7 +72,7 @@ pub(crate) enum DefaultBehavior {
     Func(syn::ExprPath),
 }
 
-/// Parse all field attributes marked "reflect" (such as `#[reflect(ignore)]`).
+/// Parse all field attributes marked "reflect" (such as `#[reflect(ignore)]` or `#[reflect(@0.0..=1.0)]`).
 pub(crate) fn parse_field_attrs(attrs: &[Attribute]) -> Result<ReflectFieldAttr, syn::Error> {
     let mut args = ReflectFieldAttr::default();
     let mut errors: Option<syn::Error> = None;
@@ -78,12 +81,17 @@ pub(crate) fn parse_field_attrs(attrs: &[Attribute]) -> Result<ReflectFieldAttr,
         .iter()
         .filter(|a| a.path.is_ident(REFLECT_ATTRIBUTE_NAME));
     for attr in attrs {
-        let meta = attr.parse_meta()?;
-        if let Err(err) = parse_meta(&mut args, &meta) {
-            if let Some(ref mut error) = errors {
-                error.combine(err);
-            } else {
-                errors = Some(err);
+        let nested = parse_reflect_args(attr, &mut args.custom_attributes)?;
+        for meta in nested.iter().filter_map(|nested| match nested {
+            NestedMeta::Meta(meta) => Some(meta),
+            NestedMeta::Lit(_) => None,
+        }) {
+            if let Err(err) = parse_meta(&mut args, meta) {
+                if let Some(ref mut error) = errors {
+                    error.combine(err);
+                } else {
+                    errors = Some(err);
+                }
             }
         }
     }
@@ -5,7 +5,7 @@ use crate::ReflectStruct;
 use proc_macro::TokenStream;
 use quote::{quote, ToTokens};
 
-/// Implements `Struct`, `GetTypeRegistration`, and `Reflect` for the given derive data.
+/// Implements `Struct`, `GetTypeRegistration`, `TypePath`, and `Reflect` for the given derive data.
 pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> TokenStream {
     let fqoption = FQOption.into_token_stream();
 
@@ -47,37 +47,48 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> TokenStream {
             }
         });
 
+    let field_custom_attributes = reflect_struct.active_fields().map(|field| {
+        field
+            .attrs
+            .custom_attributes
+            .to_builder_call(bevy_reflect_path)
+    });
+
     #[cfg(feature = "documentation")]
     let field_generator = {
         let docs = reflect_struct
             .active_fields()
             .map(|field| quote::ToTokens::to_token_stream(&field.doc));
         quote! {
-            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names).with_docs(#docs) ,)*
+            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names).with_docs(#docs) #field_custom_attributes ,)*
         }
     };
 
     #[cfg(not(feature = "documentation"))]
     let field_generator = {
         quote! {
-            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names) ,)*
+            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names) #field_custom_attributes ,)*
         }
     };
 
     let string_name = struct_name.to_string();
+    let custom_attributes = reflect_struct
+        .meta()
+        .custom_attributes()
+        .to_builder_call(bevy_reflect_path);
 
     #[cfg(feature = "documentation")]
     let info_generator = {
         let doc = reflect_struct.meta().doc();
         quote! {
-            #bevy_reflect_path::StructInfo::new::<Self>(#string_name, &fields).with_docs(#doc)
+            #bevy_reflect_path::StructInfo::new::<Self>(#string_name, &fields).with_docs(#doc) #custom_attributes
         }
     };
 
     #[cfg(not(feature = "documentation"))]
     let info_generator = {
         quote! {
-            #bevy_reflect_path::StructInfo::new::<Self>(#string_name, &fields)
+            #bevy_reflect_path::StructInfo::new::<Self>(#string_name, &fields) #custom_attributes
         }
     };
 
@@ -100,11 +111,15 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> TokenStream {
 
     let where_reflect_clause = extend_where_clause(where_clause, &where_clause_options);
 
+    let type_path_impl = reflect_struct.meta().get_type_path_impl();
+
     TokenStream::from(quote! {
         #get_type_registration_impl
 
         #typed_impl
 
+        #type_path_impl
+
         impl #impl_generics #bevy_reflect_path::Struct for #struct_name #ty_generics #where_reflect_clause {
             fn field(&self, name: &str) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
                 match name {
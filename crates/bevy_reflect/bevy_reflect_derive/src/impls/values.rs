@@ -5,7 +5,7 @@ use crate::ReflectMeta;
 use proc_macro::TokenStream;
 use quote::quote;
 
-/// Implements `GetTypeRegistration` and `Reflect` for the given type data.
+/// Implements `GetTypeRegistration`, `TypePath`, and `Reflect` for the given type data.
 pub(crate) fn impl_value(meta: &ReflectMeta) -> TokenStream {
     let bevy_reflect_path = meta.bevy_reflect_path();
     let type_name = meta.type_name();
@@ -37,11 +37,15 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = meta.generics().split_for_impl();
     let get_type_registration_impl = meta.get_type_registration(&where_clause_options);
 
+    let type_path_impl = meta.get_type_path_impl();
+
     TokenStream::from(quote! {
         #get_type_registration_impl
 
         #typed_impl
 
+        #type_path_impl
+
         impl #impl_generics #bevy_reflect_path::Reflect for #type_name #ty_generics #where_clause  {
             #[inline]
             fn type_name(&self) -> &str {
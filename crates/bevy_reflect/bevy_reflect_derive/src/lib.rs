@@ -4,17 +4,19 @@
 //! types to easily implement `Reflect` along with other `bevy_reflect` traits,
 //! such as `Struct`, `GetTypeRegistration`, and more— all with a single derive!
 //!
-//! Some other noteworthy exports include the derive macros for [`FromReflect`] and
-//! [`TypeUuid`], as well as the [`reflect_trait`] attribute macro.
+//! Some other noteworthy exports include the derive macros for [`FromReflect`],
+//! [`TypePath`] and [`TypeUuid`], as well as the [`reflect_trait`] attribute macro.
 //!
 //! [`Reflect`]: crate::derive_reflect
 //! [`FromReflect`]: crate::derive_from_reflect
+//! [`TypePath`]: crate::derive_type_path
 //! [`TypeUuid`]: crate::derive_type_uuid
 //! [`reflect_trait`]: macro@reflect_trait
 
 extern crate proc_macro;
 
 mod container_attributes;
+mod custom_attributes;
 mod derive_data;
 #[cfg(feature = "documentation")]
 mod documentation;
@@ -26,6 +28,7 @@ mod impls;
 mod reflect_value;
 mod registration;
 mod trait_reflection;
+mod type_path;
 mod type_uuid;
 mod utility;
 
@@ -36,6 +39,7 @@ use quote::quote;
 use reflect_value::ReflectValueDef;
 use syn::spanned::Spanned;
 use syn::{parse_macro_input, DeriveInput};
+use type_path::TypePathOptions;
 use type_uuid::TypeUuidDef;
 
 pub(crate) static REFLECT_ATTRIBUTE_NAME: &str = "reflect";
@@ -44,7 +48,7 @@ pub(crate) static REFLECT_VALUE_ATTRIBUTE_NAME: &str = "reflect_value";
 /// The main derive macro used by `bevy_reflect` for deriving its `Reflect` trait.
 ///
 /// This macro can be used on all structs and enums (unions are not supported).
-/// It will automatically generate the implementations for `Reflect`, `Typed`, and `GetTypeRegistration`.
+/// It will automatically generate the implementations for `Reflect`, `Typed`, `TypePath`, and `GetTypeRegistration`.
 /// And, depending on the item's structure, will either implement `Struct`, `TupleStruct`, or `Enum`.
 ///
 /// # Container Attributes
@@ -99,6 +103,27 @@ pub(crate) static REFLECT_VALUE_ATTRIBUTE_NAME: &str = "reflect_value";
 /// If planning to serialize this type using the reflection serializers,
 /// then the `Serialize` and `Deserialize` traits will need to be implemented and registered as well.
 ///
+/// ## `#[type_path = "..."]` and `#[type_name = "..."]`
+///
+/// These attributes override the module path and the name given to the type by its
+/// [`TypePath`](derive_type_path) implementation, which default to the module the type is
+/// defined in and its identifier.
+///
+/// For example, a type `Foo` annotated with `#[type_path = "my_crate::foo"]` and
+/// `#[type_name = "Bar"]` has the type path `my_crate::foo::Bar`.
+/// This keeps the paths of serialized types stable when they are moved or renamed.
+///
+/// ## `#[reflect(@...)]`
+///
+/// This attribute adds a custom attribute to the `TypeInfo` of the type.
+/// Any expression evaluating to a type that implements `Reflect` may follow the `@`,
+/// such as `#[reflect(@Tooltip::new("..."))]`, and multiple custom attributes may be given.
+/// They can be queried by type with `CustomAttributes::get`, and only the last attribute
+/// of each type is kept.
+///
+/// Custom attributes are only supported by structs, tuple structs and enums,
+/// and may be mixed with the other container attributes, such as `#[reflect(@0..10, Default)]`.
+///
 /// # Field Attributes
 ///
 /// Along with the container attributes, this macro comes with some attributes that may be applied
@@ -122,10 +147,18 @@ pub(crate) static REFLECT_VALUE_ATTRIBUTE_NAME: &str = "reflect_value";
 /// What this does is register the `SerializationData` type within the `GetTypeRegistration` implementation,
 /// which will be used by the reflection serializers to determine whether or not the field is serializable.
 ///
+/// ## `#[reflect(@...)]`
+///
+/// Like the container attribute of the same name, this adds a custom attribute to the
+/// `NamedField` or `UnnamedField` of a field, such as `#[reflect(@0.0..=1.0)]`.
+/// It may also be used on enum variants, in which case the attribute is added to the
+/// `VariantInfo` of the variant.
+///
 /// [`reflect_trait`]: macro@reflect_trait
-#[proc_macro_derive(Reflect, attributes(reflect, reflect_value))]
+#[proc_macro_derive(Reflect, attributes(reflect, reflect_value, type_path, type_name))]
 pub fn derive_reflect(input: TokenStream) -> TokenStream {
-    let ast = parse_macro_input!(input as DeriveInput);
+    let mut ast = parse_macro_input!(input as DeriveInput);
+    utility::add_type_path_bounds(&mut ast.generics, &utility::get_bevy_reflect_path());
 
     let derive_data = match ReflectDerive::from_input(&ast) {
         Ok(data) => data,
@@ -170,7 +203,8 @@ pub fn derive_reflect(input: TokenStream) -> TokenStream {
 /// such as when converting a partially-constructed dynamic type to a concrete one.
 #[proc_macro_derive(FromReflect, attributes(reflect))]
 pub fn derive_from_reflect(input: TokenStream) -> TokenStream {
-    let ast = parse_macro_input!(input as DeriveInput);
+    let mut ast = parse_macro_input!(input as DeriveInput);
+    utility::add_type_path_bounds(&mut ast.generics, &utility::get_bevy_reflect_path());
 
     let derive_data = match ReflectDerive::from_input(&ast) {
         Ok(data) => data,
@@ -187,6 +221,41 @@ pub fn derive_from_reflect(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Derives the `TypePath` trait, providing a stable path and name for the type.
+///
+/// This is only needed for types which don't derive [`Reflect`], such as assets,
+/// since that derive implements `TypePath` as well.
+///
+/// The type path defaults to the module the type is defined in followed by its identifier,
+/// such as `my_crate::foo::Foo`, and generic types include the type paths of their type
+/// parameters, such as `my_crate::foo::Foo<alloc::string::String>`.
+///
+/// # Container Attributes
+///
+/// ## `#[type_path = "..."]`
+///
+/// Replaces the module path of the type, for example `#[type_path = "my_crate::foo"]`.
+///
+/// ## `#[type_name = "..."]`
+///
+/// Replaces the name of the type, for example `#[type_name = "Bar"]`.
+#[proc_macro_derive(TypePath, attributes(type_path, type_name))]
+pub fn derive_type_path(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    let options = match TypePathOptions::from_attributes(&ast.attrs) {
+        Ok(options) => options,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    TokenStream::from(type_path::impl_type_path(
+        &ast.ident,
+        &ast.generics,
+        &options,
+        &utility::get_bevy_reflect_path(),
+    ))
+}
+
 // From https://github.com/randomPoison/type-uuid
 #[proc_macro_derive(TypeUuid, attributes(uuid))]
 pub fn derive_type_uuid(input: TokenStream) -> TokenStream {
@@ -268,15 +337,24 @@ pub fn reflect_trait(args: TokenStream, input: TokenStream) -> TokenStream {
 /// impl_reflect_value!(foo<T1, T2: Baz> where T1: Bar (Default, Serialize, Deserialize));
 /// ```
 ///
+/// The full path of the type gives the path used by its `TypePath` implementation,
+/// while a type given without a path, such as `foo` above, is treated as a primitive:
+///
+/// ```ignore
+/// impl_reflect_value!(::my_crate::foo::Foo(Debug, Default));
+/// ```
+///
 /// [deriving `Reflect`]: Reflect
 #[proc_macro]
 pub fn impl_reflect_value(input: TokenStream) -> TokenStream {
-    let def = parse_macro_input!(input as ReflectValueDef);
+    let mut def = parse_macro_input!(input as ReflectValueDef);
+    utility::add_type_path_bounds(&mut def.generics, &utility::get_bevy_reflect_path());
     let meta = ReflectMeta::new(
         &def.type_name,
         &def.generics,
         def.traits.unwrap_or_default(),
-    );
+    )
+    .with_type_path(def.type_path);
 
     #[cfg(feature = "documentation")]
     let meta = meta.with_docs(documentation::Documentation::from_attributes(&def.attrs));
@@ -298,6 +376,9 @@ pub fn impl_reflect_value(input: TokenStream) -> TokenStream {
 /// error message and fail to compile. If the type does not implement `Default`, it may not
 /// be possible to reflect without extending the macro.
 ///
+/// The `#[type_path = "..."]` attribute should be used to give the module path of the
+/// foreign type, since it otherwise defaults to the module the macro is invoked in.
+///
 /// # Example
 /// Implementing `Reflect` for `bevy::prelude::Vec3` as a struct type:
 /// ```ignore
@@ -305,6 +386,7 @@ pub fn impl_reflect_value(input: TokenStream) -> TokenStream {
 ///
 /// impl_reflect_struct!(
 ///    #[reflect(PartialEq, Serialize, Deserialize, Default)]
+///    #[type_path = "glam"]
 ///    struct Vec3 {
 ///        x: f32,
 ///        y: f32,
@@ -314,7 +396,8 @@ pub fn impl_reflect_value(input: TokenStream) -> TokenStream {
 /// ```
 #[proc_macro]
 pub fn impl_reflect_struct(input: TokenStream) -> TokenStream {
-    let ast = parse_macro_input!(input as DeriveInput);
+    let mut ast = parse_macro_input!(input as DeriveInput);
+    utility::add_type_path_bounds(&mut ast.generics, &utility::get_bevy_reflect_path());
     let derive_data = match ReflectDerive::from_input(&ast) {
         Ok(data) => data,
         Err(err) => return err.into_compile_error().into(),
@@ -368,7 +451,8 @@ pub fn impl_reflect_struct(input: TokenStream) -> TokenStream {
 /// [derives `Reflect`]: Reflect
 #[proc_macro]
 pub fn impl_from_reflect_value(input: TokenStream) -> TokenStream {
-    let def = parse_macro_input!(input as ReflectValueDef);
+    let mut def = parse_macro_input!(input as ReflectValueDef);
+    utility::add_type_path_bounds(&mut def.generics, &utility::get_bevy_reflect_path());
     from_reflect::impl_value(&ReflectMeta::new(
         &def.type_name,
         &def.generics,
@@ -376,6 +460,26 @@ pub fn impl_from_reflect_value(input: TokenStream) -> TokenStream {
     ))
 }
 
+/// A macro used to implement `TypePath` for foreign types which don't implement `Reflect`
+/// through [`impl_reflect_value!`] or [`impl_reflect_struct!`].
+///
+/// The type is given with its full path and generic parameters, using the same syntax as
+/// [`impl_reflect_value!`] without the list of traits:
+///
+/// ```ignore
+/// impl_type_path!(::alloc::vec::Vec<T>);
+/// ```
+#[proc_macro]
+pub fn impl_type_path(input: TokenStream) -> TokenStream {
+    let def = parse_macro_input!(input as ReflectValueDef);
+    TokenStream::from(type_path::impl_type_path(
+        &def.type_name,
+        &def.generics,
+        &def.type_path,
+        &utility::get_bevy_reflect_path(),
+    ))
+}
+
 /// Derives `TypeUuid` for the given type. This is used internally to implement `TypeUuid` on foreign types, such as those in the std. This macro should be used in the format of `<[Generic Params]> [Type (Path)], [Uuid (String Literal)]`.
 #[proc_macro]
 pub fn impl_type_uuid(input: TokenStream) -> TokenStream {
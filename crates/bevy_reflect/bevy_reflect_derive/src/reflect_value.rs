@@ -1,8 +1,9 @@
 use crate::container_attributes::ReflectTraits;
+use crate::type_path::TypePathOptions;
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
 use syn::token::{Paren, Where};
-use syn::{parenthesized, Attribute, Generics};
+use syn::{parenthesized, Attribute, Generics, Path};
 
 /// A struct used to define a simple reflected value type (such as primitives).
 ///
@@ -17,11 +18,18 @@ use syn::{parenthesized, Attribute, Generics};
 ///
 /// // With generics and where clause
 /// foo<T1, T2> where T1: Bar (TraitA, TraitB)
+///
+/// // With the full path of the type, as used by its `TypePath` impl
+/// ::my_crate::foo::Foo(TraitA, TraitB)
 /// ```
+///
+/// A type given without a path, such as `foo` above, is treated as a primitive and has no
+/// module path.
 pub(crate) struct ReflectValueDef {
     #[allow(dead_code)]
     pub attrs: Vec<Attribute>,
     pub type_name: Ident,
+    pub type_path: TypePathOptions,
     pub generics: Generics,
     pub traits: Option<ReflectTraits>,
 }
@@ -29,7 +37,8 @@ pub(crate) struct ReflectValueDef {
 impl Parse for ReflectValueDef {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let attrs = input.call(Attribute::parse_outer)?;
-        let type_ident = input.parse::<Ident>()?;
+        let path = Path::parse_mod_style(input)?;
+        let type_ident = path.segments.last().unwrap().ident.clone();
         let generics = input.parse::<Generics>()?;
         let mut lookahead = input.lookahead1();
         let mut where_clause = None;
@@ -48,6 +57,7 @@ impl Parse for ReflectValueDef {
         Ok(ReflectValueDef {
             attrs,
             type_name: type_ident,
+            type_path: TypePathOptions::from_path(&path),
             generics: Generics {
                 where_clause,
                 ..generics
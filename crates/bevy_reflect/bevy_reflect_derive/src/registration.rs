@@ -1,6 +1,6 @@
 //! Contains code related specifically to Bevy's type registration.
 
-use crate::utility::{extend_where_clause, WhereClauseOptions};
+use crate::utility::{add_type_path_bounds, extend_where_clause, WhereClauseOptions};
 use bit_set::BitSet;
 use proc_macro2::Ident;
 use quote::quote;
@@ -16,6 +16,10 @@ pub(crate) fn impl_get_type_registration(
     where_clause_options: &WhereClauseOptions,
     serialization_denylist: Option<&BitSet<u32>>,
 ) -> proc_macro2::TokenStream {
+    // `TypeRegistration::of` requires the type to implement `TypePath`, which requires the same
+    // of its type parameters
+    let mut generics = generics.clone();
+    add_type_path_bounds(&mut generics, bevy_reflect_path);
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let serialization_data = serialization_denylist.map(|denylist| {
         let denylist = denylist.into_iter();
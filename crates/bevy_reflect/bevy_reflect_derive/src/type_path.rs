@@ -0,0 +1,200 @@
+//! Contains code related to the `TypePath` trait, which gives types a stable path and name.
+
+use crate::utility::add_type_path_bounds;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Attribute, GenericParam, Generics, Lit, Meta, Path};
+
+pub(crate) static TYPE_PATH_ATTRIBUTE_NAME: &str = "type_path";
+pub(crate) static TYPE_NAME_ATTRIBUTE_NAME: &str = "type_name";
+
+/// The module a type is found in, as used by its `TypePath` implementation.
+#[derive(Clone, Default)]
+pub(crate) enum TypeModulePath {
+    /// The module the macro was invoked in, as given by `module_path!`.
+    #[default]
+    CallSite,
+    /// A custom module path, such as `alloc::string` for `alloc::string::String`.
+    Custom(String),
+    /// The type is a primitive and isn't found in any module, such as `u8`.
+    Primitive,
+}
+
+/// The path and name given to a type by its `TypePath` implementation.
+///
+/// Both default to where the type is defined, and may be overridden with the
+/// `#[type_path = "my_crate::foo"]` and `#[type_name = "Bar"]` container attributes.
+#[derive(Clone, Default)]
+pub(crate) struct TypePathOptions {
+    pub module_path: TypeModulePath,
+    pub type_name: Option<String>,
+}
+
+impl TypePathOptions {
+    /// Parses the `#[type_path = "..."]` and `#[type_name = "..."]` attributes of a type.
+    pub fn from_attributes(attrs: &[Attribute]) -> Result<Self, syn::Error> {
+        let mut options = Self::default();
+        for attribute in attrs.iter().filter_map(|attr| attr.parse_meta().ok()) {
+            let Meta::NameValue(pair) = attribute else {
+                continue;
+            };
+
+            let is_type_path = pair.path.is_ident(TYPE_PATH_ATTRIBUTE_NAME);
+            if !is_type_path && !pair.path.is_ident(TYPE_NAME_ATTRIBUTE_NAME) {
+                continue;
+            }
+
+            let Lit::Str(lit) = &pair.lit else {
+                return Err(syn::Error::new(
+                    pair.lit.span(),
+                    "expected a string literal, such as `#[type_path = \"my_crate::foo\"]`",
+                ));
+            };
+            let value = lit.value();
+            if is_type_path {
+                if value.is_empty() || value.starts_with("::") || value.ends_with("::") {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        format_args!("`{value}` is not a valid module path"),
+                    ));
+                }
+                options.module_path = TypeModulePath::Custom(value);
+            } else {
+                if value.is_empty() {
+                    return Err(syn::Error::new(lit.span(), "type names cannot be empty"));
+                }
+                options.type_name = Some(value);
+            }
+        }
+        Ok(options)
+    }
+
+    /// Creates the options for a type referred to by `path` in a macro such as
+    /// `impl_reflect_value!`.
+    ///
+    /// A single identifier, such as `u8`, denotes a primitive type. Any other path,
+    /// such as `::alloc::string::String`, is the full path to the type.
+    pub fn from_path(path: &Path) -> Self {
+        let module_path = if path.leading_colon.is_none() && path.segments.len() == 1 {
+            TypeModulePath::Primitive
+        } else {
+            let segments = path.segments.iter().rev().skip(1).rev();
+            let segments = segments.map(|segment| segment.ident.to_string());
+            TypeModulePath::Custom(segments.collect::<Vec<_>>().join("::"))
+        };
+        Self {
+            module_path,
+            type_name: None,
+        }
+    }
+}
+
+/// Generates the `TypePath` impl for the given type.
+///
+/// Generic types include the type paths of their type parameters and the values of their
+/// const parameters, such as `my_crate::Foo<alloc::string::String, 2>`.
+pub(crate) fn impl_type_path(
+    type_name: &Ident,
+    generics: &Generics,
+    options: &TypePathOptions,
+    bevy_reflect_path: &Path,
+) -> TokenStream {
+    let name = options
+        .type_name
+        .clone()
+        .unwrap_or_else(|| type_name.to_string());
+
+    let (base_path, crate_name, module_path) = match &options.module_path {
+        TypeModulePath::CallSite => (
+            quote!(::core::concat!(::core::module_path!(), "::", #name)),
+            quote!(::core::module_path!().split(':').next()),
+            quote!(::core::option::Option::Some(::core::module_path!())),
+        ),
+        TypeModulePath::Custom(path) => {
+            let crate_name = path.split("::").next().unwrap_or_default();
+            (
+                quote!(::core::concat!(#path, "::", #name)),
+                quote!(::core::option::Option::Some(#crate_name)),
+                quote!(::core::option::Option::Some(#path)),
+            )
+        }
+        TypeModulePath::Primitive => (
+            quote!(#name),
+            quote!(::core::option::Option::None),
+            quote!(::core::option::Option::None),
+        ),
+    };
+
+    let mut param_paths = Vec::new();
+    let mut param_short_paths = Vec::new();
+    for param in &generics.params {
+        match param {
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                param_paths.push(quote! {
+                    ::std::string::ToString::to_string(<#ident as #bevy_reflect_path::TypePath>::type_path())
+                });
+                param_short_paths.push(quote! {
+                    ::std::string::ToString::to_string(<#ident as #bevy_reflect_path::TypePath>::short_type_path())
+                });
+            }
+            GenericParam::Const(param) => {
+                let ident = &param.ident;
+                let value = quote!(::std::string::ToString::to_string(&#ident));
+                param_paths.push(value.clone());
+                param_short_paths.push(value);
+            }
+            GenericParam::Lifetime(_) => {}
+        }
+    }
+
+    let (type_path, short_type_path) = if param_paths.is_empty() {
+        (base_path, quote!(#name))
+    } else {
+        let cell = quote!(#bevy_reflect_path::utility::GenericTypePathCell);
+        (
+            quote! {
+                static CELL: #cell = #cell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    let params: &[::std::string::String] = &[#(#param_paths),*];
+                    ::std::format!("{}<{}>", #base_path, params.join(", "))
+                })
+            },
+            quote! {
+                static CELL: #cell = #cell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    let params: &[::std::string::String] = &[#(#param_short_paths),*];
+                    ::std::format!("{}<{}>", #name, params.join(", "))
+                })
+            },
+        )
+    };
+
+    let mut generics = generics.clone();
+    add_type_path_bounds(&mut generics, bevy_reflect_path);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #bevy_reflect_path::TypePath for #type_name #ty_generics #where_clause {
+            fn type_path() -> &'static str {
+                #type_path
+            }
+
+            fn short_type_path() -> &'static str {
+                #short_type_path
+            }
+
+            fn type_ident() -> ::core::option::Option<&'static str> {
+                ::core::option::Option::Some(#name)
+            }
+
+            fn crate_name() -> ::core::option::Option<&'static str> {
+                #crate_name
+            }
+
+            fn module_path() -> ::core::option::Option<&'static str> {
+                #module_path
+            }
+        }
+    }
+}
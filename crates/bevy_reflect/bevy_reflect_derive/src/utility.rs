@@ -5,13 +5,25 @@ use bevy_macro_utils::BevyManifest;
 use bit_set::BitSet;
 use proc_macro2::{Ident, Span};
 use quote::quote;
-use syn::{Member, Path, Type, WhereClause};
+use syn::{Generics, Member, Path, Type, WhereClause};
 
 /// Returns the correct path for `bevy_reflect`.
 pub(crate) fn get_bevy_reflect_path() -> Path {
     BevyManifest::get_path_direct("bevy_reflect")
 }
 
+/// Adds a `TypePath` bound to every type parameter of `generics`.
+///
+/// Reflected types need to implement `TypePath`, which generic types only do if their type
+/// parameters do as well.
+pub(crate) fn add_type_path_bounds(generics: &mut Generics, bevy_reflect_path: &Path) {
+    generics.type_params_mut().for_each(|param| {
+        param
+            .bounds
+            .push(syn::parse_quote!(#bevy_reflect_path::TypePath));
+    });
+}
+
 /// Returns the "reflected" ident for a given string.
 ///
 /// # Example
@@ -1,6 +1,6 @@
 use crate::{
     utility::{reflect_hasher, NonGenericTypeInfoCell},
-    DynamicInfo, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, Typed,
+    DynamicInfo, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypePath, Typed,
 };
 use std::{
     any::{Any, TypeId},
@@ -77,6 +77,7 @@ pub trait Array: Reflect {
 #[derive(Clone, Debug)]
 pub struct ArrayInfo {
     type_name: &'static str,
+    type_path: &'static str,
     type_id: TypeId,
     item_type_name: &'static str,
     item_type_id: TypeId,
@@ -92,9 +93,10 @@ impl ArrayInfo {
     ///
     /// * `capacity`: The maximum capacity of the underlying array.
     ///
-    pub fn new<TArray: Array, TItem: Reflect>(capacity: usize) -> Self {
+    pub fn new<TArray: Array + TypePath, TItem: Reflect>(capacity: usize) -> Self {
         Self {
             type_name: std::any::type_name::<TArray>(),
+            type_path: TArray::type_path(),
             type_id: TypeId::of::<TArray>(),
             item_type_name: std::any::type_name::<TItem>(),
             item_type_id: TypeId::of::<TItem>(),
@@ -122,6 +124,13 @@ impl ArrayInfo {
         self.type_name
     }
 
+    /// The [type path] of the array.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
     /// The [`TypeId`] of the array.
     pub fn type_id(&self) -> TypeId {
         self.type_id
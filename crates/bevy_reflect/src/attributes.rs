@@ -0,0 +1,111 @@
+use crate::Reflect;
+use std::any::TypeId;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+/// A collection of user-defined attributes attached to a reflected type, field or variant.
+///
+/// Attributes are arbitrary values implementing [`Reflect`], and at most one attribute of each
+/// type is stored. They are usually added with the `@` syntax of the
+/// [`Reflect` derive](derive@crate::Reflect), such as `#[reflect(@0.0..=1.0)]`, and can then be
+/// queried by type from the corresponding [`TypeInfo`], [`NamedField`], [`UnnamedField`] or
+/// [`VariantInfo`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, Typed, TypeInfo};
+/// # use std::ops::RangeInclusive;
+/// #[derive(Reflect)]
+/// struct Tooltip(String);
+///
+/// impl Tooltip {
+///     fn new(text: &str) -> Self {
+///         Self(text.to_string())
+///     }
+/// }
+///
+/// #[derive(Reflect)]
+/// #[reflect(@Tooltip::new("A slider"))]
+/// struct Slider {
+///     #[reflect(@0.0..=1.0)]
+///     value: f64,
+/// }
+///
+/// let TypeInfo::Struct(info) = Slider::type_info() else { unreachable!() };
+/// let tooltip = info.custom_attributes().get::<Tooltip>().unwrap();
+/// assert_eq!(tooltip.0, "A slider");
+///
+/// let range = info.field("value").unwrap().custom_attributes().get::<RangeInclusive<f64>>();
+/// assert_eq!(range, Some(&(0.0..=1.0)));
+/// ```
+///
+/// [`TypeInfo`]: crate::TypeInfo
+/// [`NamedField`]: crate::NamedField
+/// [`UnnamedField`]: crate::UnnamedField
+/// [`VariantInfo`]: crate::VariantInfo
+#[derive(Clone, Default)]
+pub struct CustomAttributes {
+    attributes: Vec<(TypeId, Arc<dyn Reflect>)>,
+}
+
+impl CustomAttributes {
+    /// Adds the given attribute, replacing any existing attribute of the same type.
+    pub fn with_attribute<T: Reflect>(mut self, value: T) -> Self {
+        let type_id = TypeId::of::<T>();
+        let value: Arc<dyn Reflect> = Arc::new(value);
+        match self.attributes.iter_mut().find(|(id, _)| *id == type_id) {
+            Some((_, attribute)) => *attribute = value,
+            None => self.attributes.push((type_id, value)),
+        }
+        self
+    }
+
+    /// Returns the attribute of type `T`, if any.
+    pub fn get<T: Reflect>(&self) -> Option<&T> {
+        self.get_by_id(TypeId::of::<T>())?.downcast_ref()
+    }
+
+    /// Returns the attribute with the given [`TypeId`], if any.
+    pub fn get_by_id(&self, type_id: TypeId) -> Option<&dyn Reflect> {
+        self.attributes
+            .iter()
+            .find(|(id, _)| *id == type_id)
+            .map(|(_, attribute)| attribute.as_ref())
+    }
+
+    /// Returns `true` if an attribute of type `T` is present.
+    pub fn contains<T: Reflect>(&self) -> bool {
+        self.contains_by_id(TypeId::of::<T>())
+    }
+
+    /// Returns `true` if an attribute with the given [`TypeId`] is present.
+    pub fn contains_by_id(&self, type_id: TypeId) -> bool {
+        self.attributes.iter().any(|(id, _)| *id == type_id)
+    }
+
+    /// Returns an iterator over the attributes and their [`TypeId`]s, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = (TypeId, &dyn Reflect)> {
+        self.attributes
+            .iter()
+            .map(|(id, attribute)| (*id, attribute.as_ref()))
+    }
+
+    /// Returns the number of attributes.
+    pub fn len(&self) -> usize {
+        self.attributes.len()
+    }
+
+    /// Returns `true` if there are no attributes.
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty()
+    }
+}
+
+impl Debug for CustomAttributes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set()
+            .entries(self.attributes.iter().map(|(_, attribute)| attribute))
+            .finish()
+    }
+}
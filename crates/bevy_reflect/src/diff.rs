@@ -0,0 +1,891 @@
+use crate::{Enum, List, Map, Reflect, ReflectMut, ReflectRef, Struct, VariantType};
+use std::ops::Range;
+use thiserror::Error;
+
+/// The changes turning a reflected value into another one, as returned by [`diff`].
+///
+/// A diff only contains what changed between both values, and can be applied as a patch with
+/// [`Diff::apply`]. It can be serialized using [`DiffSerializer`] and [`DiffDeserializer`].
+///
+/// [`DiffSerializer`]: crate::serde::DiffSerializer
+/// [`DiffDeserializer`]: crate::serde::DiffDeserializer
+#[derive(Debug)]
+pub enum Diff {
+    /// Both values are equal.
+    Unchanged,
+    /// The value is replaced by a new one.
+    ///
+    /// This is the case when both values are of different types, of different enum variants,
+    /// of a [value type](crate::ReflectRef::Value) and not equal, or lists with too many
+    /// elements inserted or removed.
+    Replaced(Box<dyn Reflect>),
+    /// The changed fields of a [`Struct`], by name.
+    Struct(Vec<(String, Diff)>),
+    /// The changed fields of a [`TupleStruct`](crate::TupleStruct), by index.
+    TupleStruct(Vec<(usize, Diff)>),
+    /// The changed fields of a [`Tuple`](crate::Tuple), by index.
+    Tuple(Vec<(usize, Diff)>),
+    /// The changed elements of an [`Array`](crate::Array), by index.
+    Array(Vec<(usize, Diff)>),
+    /// The changes of a [`List`], applied in order.
+    List(Vec<ListChange>),
+    /// The changes of a [`Map`], applied in order.
+    Map(Vec<MapChange>),
+    /// The changed fields of an [`Enum`] struct variant, by name.
+    StructVariant {
+        variant: String,
+        fields: Vec<(String, Diff)>,
+    },
+    /// The changed fields of an [`Enum`] tuple variant, by index.
+    TupleVariant {
+        variant: String,
+        fields: Vec<(usize, Diff)>,
+    },
+}
+
+/// A change of a [`List`] in a [`Diff`].
+///
+/// Indices refer to the list as it is when the change is applied, after the previous changes.
+#[derive(Debug)]
+pub enum ListChange {
+    /// An element is inserted at the given index.
+    Insert(usize, Box<dyn Reflect>),
+    /// The element at the given index is removed.
+    Remove(usize),
+    /// The element at the given index is modified.
+    Modify(usize, Diff),
+}
+
+/// A change of a [`Map`] in a [`Diff`].
+#[derive(Debug)]
+pub enum MapChange {
+    /// An entry is inserted.
+    Insert(Box<dyn Reflect>, Box<dyn Reflect>),
+    /// The entry with the given key is removed.
+    Remove(Box<dyn Reflect>),
+    /// The value of the entry with the given key is modified.
+    Modify(Box<dyn Reflect>, Diff),
+}
+
+/// An error returned when a [`Diff`] can't be applied to a value.
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum DiffApplyError {
+    #[error("expected a {expected}, but found a different reflect value")]
+    MismatchedKinds { expected: &'static str },
+    #[error("expected the variant `{expected}`, but found the variant `{found}`")]
+    MismatchedVariants { expected: String, found: String },
+    #[error("a value of type `{replacement}` can't replace a value of type `{value}`")]
+    MismatchedTypes { replacement: String, value: String },
+    #[error("the value doesn't have a field with the name `{field}`")]
+    MissingField { field: String },
+    #[error("the value doesn't have an element at the index {index}")]
+    MissingIndex { index: usize },
+    #[error("the map doesn't contain the key `{key}`")]
+    MissingKey { key: String },
+    #[error("an element of type `{element}` can't be inserted into a `{container}`")]
+    MismatchedElement { element: String, container: String },
+}
+
+/// Returns the changes turning the reflected value `a` into `b`.
+///
+/// Structs, tuple structs, tuples, arrays and the fields of enums of the same variant are
+/// compared field by field. Elements inserted into or removed from lists and maps are recorded
+/// as such. Any other change replaces the value, using [`Reflect::reflect_partial_eq`] to check
+/// whether values are equal.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{diff, Diff, Reflect};
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// struct Player {
+///     name: String,
+///     inventory: Vec<u32>,
+/// }
+///
+/// let a = Player {
+///     name: String::from("Ferris"),
+///     inventory: vec![1, 2, 3],
+/// };
+/// let b = Player {
+///     name: String::from("Ferris"),
+///     inventory: vec![1, 3, 4],
+/// };
+///
+/// let diff = diff(&a, &b);
+/// let Diff::Struct(fields) = &diff else { unreachable!() };
+/// assert_eq!(fields.len(), 1);
+/// assert_eq!(fields[0].0, "inventory");
+///
+/// let mut patched = a.clone();
+/// diff.apply(&mut patched).unwrap();
+/// assert_eq!(patched, b);
+/// ```
+pub fn diff(a: &dyn Reflect, b: &dyn Reflect) -> Diff {
+    if a.type_name() != b.type_name() {
+        return Diff::Replaced(b.clone_value());
+    }
+
+    match (a.reflect_ref(), b.reflect_ref()) {
+        (ReflectRef::Struct(a), ReflectRef::Struct(b)) => diff_struct(a, b),
+        (ReflectRef::TupleStruct(a), ReflectRef::TupleStruct(b)) => {
+            diff_fields(a.field_len(), b.field_len(), |i| a.field(i), |i| b.field(i)).map_or_else(
+                || Diff::Replaced(b.clone_value()),
+                |fields| wrap_fields(fields, Diff::TupleStruct),
+            )
+        }
+        (ReflectRef::Tuple(a), ReflectRef::Tuple(b)) => {
+            diff_fields(a.field_len(), b.field_len(), |i| a.field(i), |i| b.field(i)).map_or_else(
+                || Diff::Replaced(b.clone_value()),
+                |fields| wrap_fields(fields, Diff::Tuple),
+            )
+        }
+        (ReflectRef::Array(a), ReflectRef::Array(b)) => {
+            diff_fields(a.len(), b.len(), |i| a.get(i), |i| b.get(i)).map_or_else(
+                || Diff::Replaced(b.clone_value()),
+                |fields| wrap_fields(fields, Diff::Array),
+            )
+        }
+        (ReflectRef::List(a), ReflectRef::List(b)) => diff_list(a, b),
+        (ReflectRef::Map(a), ReflectRef::Map(b)) => diff_map(a, b),
+        (ReflectRef::Enum(a), ReflectRef::Enum(b)) => diff_enum(a, b),
+        (ReflectRef::Value(a), ReflectRef::Value(b)) => {
+            if a.reflect_partial_eq(b) == Some(true) {
+                Diff::Unchanged
+            } else {
+                Diff::Replaced(b.clone_value())
+            }
+        }
+        _ => Diff::Replaced(b.clone_value()),
+    }
+}
+
+impl Diff {
+    /// Returns `true` if this diff doesn't change anything.
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Diff::Unchanged)
+    }
+
+    /// Applies this diff to `value`, turning it into the second value given to [`diff`] if it's
+    /// equal to the first one.
+    ///
+    /// The changes applied before an error occurs are kept.
+    pub fn apply(&self, value: &mut dyn Reflect) -> Result<(), DiffApplyError> {
+        match self {
+            Diff::Unchanged => Ok(()),
+            Diff::Replaced(replacement) => apply_replacement(replacement.as_ref(), value),
+            Diff::Struct(fields) => {
+                let ReflectMut::Struct(value) = value.reflect_mut() else {
+                    return Err(DiffApplyError::MismatchedKinds { expected: "struct" });
+                };
+                apply_named_fields(fields, value, |value, name| value.field_mut(name))
+            }
+            Diff::TupleStruct(fields) => {
+                let ReflectMut::TupleStruct(value) = value.reflect_mut() else {
+                    return Err(DiffApplyError::MismatchedKinds {
+                        expected: "tuple struct",
+                    });
+                };
+                apply_indexed_fields(fields, value, |value, index| value.field_mut(index))
+            }
+            Diff::Tuple(fields) => {
+                let ReflectMut::Tuple(value) = value.reflect_mut() else {
+                    return Err(DiffApplyError::MismatchedKinds { expected: "tuple" });
+                };
+                apply_indexed_fields(fields, value, |value, index| value.field_mut(index))
+            }
+            Diff::Array(elements) => {
+                let ReflectMut::Array(value) = value.reflect_mut() else {
+                    return Err(DiffApplyError::MismatchedKinds { expected: "array" });
+                };
+                apply_indexed_fields(elements, value, |value, index| value.get_mut(index))
+            }
+            Diff::List(changes) => {
+                let ReflectMut::List(value) = value.reflect_mut() else {
+                    return Err(DiffApplyError::MismatchedKinds { expected: "list" });
+                };
+                apply_list_changes(changes, value)
+            }
+            Diff::Map(changes) => {
+                let ReflectMut::Map(value) = value.reflect_mut() else {
+                    return Err(DiffApplyError::MismatchedKinds { expected: "map" });
+                };
+                apply_map_changes(changes, value)
+            }
+            Diff::StructVariant { variant, fields } => {
+                let value = variant_mut(value, variant)?;
+                apply_named_fields(fields, value, |value, name| value.field_mut(name))
+            }
+            Diff::TupleVariant { variant, fields } => {
+                let value = variant_mut(value, variant)?;
+                apply_indexed_fields(fields, value, |value, index| value.field_at_mut(index))
+            }
+        }
+    }
+}
+
+fn diff_struct(a: &dyn Struct, b: &dyn Struct) -> Diff {
+    if a.field_len() != b.field_len() {
+        return Diff::Replaced(b.clone_value());
+    }
+    diff_named_fields(
+        b.field_len(),
+        |i| b.name_at(i),
+        |name| a.field(name),
+        |name| b.field(name),
+    )
+    .map_or_else(
+        || Diff::Replaced(b.clone_value()),
+        |fields| wrap_fields(fields, Diff::Struct),
+    )
+}
+
+fn diff_enum(a: &dyn Enum, b: &dyn Enum) -> Diff {
+    if a.variant_name() != b.variant_name() || a.field_len() != b.field_len() {
+        return Diff::Replaced(b.clone_value());
+    }
+
+    let variant = b.variant_name().to_string();
+    match b.variant_type() {
+        VariantType::Struct => diff_named_fields(
+            b.field_len(),
+            |i| b.name_at(i),
+            |name| a.field(name),
+            |name| b.field(name),
+        )
+        .map_or_else(
+            || Diff::Replaced(b.clone_value()),
+            |fields| wrap_fields(fields, |fields| Diff::StructVariant { variant, fields }),
+        ),
+        VariantType::Tuple => diff_fields(
+            a.field_len(),
+            b.field_len(),
+            |i| a.field_at(i),
+            |i| b.field_at(i),
+        )
+        .map_or_else(
+            || Diff::Replaced(b.clone_value()),
+            |fields| wrap_fields(fields, |fields| Diff::TupleVariant { variant, fields }),
+        ),
+        VariantType::Unit => Diff::Unchanged,
+    }
+}
+
+/// Wraps the changed fields of a value into a diff, which is unchanged if no field changed.
+fn wrap_fields<K>(fields: Vec<(K, Diff)>, wrap: impl FnOnce(Vec<(K, Diff)>) -> Diff) -> Diff {
+    if fields.is_empty() {
+        Diff::Unchanged
+    } else {
+        wrap(fields)
+    }
+}
+
+/// Diffs the fields named by `name_at`, returning `None` if a field is missing.
+fn diff_named_fields<'a>(
+    len: usize,
+    name_at: impl Fn(usize) -> Option<&'a str>,
+    a: impl Fn(&str) -> Option<&'a dyn Reflect>,
+    b: impl Fn(&str) -> Option<&'a dyn Reflect>,
+) -> Option<Vec<(String, Diff)>> {
+    let mut fields = Vec::new();
+    for index in 0..len {
+        let name = name_at(index)?;
+        let diff = diff(a(name)?, b(name)?);
+        if !diff.is_unchanged() {
+            fields.push((name.to_string(), diff));
+        }
+    }
+    Some(fields)
+}
+
+/// Diffs indexed fields, returning `None` if both values have a different number of fields.
+fn diff_fields<'a>(
+    a_len: usize,
+    b_len: usize,
+    a: impl Fn(usize) -> Option<&'a dyn Reflect>,
+    b: impl Fn(usize) -> Option<&'a dyn Reflect>,
+) -> Option<Vec<(usize, Diff)>> {
+    if a_len != b_len {
+        return None;
+    }
+    let mut fields = Vec::new();
+    for index in 0..b_len {
+        let diff = diff(a(index)?, b(index)?);
+        if !diff.is_unchanged() {
+            fields.push((index, diff));
+        }
+    }
+    Some(fields)
+}
+
+/// The largest number of elements inserted or removed between two lists for which
+/// [`diff_list`] looks for the changes, above which the list is replaced instead.
+const MAX_LIST_EDIT_DISTANCE: usize = 256;
+
+/// A step of the shortest edit script turning a list into another one.
+enum ListStep {
+    Keep,
+    Insert(usize),
+    Remove(usize),
+}
+
+/// Diffs two lists using their shortest edit script, so that the elements inserted or removed
+/// in the middle of a list don't modify all the following ones.
+///
+/// The common prefix and suffix of both lists are skipped, and the changes in between are
+/// found with Myers' algorithm, taking `O((N + M) * D)` comparisons for `D` inserted or removed
+/// elements. Lists with more than [`MAX_LIST_EDIT_DISTANCE`] of them are replaced.
+fn diff_list(a: &dyn List, b: &dyn List) -> Diff {
+    let equal =
+        |i: usize, j: usize| a.get(i).unwrap().reflect_partial_eq(b.get(j).unwrap()) == Some(true);
+
+    let (a_len, b_len) = (a.len(), b.len());
+    let mut prefix = 0;
+    while prefix < a_len.min(b_len) && equal(prefix, prefix) {
+        prefix += 1;
+    }
+    let mut suffix = 0;
+    while suffix < a_len.min(b_len) - prefix && equal(a_len - suffix - 1, b_len - suffix - 1) {
+        suffix += 1;
+    }
+
+    let Some(steps) = shortest_edit_script(prefix..a_len - suffix, prefix..b_len - suffix, equal)
+    else {
+        return Diff::Replaced(b.clone_value());
+    };
+
+    // Elements removed and inserted at the same position are modified instead
+    let mut changes = Vec::new();
+    let mut index = prefix;
+    let mut steps = steps.into_iter().peekable();
+    while let Some(step) = steps.next() {
+        let (mut removed, mut inserted): (Vec<usize>, Vec<usize>) = (Vec::new(), Vec::new());
+        match step {
+            ListStep::Keep => {
+                index += 1;
+                continue;
+            }
+            ListStep::Insert(j) => inserted.push(j),
+            ListStep::Remove(i) => removed.push(i),
+        }
+        while let Some(step) = steps.next_if(|step| !matches!(step, ListStep::Keep)) {
+            match step {
+                ListStep::Insert(j) => inserted.push(j),
+                ListStep::Remove(i) => removed.push(i),
+                ListStep::Keep => unreachable!(),
+            }
+        }
+
+        let modified = removed.len().min(inserted.len());
+        for (&i, &j) in removed[..].iter().zip(&inserted) {
+            let diff = diff(a.get(i).unwrap(), b.get(j).unwrap());
+            if !diff.is_unchanged() {
+                changes.push(ListChange::Modify(index, diff));
+            }
+            index += 1;
+        }
+        for _ in modified..removed.len() {
+            changes.push(ListChange::Remove(index));
+        }
+        for &j in &inserted[modified..] {
+            changes.push(ListChange::Insert(index, b.get(j).unwrap().clone_value()));
+            index += 1;
+        }
+    }
+
+    if changes.is_empty() {
+        Diff::Unchanged
+    } else {
+        Diff::List(changes)
+    }
+}
+
+/// Returns the shortest edit script turning the elements of `a` into those of `b`, given the
+/// indices of both ranges of elements, or `None` if it inserts or removes more than
+/// [`MAX_LIST_EDIT_DISTANCE`] elements.
+fn shortest_edit_script(
+    a: Range<usize>,
+    b: Range<usize>,
+    equal: impl Fn(usize, usize) -> bool,
+) -> Option<Vec<ListStep>> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max = (a.len() + b.len()).min(MAX_LIST_EDIT_DISTANCE) as isize;
+
+    // `furthest[k]` is the furthest index in `a` reached on the diagonal `k = x - y`
+    let diagonal = |k: isize| (k + max + 1) as usize;
+    let mut furthest = vec![0isize; 2 * max as usize + 3];
+    let mut trace = Vec::new();
+    for d in 0..=max {
+        trace.push(furthest.clone());
+        for k in (-d..=d).step_by(2) {
+            let mut x =
+                if k == -d || (k != d && furthest[diagonal(k - 1)] < furthest[diagonal(k + 1)]) {
+                    furthest[diagonal(k + 1)]
+                } else {
+                    furthest[diagonal(k - 1)] + 1
+                };
+            let mut y = x - k;
+            while x < n && y < m && equal(a.start + x as usize, b.start + y as usize) {
+                x += 1;
+                y += 1;
+            }
+            furthest[diagonal(k)] = x;
+
+            if x >= n && y >= m {
+                return Some(backtrack_edit_script(
+                    &trace, n, m, diagonal, a.start, b.start,
+                ));
+            }
+        }
+    }
+    None
+}
+
+/// Follows the furthest reached indices of each step of [`shortest_edit_script`] backwards to
+/// collect its edit script.
+fn backtrack_edit_script(
+    trace: &[Vec<isize>],
+    n: isize,
+    m: isize,
+    diagonal: impl Fn(isize) -> usize,
+    a_start: usize,
+    b_start: usize,
+) -> Vec<ListStep> {
+    let mut steps = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, furthest) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let k = x - y;
+        let previous_k =
+            if k == -d || (k != d && furthest[diagonal(k - 1)] < furthest[diagonal(k + 1)]) {
+                k + 1
+            } else {
+                k - 1
+            };
+        let previous_x = furthest[diagonal(previous_k)];
+        let previous_y = previous_x - previous_k;
+
+        while x > previous_x && y > previous_y {
+            steps.push(ListStep::Keep);
+            x -= 1;
+            y -= 1;
+        }
+        if d > 0 {
+            if x == previous_x {
+                steps.push(ListStep::Insert(b_start + previous_y as usize));
+            } else {
+                steps.push(ListStep::Remove(a_start + previous_x as usize));
+            }
+        }
+        x = previous_x;
+        y = previous_y;
+    }
+    steps.reverse();
+    steps
+}
+
+fn diff_map(a: &dyn Map, b: &dyn Map) -> Diff {
+    let mut changes = Vec::new();
+    for (key, _) in a.iter() {
+        if b.get(key).is_none() {
+            changes.push(MapChange::Remove(key.clone_value()));
+        }
+    }
+    for (key, b_value) in b.iter() {
+        match a.get(key) {
+            Some(a_value) => {
+                let diff = diff(a_value, b_value);
+                if !diff.is_unchanged() {
+                    changes.push(MapChange::Modify(key.clone_value(), diff));
+                }
+            }
+            None => changes.push(MapChange::Insert(key.clone_value(), b_value.clone_value())),
+        }
+    }
+
+    if changes.is_empty() {
+        Diff::Unchanged
+    } else {
+        Diff::Map(changes)
+    }
+}
+
+/// Replaces `value`, falling back to [`Reflect::apply`] for replacements of the same kind, such
+/// as a dynamic value representing the type of `value`.
+fn apply_replacement(
+    replacement: &dyn Reflect,
+    value: &mut dyn Reflect,
+) -> Result<(), DiffApplyError> {
+    let Err(replacement) = value.set(replacement.clone_value()) else {
+        return Ok(());
+    };
+
+    match (replacement.reflect_ref(), value.reflect_ref()) {
+        (ReflectRef::Value(_), _) | (_, ReflectRef::Value(_)) => {}
+        (ReflectRef::Struct(_), ReflectRef::Struct(_))
+        | (ReflectRef::TupleStruct(_), ReflectRef::TupleStruct(_))
+        | (ReflectRef::Tuple(_), ReflectRef::Tuple(_))
+        | (ReflectRef::Array(_), ReflectRef::Array(_))
+        | (ReflectRef::Enum(_), ReflectRef::Enum(_)) => {
+            value.apply(replacement.as_ref());
+            return Ok(());
+        }
+        (ReflectRef::List(replacement), ReflectRef::List(_)) => {
+            let ReflectMut::List(list) = value.reflect_mut() else {
+                unreachable!()
+            };
+            while list.len() > replacement.len() {
+                list.pop();
+            }
+            list.apply(replacement.as_reflect());
+            return Ok(());
+        }
+        (ReflectRef::Map(replacement), ReflectRef::Map(_)) => {
+            let ReflectMut::Map(map) = value.reflect_mut() else {
+                unreachable!()
+            };
+            let removed: Vec<_> = map
+                .iter()
+                .filter(|(key, _)| replacement.get(*key).is_none())
+                .map(|(key, _)| key.clone_value())
+                .collect();
+            for key in removed {
+                map.remove(key.as_ref());
+            }
+            map.apply(replacement.as_reflect());
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    Err(DiffApplyError::MismatchedTypes {
+        replacement: replacement.type_name().to_string(),
+        value: value.type_name().to_string(),
+    })
+}
+
+fn apply_named_fields<T: ?Sized>(
+    fields: &[(String, Diff)],
+    value: &mut T,
+    field_mut: for<'a> fn(&'a mut T, &str) -> Option<&'a mut dyn Reflect>,
+) -> Result<(), DiffApplyError> {
+    for (name, diff) in fields {
+        let field = field_mut(value, name).ok_or_else(|| DiffApplyError::MissingField {
+            field: name.clone(),
+        })?;
+        diff.apply(field)?;
+    }
+    Ok(())
+}
+
+fn apply_indexed_fields<T: ?Sized>(
+    fields: &[(usize, Diff)],
+    value: &mut T,
+    field_mut: fn(&mut T, usize) -> Option<&mut dyn Reflect>,
+) -> Result<(), DiffApplyError> {
+    for (index, diff) in fields {
+        let field =
+            field_mut(value, *index).ok_or(DiffApplyError::MissingIndex { index: *index })?;
+        diff.apply(field)?;
+    }
+    Ok(())
+}
+
+fn apply_list_changes(changes: &[ListChange], list: &mut dyn List) -> Result<(), DiffApplyError> {
+    for change in changes {
+        match change {
+            ListChange::Insert(index, element) => {
+                if *index > list.len() {
+                    return Err(DiffApplyError::MissingIndex { index: *index });
+                }
+                list.try_insert(*index, element.clone_value())
+                    .map_err(|element| DiffApplyError::MismatchedElement {
+                        element: element.type_name().to_string(),
+                        container: list.type_name().to_string(),
+                    })?;
+            }
+            ListChange::Remove(index) => {
+                if *index >= list.len() {
+                    return Err(DiffApplyError::MissingIndex { index: *index });
+                }
+                list.remove(*index);
+            }
+            ListChange::Modify(index, diff) => {
+                let element = list
+                    .get_mut(*index)
+                    .ok_or(DiffApplyError::MissingIndex { index: *index })?;
+                diff.apply(element)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn apply_map_changes(changes: &[MapChange], map: &mut dyn Map) -> Result<(), DiffApplyError> {
+    for change in changes {
+        match change {
+            MapChange::Insert(key, value) => {
+                map.try_insert_boxed(key.clone_value(), value.clone_value())
+                    .map_err(|element| DiffApplyError::MismatchedElement {
+                        element: element.type_name().to_string(),
+                        container: map.type_name().to_string(),
+                    })?;
+            }
+            MapChange::Remove(key) => {
+                map.remove(key.as_ref());
+            }
+            MapChange::Modify(key, diff) => {
+                let value =
+                    map.get_mut(key.as_ref())
+                        .ok_or_else(|| DiffApplyError::MissingKey {
+                            key: format!("{key:?}"),
+                        })?;
+                diff.apply(value)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn variant_mut<'a>(
+    value: &'a mut dyn Reflect,
+    variant: &str,
+) -> Result<&'a mut dyn Enum, DiffApplyError> {
+    let ReflectMut::Enum(value) = value.reflect_mut() else {
+        return Err(DiffApplyError::MismatchedKinds { expected: "enum" });
+    };
+    if value.variant_name() != variant {
+        return Err(DiffApplyError::MismatchedVariants {
+            expected: variant.to_string(),
+            found: value.variant_name().to_string(),
+        });
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, Diff, DiffApplyError, ListChange, MapChange};
+    use crate as bevy_reflect;
+    use crate::{FromReflect, Reflect};
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, FromReflect, Clone, Debug, PartialEq)]
+    enum Shape {
+        Circle { radius: f32 },
+        Rectangle(f32, f32),
+        Point,
+    }
+
+    #[derive(Reflect, FromReflect, Clone, Debug, PartialEq)]
+    struct Item {
+        name: String,
+        count: u32,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Level {
+        shapes: Vec<Shape>,
+        items: Vec<Item>,
+        scores: HashMap<String, u32>,
+        position: (f32, f32),
+        grid: [u8; 3],
+    }
+
+    fn level() -> Level {
+        Level {
+            shapes: vec![Shape::Circle { radius: 1.0 }, Shape::Point],
+            items: vec![
+                Item {
+                    name: String::from("sword"),
+                    count: 1,
+                },
+                Item {
+                    name: String::from("potion"),
+                    count: 3,
+                },
+            ],
+            scores: HashMap::from_iter([(String::from("a"), 1), (String::from("b"), 2)]),
+            position: (0.0, 0.0),
+            grid: [0, 1, 2],
+        }
+    }
+
+    fn assert_patch(a: &Level, b: &Level) {
+        let diff = diff(a, b);
+        let mut patched = a.clone();
+        diff.apply(&mut patched).unwrap();
+        assert_eq!(&patched, b);
+    }
+
+    #[test]
+    fn should_not_diff_equal_values() {
+        assert!(diff(&level(), &level()).is_unchanged());
+        assert!(diff(&123_u32, &123_u32).is_unchanged());
+    }
+
+    #[test]
+    fn should_diff_changed_fields() {
+        let a = level();
+        let mut b = level();
+        b.position.1 = 2.0;
+        b.grid[2] = 3;
+
+        let Diff::Struct(fields) = diff(&a, &b) else {
+            panic!("expected a struct diff");
+        };
+        let names: Vec<_> = fields.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["position", "grid"]);
+        assert!(matches!(&fields[0].1, Diff::Tuple(fields) if fields[0].0 == 1));
+        assert!(matches!(&fields[1].1, Diff::Array(fields) if fields[0].0 == 2));
+        assert_patch(&a, &b);
+    }
+
+    #[test]
+    fn should_diff_list_insertions_and_removals() {
+        let a = level();
+        let mut b = level();
+        b.items.insert(
+            0,
+            Item {
+                name: String::from("shield"),
+                count: 1,
+            },
+        );
+        b.items[2].count = 2;
+        b.shapes.remove(0);
+
+        let diff = diff(&a, &b);
+        let Diff::Struct(fields) = &diff else {
+            panic!("expected a struct diff");
+        };
+        let Diff::List(shapes) = &fields[0].1 else {
+            panic!("expected a list diff");
+        };
+        assert!(matches!(shapes[..], [ListChange::Remove(0)]));
+        let Diff::List(items) = &fields[1].1 else {
+            panic!("expected a list diff");
+        };
+        assert!(matches!(
+            items[..],
+            [
+                ListChange::Insert(0, _),
+                ListChange::Modify(2, Diff::Struct(_))
+            ]
+        ));
+        assert_patch(&a, &b);
+
+        let mut c = level();
+        c.items.clear();
+        assert_patch(&a, &c);
+        assert_patch(&c, &a);
+    }
+
+    #[test]
+    fn should_diff_long_lists() {
+        let a: Vec<u32> = (0..10_000).collect();
+        let mut b = a.clone();
+        b.insert(5_000, 42);
+        b[7_000] = 7;
+        b.remove(9_000);
+
+        let list_diff = diff(&a, &b);
+        let Diff::List(changes) = &list_diff else {
+            panic!("expected a list diff");
+        };
+        assert!(matches!(
+            changes[..],
+            [
+                ListChange::Insert(5_000, _),
+                ListChange::Modify(7_000, Diff::Replaced(_)),
+                ListChange::Remove(9_000)
+            ]
+        ));
+        let mut patched = a.clone();
+        list_diff.apply(&mut patched).unwrap();
+        assert_eq!(patched, b);
+
+        let c: Vec<u32> = (0..10_000).rev().collect();
+        let list_diff = diff(&a, &c);
+        assert!(matches!(list_diff, Diff::Replaced(_)));
+        let mut patched = a.clone();
+        list_diff.apply(&mut patched).unwrap();
+        assert_eq!(patched, c);
+    }
+
+    #[test]
+    fn should_diff_map_entries() {
+        let a = level();
+        let mut b = level();
+        b.scores.remove("a");
+        b.scores.insert(String::from("b"), 3);
+        b.scores.insert(String::from("c"), 4);
+
+        let Diff::Struct(fields) = diff(&a, &b) else {
+            panic!("expected a struct diff");
+        };
+        let Diff::Map(changes) = &fields[0].1 else {
+            panic!("expected a map diff");
+        };
+        assert_eq!(changes.len(), 3);
+        assert!(matches!(changes[0], MapChange::Remove(_)));
+        assert_patch(&a, &b);
+    }
+
+    #[test]
+    fn should_diff_enums() {
+        let a = Shape::Circle { radius: 1.0 };
+        let b = Shape::Circle { radius: 2.0 };
+        assert!(matches!(
+            diff(&a, &b),
+            Diff::StructVariant { variant, .. } if variant == "Circle"
+        ));
+
+        let c = Shape::Rectangle(1.0, 2.0);
+        assert!(matches!(diff(&a, &c), Diff::Replaced(_)));
+        let d = Shape::Rectangle(1.0, 3.0);
+        assert!(matches!(
+            diff(&c, &d),
+            Diff::TupleVariant { fields, .. } if fields[0].0 == 1
+        ));
+
+        let mut value = a.clone();
+        diff(&a, &c).apply(&mut value).unwrap();
+        assert_eq!(value, c);
+        diff(&c, &d).apply(&mut value).unwrap();
+        assert_eq!(value, d);
+        assert_eq!(
+            diff(&a, &b).apply(&mut value),
+            Err(DiffApplyError::MismatchedVariants {
+                expected: String::from("Circle"),
+                found: String::from("Rectangle"),
+            })
+        );
+    }
+    #[test]
+    fn should_not_insert_mismatched_elements() {
+        let mut list = vec![1_u32, 2];
+        let list_diff = Diff::List(vec![ListChange::Insert(1, Box::new(String::from("a")))]);
+        assert!(matches!(
+            list_diff.apply(&mut list),
+            Err(DiffApplyError::MismatchedElement { .. })
+        ));
+        assert_eq!(list, [1, 2]);
+
+        let mut map = HashMap::from_iter([(String::from("a"), 1_u32)]);
+        let map_diff = Diff::Map(vec![MapChange::Insert(
+            Box::new(String::from("b")),
+            Box::new(2.0_f32),
+        )]);
+        assert!(matches!(
+            map_diff.apply(&mut map),
+            Err(DiffApplyError::MismatchedElement { .. })
+        ));
+        let map_diff = Diff::Map(vec![MapChange::Insert(Box::new(2_u32), Box::new(2_u32))]);
+        assert!(matches!(
+            map_diff.apply(&mut map),
+            Err(DiffApplyError::MismatchedElement { .. })
+        ));
+        assert_eq!(map.len(), 1);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{DynamicEnum, Reflect, VariantInfo, VariantType};
+use crate::{CustomAttributes, DynamicEnum, Reflect, TypePath, VariantInfo, VariantType};
 use bevy_utils::HashMap;
 use std::any::{Any, TypeId};
 use std::slice::Iter;
@@ -135,10 +135,12 @@ pub trait Enum: Reflect {
 pub struct EnumInfo {
     name: &'static str,
     type_name: &'static str,
+    type_path: &'static str,
     type_id: TypeId,
     variants: Box<[VariantInfo]>,
     variant_names: Box<[&'static str]>,
     variant_indices: HashMap<&'static str, usize>,
+    custom_attributes: CustomAttributes,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -151,7 +153,7 @@ impl EnumInfo {
     /// * `name`: The name of this enum (_without_ generics or lifetimes)
     /// * `variants`: The variants of this enum in the order they are defined
     ///
-    pub fn new<TEnum: Enum>(name: &'static str, variants: &[VariantInfo]) -> Self {
+    pub fn new<TEnum: Enum + TypePath>(name: &'static str, variants: &[VariantInfo]) -> Self {
         let variant_indices = variants
             .iter()
             .enumerate()
@@ -163,10 +165,12 @@ impl EnumInfo {
         Self {
             name,
             type_name: std::any::type_name::<TEnum>(),
+            type_path: TEnum::type_path(),
             type_id: TypeId::of::<TEnum>(),
             variants: variants.to_vec().into_boxed_slice(),
             variant_names,
             variant_indices,
+            custom_attributes: CustomAttributes::default(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -178,6 +182,14 @@ impl EnumInfo {
         Self { docs, ..self }
     }
 
+    /// Sets the custom attributes for this enum.
+    pub fn with_custom_attributes(self, custom_attributes: CustomAttributes) -> Self {
+        Self {
+            custom_attributes,
+            ..self
+        }
+    }
+
     /// A slice containing the names of all variants in order.
     pub fn variant_names(&self) -> &[&'static str] {
         &self.variant_names
@@ -238,6 +250,13 @@ impl EnumInfo {
         self.type_name
     }
 
+    /// The [type path] of the enum.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
     /// The [`TypeId`] of the enum.
     pub fn type_id(&self) -> TypeId {
         self.type_id
@@ -248,6 +267,11 @@ impl EnumInfo {
         TypeId::of::<T>() == self.type_id
     }
 
+    /// The custom attributes of this enum.
+    pub fn custom_attributes(&self) -> &CustomAttributes {
+        &self.custom_attributes
+    }
+
     /// The docstring of this enum, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
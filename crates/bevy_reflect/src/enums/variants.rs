@@ -1,4 +1,4 @@
-use crate::{NamedField, UnnamedField};
+use crate::{CustomAttributes, NamedField, UnnamedField};
 use bevy_utils::HashMap;
 use std::slice::Iter;
 
@@ -73,6 +73,15 @@ impl VariantInfo {
         }
     }
 
+    /// The custom attributes of the underlying variant.
+    pub fn custom_attributes(&self) -> &CustomAttributes {
+        match self {
+            Self::Struct(info) => info.custom_attributes(),
+            Self::Tuple(info) => info.custom_attributes(),
+            Self::Unit(info) => info.custom_attributes(),
+        }
+    }
+
     /// The docstring of the underlying variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&str> {
@@ -91,6 +100,7 @@ pub struct StructVariantInfo {
     fields: Box<[NamedField]>,
     field_names: Box<[&'static str]>,
     field_indices: HashMap<&'static str, usize>,
+    custom_attributes: CustomAttributes,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -105,6 +115,7 @@ impl StructVariantInfo {
             fields: fields.to_vec().into_boxed_slice(),
             field_names,
             field_indices,
+            custom_attributes: CustomAttributes::default(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -116,6 +127,14 @@ impl StructVariantInfo {
         Self { docs, ..self }
     }
 
+    /// Sets the custom attributes for this variant.
+    pub fn with_custom_attributes(self, custom_attributes: CustomAttributes) -> Self {
+        Self {
+            custom_attributes,
+            ..self
+        }
+    }
+
     /// The name of this variant.
     pub fn name(&self) -> &'static str {
         self.name
@@ -161,6 +180,11 @@ impl StructVariantInfo {
             .collect()
     }
 
+    /// The custom attributes of this variant.
+    pub fn custom_attributes(&self) -> &CustomAttributes {
+        &self.custom_attributes
+    }
+
     /// The docstring of this variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
@@ -173,6 +197,7 @@ impl StructVariantInfo {
 pub struct TupleVariantInfo {
     name: &'static str,
     fields: Box<[UnnamedField]>,
+    custom_attributes: CustomAttributes,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -183,6 +208,7 @@ impl TupleVariantInfo {
         Self {
             name,
             fields: fields.to_vec().into_boxed_slice(),
+            custom_attributes: CustomAttributes::default(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -194,6 +220,14 @@ impl TupleVariantInfo {
         Self { docs, ..self }
     }
 
+    /// Sets the custom attributes for this variant.
+    pub fn with_custom_attributes(self, custom_attributes: CustomAttributes) -> Self {
+        Self {
+            custom_attributes,
+            ..self
+        }
+    }
+
     /// The name of this variant.
     pub fn name(&self) -> &'static str {
         self.name
@@ -214,6 +248,11 @@ impl TupleVariantInfo {
         self.fields.len()
     }
 
+    /// The custom attributes of this variant.
+    pub fn custom_attributes(&self) -> &CustomAttributes {
+        &self.custom_attributes
+    }
+
     /// The docstring of this variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
@@ -225,6 +264,7 @@ impl TupleVariantInfo {
 #[derive(Clone, Debug)]
 pub struct UnitVariantInfo {
     name: &'static str,
+    custom_attributes: CustomAttributes,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -234,6 +274,7 @@ impl UnitVariantInfo {
     pub fn new(name: &'static str) -> Self {
         Self {
             name,
+            custom_attributes: CustomAttributes::default(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -245,11 +286,24 @@ impl UnitVariantInfo {
         Self { docs, ..self }
     }
 
+    /// Sets the custom attributes for this variant.
+    pub fn with_custom_attributes(self, custom_attributes: CustomAttributes) -> Self {
+        Self {
+            custom_attributes,
+            ..self
+        }
+    }
+
     /// The name of this variant.
     pub fn name(&self) -> &'static str {
         self.name
     }
 
+    /// The custom attributes of this variant.
+    pub fn custom_attributes(&self) -> &CustomAttributes {
+        &self.custom_attributes
+    }
+
     /// The docstring of this variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
@@ -1,4 +1,4 @@
-use crate::Reflect;
+use crate::{CustomAttributes, Reflect};
 use std::any::{Any, TypeId};
 
 /// The named field of a reflected struct.
@@ -7,6 +7,7 @@ pub struct NamedField {
     name: &'static str,
     type_name: &'static str,
     type_id: TypeId,
+    custom_attributes: CustomAttributes,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -18,6 +19,7 @@ impl NamedField {
             name,
             type_name: std::any::type_name::<T>(),
             type_id: TypeId::of::<T>(),
+            custom_attributes: CustomAttributes::default(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -29,6 +31,14 @@ impl NamedField {
         Self { docs, ..self }
     }
 
+    /// Sets the custom attributes for this field.
+    pub fn with_custom_attributes(self, custom_attributes: CustomAttributes) -> Self {
+        Self {
+            custom_attributes,
+            ..self
+        }
+    }
+
     /// The name of the field.
     pub fn name(&self) -> &'static str {
         self.name
@@ -51,6 +61,11 @@ impl NamedField {
         TypeId::of::<T>() == self.type_id
     }
 
+    /// The custom attributes of this field.
+    pub fn custom_attributes(&self) -> &CustomAttributes {
+        &self.custom_attributes
+    }
+
     /// The docstring of this field, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
@@ -64,6 +79,7 @@ pub struct UnnamedField {
     index: usize,
     type_name: &'static str,
     type_id: TypeId,
+    custom_attributes: CustomAttributes,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -74,6 +90,7 @@ impl UnnamedField {
             index,
             type_name: std::any::type_name::<T>(),
             type_id: TypeId::of::<T>(),
+            custom_attributes: CustomAttributes::default(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -85,6 +102,14 @@ impl UnnamedField {
         Self { docs, ..self }
     }
 
+    /// Sets the custom attributes for this field.
+    pub fn with_custom_attributes(self, custom_attributes: CustomAttributes) -> Self {
+        Self {
+            custom_attributes,
+            ..self
+        }
+    }
+
     /// Returns the index of the field.
     pub fn index(&self) -> usize {
         self.index
@@ -107,6 +132,11 @@ impl UnnamedField {
         TypeId::of::<T>() == self.type_id
     }
 
+    /// The custom attributes of this field.
+    pub fn custom_attributes(&self) -> &CustomAttributes {
+        &self.custom_attributes
+    }
+
     /// The docstring of this field, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
@@ -0,0 +1,248 @@
+use crate::func::{ArgInfo, FunctionError, Ownership};
+use crate::{FromReflect, Reflect, TypePath};
+
+/// An argument passed to a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug)]
+pub enum Arg<'a> {
+    /// An owned value, taken by value by the function.
+    Owned(Box<dyn Reflect>),
+    /// A borrowed value, taken by reference by the function.
+    Ref(&'a dyn Reflect),
+    /// A mutably borrowed value, taken by mutable reference by the function.
+    Mut(&'a mut dyn Reflect),
+}
+
+impl<'a> Arg<'a> {
+    /// Returns the [`Ownership`] of this argument.
+    pub fn ownership(&self) -> Ownership {
+        match self {
+            Self::Owned(_) => Ownership::Owned,
+            Self::Ref(_) => Ownership::Ref,
+            Self::Mut(_) => Ownership::Mut,
+        }
+    }
+
+    /// Returns the value of this argument.
+    pub fn value(&self) -> &dyn Reflect {
+        match self {
+            Self::Owned(value) => value.as_ref(),
+            Self::Ref(value) => *value,
+            Self::Mut(value) => *value,
+        }
+    }
+}
+
+/// An ordered list of [arguments] to call a [`DynamicFunction`] with.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::func::{ArgList, Ownership};
+/// let mut value = 123_i32;
+/// let args = ArgList::new()
+///     .with_owned(String::from("Hello"))
+///     .with_ref(&1.5_f32)
+///     .with_mut(&mut value);
+///
+/// assert_eq!(args.len(), 3);
+/// assert_eq!(args.get(1).unwrap().ownership(), Ownership::Ref);
+/// ```
+///
+/// [arguments]: Arg
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Default)]
+pub struct ArgList<'a>(Vec<Arg<'a>>);
+
+impl<'a> ArgList<'a> {
+    /// Creates an empty [`ArgList`].
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Appends an [`Arg`] to the list.
+    pub fn push(&mut self, arg: Arg<'a>) {
+        self.0.push(arg);
+    }
+
+    /// Appends an owned value to the list.
+    pub fn push_owned<T: Reflect>(&mut self, value: T) {
+        self.push(Arg::Owned(Box::new(value)));
+    }
+
+    /// Appends a boxed owned value to the list.
+    pub fn push_boxed(&mut self, value: Box<dyn Reflect>) {
+        self.push(Arg::Owned(value));
+    }
+
+    /// Appends a reference to the list.
+    pub fn push_ref(&mut self, value: &'a dyn Reflect) {
+        self.push(Arg::Ref(value));
+    }
+
+    /// Appends a mutable reference to the list.
+    pub fn push_mut(&mut self, value: &'a mut dyn Reflect) {
+        self.push(Arg::Mut(value));
+    }
+
+    /// Appends an [`Arg`] to the list and returns the list.
+    pub fn with_arg(mut self, arg: Arg<'a>) -> Self {
+        self.push(arg);
+        self
+    }
+
+    /// Appends an owned value to the list and returns the list.
+    pub fn with_owned<T: Reflect>(mut self, value: T) -> Self {
+        self.push_owned(value);
+        self
+    }
+
+    /// Appends a boxed owned value to the list and returns the list.
+    pub fn with_boxed(mut self, value: Box<dyn Reflect>) -> Self {
+        self.push_boxed(value);
+        self
+    }
+
+    /// Appends a reference to the list and returns the list.
+    pub fn with_ref(mut self, value: &'a dyn Reflect) -> Self {
+        self.push_ref(value);
+        self
+    }
+
+    /// Appends a mutable reference to the list and returns the list.
+    pub fn with_mut(mut self, value: &'a mut dyn Reflect) -> Self {
+        self.push_mut(value);
+        self
+    }
+
+    /// Returns the argument at the given index, if any.
+    pub fn get(&self, index: usize) -> Option<&Arg<'a>> {
+        self.0.get(index)
+    }
+
+    /// Returns an iterator over the arguments.
+    pub fn iter(&self) -> std::slice::Iter<'_, Arg<'a>> {
+        self.0.iter()
+    }
+
+    /// Returns the number of arguments in the list.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the list contains no arguments.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for ArgList<'a> {
+    type Item = Arg<'a>;
+    type IntoIter = std::vec::IntoIter<Arg<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Marks an argument taken by value, for use with [`FromArg`].
+pub struct OwnedArg;
+
+/// Marks an argument taken by reference, for use with [`FromArg`].
+pub struct RefArg;
+
+/// Marks an argument taken by mutable reference, for use with [`FromArg`].
+pub struct MutArg;
+
+/// A type that can be taken from an [`Arg`] when calling a [`DynamicFunction`].
+///
+/// This trait is implemented for all types implementing [`FromReflect`] and [`TypePath`], which
+/// are taken by value, as well as references and mutable references to types implementing
+/// [`Reflect`] and [`TypePath`].
+/// The `Marker` parameter keeps these implementations apart and is inferred by
+/// [`IntoFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+/// [`IntoFunction`]: crate::func::IntoFunction
+pub trait FromArg<Marker> {
+    /// The type of the argument, borrowing from the [`Arg`] it's taken from.
+    type Item<'a>;
+
+    /// Returns the [`ArgInfo`] for this type when passed at the given index.
+    fn arg_info(index: usize) -> ArgInfo;
+
+    /// Takes the argument at the given index from `arg`.
+    ///
+    /// Returns an error if the argument has the wrong [`Ownership`] or type.
+    fn from_arg(arg: Arg<'_>, index: usize) -> Result<Self::Item<'_>, FunctionError>;
+}
+
+impl<T: FromReflect + TypePath> FromArg<OwnedArg> for T {
+    type Item<'a> = T;
+
+    fn arg_info(index: usize) -> ArgInfo {
+        ArgInfo::new::<T>(index, Ownership::Owned)
+    }
+
+    fn from_arg(arg: Arg<'_>, index: usize) -> Result<Self::Item<'_>, FunctionError> {
+        let Arg::Owned(value) = arg else {
+            return Err(FunctionError::invalid_ownership(
+                index,
+                Ownership::Owned,
+                &arg,
+            ));
+        };
+
+        // Dynamic values, such as those created by a scripting language, are converted
+        value.take::<T>().or_else(|value| {
+            T::from_reflect(value.as_ref())
+                .ok_or_else(|| FunctionError::unexpected_type::<T>(index, value.as_ref()))
+        })
+    }
+}
+
+impl<T: Reflect + TypePath> FromArg<RefArg> for &'static T {
+    type Item<'a> = &'a T;
+
+    fn arg_info(index: usize) -> ArgInfo {
+        ArgInfo::new::<T>(index, Ownership::Ref)
+    }
+
+    fn from_arg(arg: Arg<'_>, index: usize) -> Result<Self::Item<'_>, FunctionError> {
+        let Arg::Ref(value) = arg else {
+            return Err(FunctionError::invalid_ownership(
+                index,
+                Ownership::Ref,
+                &arg,
+            ));
+        };
+
+        value
+            .downcast_ref::<T>()
+            .ok_or_else(|| FunctionError::unexpected_type::<T>(index, value))
+    }
+}
+
+impl<T: Reflect + TypePath> FromArg<MutArg> for &'static mut T {
+    type Item<'a> = &'a mut T;
+
+    fn arg_info(index: usize) -> ArgInfo {
+        ArgInfo::new::<T>(index, Ownership::Mut)
+    }
+
+    fn from_arg(arg: Arg<'_>, index: usize) -> Result<Self::Item<'_>, FunctionError> {
+        let Arg::Mut(value) = arg else {
+            return Err(FunctionError::invalid_ownership(
+                index,
+                Ownership::Mut,
+                &arg,
+            ));
+        };
+
+        if !value.is::<T>() {
+            return Err(FunctionError::unexpected_type::<T>(index, value));
+        }
+        Ok(value.downcast_mut::<T>().unwrap())
+    }
+}
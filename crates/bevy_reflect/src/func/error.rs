@@ -0,0 +1,51 @@
+use crate::func::{Arg, Ownership};
+use crate::{Reflect, TypePath};
+use thiserror::Error;
+
+/// An error that occurs when calling a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FunctionError {
+    /// The function was called with the wrong number of arguments.
+    #[error("expected {expected} arguments but received {received}")]
+    ArgCountMismatch { expected: usize, received: usize },
+    /// An argument was passed with the wrong [`Ownership`].
+    #[error("expected argument {index} to be {expected} but received {received}")]
+    InvalidOwnership {
+        index: usize,
+        expected: Ownership,
+        received: Ownership,
+    },
+    /// An argument has the wrong type.
+    ///
+    /// The expected type is given by its [type path], while the received one is given by its
+    /// [type name], as the type path of a reflected value isn't known without a registry.
+    ///
+    /// [type path]: TypePath::type_path
+    /// [type name]: Reflect::type_name
+    #[error("expected argument {index} to be of type `{expected}` but received `{received}`")]
+    UnexpectedType {
+        index: usize,
+        expected: &'static str,
+        received: String,
+    },
+}
+
+impl FunctionError {
+    pub(crate) fn invalid_ownership(index: usize, expected: Ownership, arg: &Arg) -> Self {
+        Self::InvalidOwnership {
+            index,
+            expected,
+            received: arg.ownership(),
+        }
+    }
+
+    pub(crate) fn unexpected_type<T: TypePath>(index: usize, received: &dyn Reflect) -> Self {
+        Self::UnexpectedType {
+            index,
+            expected: T::type_path(),
+            received: received.type_name().to_string(),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::func::{ArgList, FunctionError, FunctionInfo, Return};
+use std::borrow::Cow;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+/// The result of calling a [`DynamicFunction`].
+pub type FunctionResult<'a> = Result<Return<'a>, FunctionError>;
+
+/// A function or closure whose arguments and return value are passed as reflected values.
+///
+/// This allows Rust functions to be called dynamically, such as from a debug console or a
+/// scripting language, with the argument and return types described by its [`FunctionInfo`].
+///
+/// A [`DynamicFunction`] is usually created from a function or closure with
+/// [`IntoFunction::into_function`], and can be cheaply cloned.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::func::{ArgList, IntoFunction};
+/// fn add(a: i32, b: &i32) -> i32 {
+///     a + *b
+/// }
+///
+/// let func = add.into_function();
+/// assert_eq!(func.info().arg_count(), 2);
+///
+/// let args = ArgList::new().with_owned(25_i32).with_ref(&75_i32);
+/// let value = func.call(args).unwrap().unwrap_owned();
+/// assert_eq!(value.take::<i32>().unwrap(), 100);
+/// ```
+///
+/// [`IntoFunction::into_function`]: crate::func::IntoFunction::into_function
+#[derive(Clone)]
+pub struct DynamicFunction {
+    info: FunctionInfo,
+    func: Arc<dyn for<'a> Fn(ArgList<'a>) -> FunctionResult<'a> + Send + Sync>,
+}
+
+impl DynamicFunction {
+    /// Create a new [`DynamicFunction`] from a closure taking an [`ArgList`].
+    ///
+    /// The closure is only called with as many arguments as there are in `info`,
+    /// but is responsible for checking their ownership and types.
+    pub fn new<F>(func: F, info: FunctionInfo) -> Self
+    where
+        F: for<'a> Fn(ArgList<'a>) -> FunctionResult<'a> + Send + Sync + 'static,
+    {
+        Self {
+            info,
+            func: Arc::new(func),
+        }
+    }
+
+    /// Sets the name of this function.
+    pub fn with_name(self, name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            info: self.info.with_name(name),
+            ..self
+        }
+    }
+
+    /// Sets the names of the arguments of this function, in order.
+    pub fn with_arg_names<I>(self, names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Cow<'static, str>>,
+    {
+        Self {
+            info: self.info.with_arg_names(names),
+            ..self
+        }
+    }
+
+    /// Calls the function with the given arguments.
+    ///
+    /// Returns an error if the number of arguments doesn't match the function's
+    /// [`FunctionInfo`], or if an argument has the wrong type or [`Ownership`].
+    ///
+    /// [`Ownership`]: crate::func::Ownership
+    pub fn call<'a>(&self, args: ArgList<'a>) -> FunctionResult<'a> {
+        let expected = self.info.arg_count();
+        if args.len() != expected {
+            return Err(FunctionError::ArgCountMismatch {
+                expected,
+                received: args.len(),
+            });
+        }
+
+        (self.func)(args)
+    }
+
+    /// The [`FunctionInfo`] of this function.
+    pub fn info(&self) -> &FunctionInfo {
+        &self.info
+    }
+
+    /// The name of this function, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.info.name()
+    }
+}
+
+impl Debug for DynamicFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynamicFunction")
+            .field("info", &self.info)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{Reflect, TypePath};
+use std::any::{Any, TypeId};
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+/// How a [`DynamicFunction`] takes an argument or returns a value.
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Ownership {
+    /// The value is owned, such as `T`.
+    Owned,
+    /// The value is borrowed, such as `&T`.
+    Ref,
+    /// The value is mutably borrowed, such as `&mut T`.
+    Mut,
+}
+
+impl Display for Ownership {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Owned => write!(f, "owned"),
+            Self::Ref => write!(f, "a reference"),
+            Self::Mut => write!(f, "a mutable reference"),
+        }
+    }
+}
+
+/// Information about an argument of a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Clone)]
+pub struct ArgInfo {
+    index: usize,
+    name: Option<Cow<'static, str>>,
+    type_path: &'static str,
+    type_id: TypeId,
+    ownership: Ownership,
+}
+
+impl ArgInfo {
+    /// Create a new [`ArgInfo`] for an argument of type `T` at the given index.
+    ///
+    /// For references, `T` is the type being referenced.
+    pub fn new<T: Reflect + TypePath>(index: usize, ownership: Ownership) -> Self {
+        Self {
+            index,
+            name: None,
+            type_path: T::type_path(),
+            type_id: TypeId::of::<T>(),
+            ownership,
+        }
+    }
+
+    /// Sets the name of this argument.
+    pub fn with_name(self, name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..self
+        }
+    }
+
+    /// The index of this argument in the argument list.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The name of this argument, if any.
+    ///
+    /// Argument names can't be known from a function's signature,
+    /// so they need to be set manually with [`with_name`](Self::with_name).
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The [type path] of this argument, without any reference.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
+    /// The [`TypeId`] of this argument, without any reference.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Check if the given type matches the argument type, without any reference.
+    pub fn is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.type_id
+    }
+
+    /// How the argument is taken by the function.
+    pub fn ownership(&self) -> Ownership {
+        self.ownership
+    }
+}
+
+/// Information about the return value of a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Clone)]
+pub struct ReturnInfo {
+    type_path: &'static str,
+    type_id: TypeId,
+    ownership: Ownership,
+}
+
+impl ReturnInfo {
+    /// Create a new [`ReturnInfo`] for a return value of type `T`.
+    ///
+    /// For references, `T` is the type being referenced.
+    pub fn new<T: Reflect + TypePath>(ownership: Ownership) -> Self {
+        Self {
+            type_path: T::type_path(),
+            type_id: TypeId::of::<T>(),
+            ownership,
+        }
+    }
+
+    /// The [type path] of the return value, without any reference.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
+    /// The [`TypeId`] of the return value, without any reference.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Check if the given type matches the return type, without any reference.
+    pub fn is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.type_id
+    }
+
+    /// How the value is returned by the function.
+    pub fn ownership(&self) -> Ownership {
+        self.ownership
+    }
+}
+
+/// Information about a [`DynamicFunction`], such as its name and signature.
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Clone)]
+pub struct FunctionInfo {
+    name: Option<Cow<'static, str>>,
+    args: Box<[ArgInfo]>,
+    return_info: ReturnInfo,
+}
+
+impl FunctionInfo {
+    /// Create a new [`FunctionInfo`].
+    ///
+    /// # Arguments
+    ///
+    /// * `args`: The arguments of the function in the order they are taken
+    /// * `return_info`: The return value of the function
+    ///
+    pub fn new(args: Vec<ArgInfo>, return_info: ReturnInfo) -> Self {
+        Self {
+            name: None,
+            args: args.into_boxed_slice(),
+            return_info,
+        }
+    }
+
+    /// Sets the name of this function.
+    pub fn with_name(self, name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..self
+        }
+    }
+
+    /// Sets the names of the arguments of this function, in order.
+    ///
+    /// Arguments beyond the number of given names keep their current name.
+    pub fn with_arg_names<I>(mut self, names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Cow<'static, str>>,
+    {
+        for (arg, name) in self.args.iter_mut().zip(names) {
+            arg.name = Some(name.into());
+        }
+        self
+    }
+
+    /// The name of this function, if any.
+    ///
+    /// Functions created from function items are named after their [type name],
+    /// such as `my_crate::foo`. Closures have no name unless one is given.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The arguments of this function, in the order they are taken.
+    pub fn args(&self) -> &[ArgInfo] {
+        &self.args
+    }
+
+    /// The number of arguments this function takes.
+    pub fn arg_count(&self) -> usize {
+        self.args.len()
+    }
+
+    /// The return value of this function.
+    pub fn return_info(&self) -> &ReturnInfo {
+        &self.return_info
+    }
+}
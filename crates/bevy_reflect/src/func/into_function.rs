@@ -0,0 +1,200 @@
+use crate::func::{
+    ArgInfo, DynamicFunction, FromArg, FunctionInfo, MutArg, Ownership, RefArg, Return, ReturnInfo,
+};
+use crate::{Reflect, TypePath};
+
+/// A type that can be converted into a [`DynamicFunction`].
+///
+/// This trait is implemented for functions and closures taking up to 8 arguments that
+/// implement [`FromArg`], and returning a value implementing [`Reflect`] and [`TypePath`].
+/// Arguments may be taken by value, by reference or by mutable reference.
+///
+/// It's also implemented for methods that return a reference borrowed from their receiver,
+/// such as `fn value(&self) -> &T` and `fn value_mut(&mut self) -> &mut T`, which may take up
+/// to 8 arguments in addition to the receiver.
+///
+/// The `Marker` parameter keeps these implementations apart and is inferred.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::func::{ArgList, IntoFunction};
+/// # use bevy_reflect::Reflect;
+/// #[derive(Reflect)]
+/// struct Player {
+///     health: u32,
+/// }
+///
+/// impl Player {
+///     fn health_mut(&mut self) -> &mut u32 {
+///         &mut self.health
+///     }
+/// }
+///
+/// let func = Player::health_mut.into_function();
+/// let mut player = Player { health: 10 };
+///
+/// let health = func.call(ArgList::new().with_mut(&mut player)).unwrap().unwrap_mut();
+/// *health.downcast_mut::<u32>().unwrap() += 5;
+/// assert_eq!(player.health, 15);
+/// ```
+pub trait IntoFunction<Marker> {
+    /// Converts this value into a [`DynamicFunction`].
+    fn into_function(self) -> DynamicFunction;
+}
+
+impl IntoFunction<()> for DynamicFunction {
+    fn into_function(self) -> DynamicFunction {
+        self
+    }
+}
+
+/// Names the function after the [type name] of `F`, unless `F` is a closure.
+///
+/// [type name]: std::any::type_name
+fn with_default_name<F>(info: FunctionInfo) -> FunctionInfo {
+    let name = std::any::type_name::<F>();
+    if name.ends_with("{{closure}}") {
+        info
+    } else {
+        info.with_name(name)
+    }
+}
+
+macro_rules! impl_into_function {
+    ($(($arg:ident, $marker:ident, $index:tt)),*) => {
+        // Functions returning an owned value
+        impl<F, R, $($arg, $marker,)*> IntoFunction<(fn($($arg),*) -> R, $($marker,)*)> for F
+        where
+            R: Reflect + TypePath,
+            $($arg: FromArg<$marker>,)*
+            F: Fn($($arg),*) -> R + Send + Sync + 'static,
+            F: for<'a> Fn($(<$arg as FromArg<$marker>>::Item<'a>),*) -> R,
+        {
+            fn into_function(self) -> DynamicFunction {
+                let info = FunctionInfo::new(
+                    vec![$(<$arg as FromArg<$marker>>::arg_info($index)),*],
+                    ReturnInfo::new::<R>(Ownership::Owned),
+                );
+
+                DynamicFunction::new(
+                    move |args| {
+                        #[allow(unused_mut, unused_variables)]
+                        let mut args = args.into_iter();
+                        let value = (self)($(
+                            <$arg as FromArg<$marker>>::from_arg(args.next().unwrap(), $index)?
+                        ),*);
+                        Ok(Return::Owned(Box::new(value)))
+                    },
+                    with_default_name::<F>(info),
+                )
+            }
+        }
+
+        // Methods returning a reference borrowed from their receiver
+        impl<F, Receiver, R, $($arg, $marker,)*> IntoFunction<(fn(&Receiver, $($arg),*) -> &R, $($marker,)*)> for F
+        where
+            Receiver: Reflect + TypePath,
+            R: Reflect + TypePath,
+            $($arg: FromArg<$marker>,)*
+            F: for<'a> Fn(&'a Receiver, $($arg),*) -> &'a R + Send + Sync + 'static,
+            F: for<'a> Fn(&'a Receiver, $(<$arg as FromArg<$marker>>::Item<'a>),*) -> &'a R,
+        {
+            fn into_function(self) -> DynamicFunction {
+                let info = FunctionInfo::new(
+                    vec![
+                        ArgInfo::new::<Receiver>(0, Ownership::Ref),
+                        $(<$arg as FromArg<$marker>>::arg_info($index + 1),)*
+                    ],
+                    ReturnInfo::new::<R>(Ownership::Ref),
+                );
+
+                DynamicFunction::new(
+                    move |args| {
+                        let mut args = args.into_iter();
+                        let receiver =
+                            <&'static Receiver as FromArg<RefArg>>::from_arg(args.next().unwrap(), 0)?;
+                        let value = (self)(receiver, $(
+                            <$arg as FromArg<$marker>>::from_arg(args.next().unwrap(), $index + 1)?
+                        ),*);
+                        Ok(Return::Ref(value))
+                    },
+                    with_default_name::<F>(info),
+                )
+            }
+        }
+
+        // Methods returning a mutable reference borrowed from their receiver
+        impl<F, Receiver, R, $($arg, $marker,)*> IntoFunction<(fn(&mut Receiver, $($arg),*) -> &mut R, $($marker,)*)> for F
+        where
+            Receiver: Reflect + TypePath,
+            R: Reflect + TypePath,
+            $($arg: FromArg<$marker>,)*
+            F: for<'a> Fn(&'a mut Receiver, $($arg),*) -> &'a mut R + Send + Sync + 'static,
+            F: for<'a> Fn(&'a mut Receiver, $(<$arg as FromArg<$marker>>::Item<'a>),*) -> &'a mut R,
+        {
+            fn into_function(self) -> DynamicFunction {
+                let info = FunctionInfo::new(
+                    vec![
+                        ArgInfo::new::<Receiver>(0, Ownership::Mut),
+                        $(<$arg as FromArg<$marker>>::arg_info($index + 1),)*
+                    ],
+                    ReturnInfo::new::<R>(Ownership::Mut),
+                );
+
+                DynamicFunction::new(
+                    move |args| {
+                        let mut args = args.into_iter();
+                        let receiver =
+                            <&'static mut Receiver as FromArg<MutArg>>::from_arg(args.next().unwrap(), 0)?;
+                        let value = (self)(receiver, $(
+                            <$arg as FromArg<$marker>>::from_arg(args.next().unwrap(), $index + 1)?
+                        ),*);
+                        Ok(Return::Mut(value))
+                    },
+                    with_default_name::<F>(info),
+                )
+            }
+        }
+    };
+}
+
+impl_into_function!();
+impl_into_function!((A0, M0, 0));
+impl_into_function!((A0, M0, 0), (A1, M1, 1));
+impl_into_function!((A0, M0, 0), (A1, M1, 1), (A2, M2, 2));
+impl_into_function!((A0, M0, 0), (A1, M1, 1), (A2, M2, 2), (A3, M3, 3));
+impl_into_function!(
+    (A0, M0, 0),
+    (A1, M1, 1),
+    (A2, M2, 2),
+    (A3, M3, 3),
+    (A4, M4, 4)
+);
+impl_into_function!(
+    (A0, M0, 0),
+    (A1, M1, 1),
+    (A2, M2, 2),
+    (A3, M3, 3),
+    (A4, M4, 4),
+    (A5, M5, 5)
+);
+impl_into_function!(
+    (A0, M0, 0),
+    (A1, M1, 1),
+    (A2, M2, 2),
+    (A3, M3, 3),
+    (A4, M4, 4),
+    (A5, M5, 5),
+    (A6, M6, 6)
+);
+impl_into_function!(
+    (A0, M0, 0),
+    (A1, M1, 1),
+    (A2, M2, 2),
+    (A3, M3, 3),
+    (A4, M4, 4),
+    (A5, M5, 5),
+    (A6, M6, 6),
+    (A7, M7, 7)
+);
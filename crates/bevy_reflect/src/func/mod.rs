@@ -0,0 +1,250 @@
+//! Reflection of functions and methods.
+//!
+//! A [`DynamicFunction`] wraps a Rust function or closure so that it can be called with
+//! reflected arguments, passed in an [`ArgList`], and returns a reflected [`Return`] value.
+//! Its signature is described by its [`FunctionInfo`], which allows a debug console or a
+//! scripting language to discover and invoke engine functions at runtime.
+//!
+//! Functions are converted with [`IntoFunction`], and may be registered as methods of a type
+//! in the [`TypeRegistry`] with [`TypeRegistry::register_method`].
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy_reflect::func::{ArgList, FunctionError, IntoFunction, Ownership};
+//! # use bevy_reflect::{Reflect, TypeRegistry};
+//! #[derive(Reflect)]
+//! struct Player {
+//!     health: u32,
+//! }
+//!
+//! impl Player {
+//!     fn heal(&mut self, amount: u32) {
+//!         self.health += amount;
+//!     }
+//! }
+//!
+//! let mut registry = TypeRegistry::default();
+//! registry.register::<Player>();
+//! registry.register_method::<Player, _, _>("heal", Player::heal);
+//!
+//! let heal = registry
+//!     .get_with_short_type_path("Player")
+//!     .and_then(|registration| registration.method("heal"))
+//!     .unwrap();
+//! assert_eq!(heal.info().args()[0].ownership(), Ownership::Mut);
+//!
+//! let mut player = Player { health: 10 };
+//! let args = ArgList::new().with_mut(&mut player).with_owned(5_u32);
+//! assert!(heal.call(args).unwrap().is_unit());
+//! assert_eq!(player.health, 15);
+//!
+//! let args = ArgList::new().with_ref(&player).with_owned(5_u32);
+//! assert_eq!(
+//!     heal.call(args).unwrap_err(),
+//!     FunctionError::InvalidOwnership {
+//!         index: 0,
+//!         expected: Ownership::Mut,
+//!         received: Ownership::Ref,
+//!     }
+//! );
+//! ```
+//!
+//! [`TypeRegistry`]: crate::TypeRegistry
+//! [`TypeRegistry::register_method`]: crate::TypeRegistry::register_method
+
+mod args;
+mod error;
+mod function;
+mod info;
+mod into_function;
+mod return_type;
+
+pub use args::*;
+pub use error::*;
+pub use function::*;
+pub use info::*;
+pub use into_function::*;
+pub use return_type::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{DynamicStruct, FromReflect, Reflect, TypeRegistry};
+    use std::any::TypeId;
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq, Clone)]
+    struct Foo {
+        value: i32,
+    }
+
+    impl Foo {
+        fn value(&self) -> &i32 {
+            &self.value
+        }
+
+        fn value_mut(&mut self) -> &mut i32 {
+            &mut self.value
+        }
+
+        fn add(&mut self, amount: i32) {
+            self.value += amount;
+        }
+    }
+
+    fn add(a: i32, b: &i32) -> i32 {
+        a + *b
+    }
+
+    #[test]
+    fn should_call_function() {
+        let func = add.into_function();
+        let info = func.info();
+        assert_eq!(Some("bevy_reflect::func::tests::add"), info.name());
+        assert_eq!(2, info.arg_count());
+        assert!(info.args()[0].is::<i32>());
+        assert_eq!("i32", info.args()[0].type_path());
+        assert_eq!(Ownership::Owned, info.args()[0].ownership());
+        assert!(info.args()[1].is::<i32>());
+        assert_eq!(Ownership::Ref, info.args()[1].ownership());
+        assert!(info.return_info().is::<i32>());
+        assert_eq!(Ownership::Owned, info.return_info().ownership());
+
+        let args = ArgList::new().with_owned(25_i32).with_ref(&75_i32);
+        let value = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(100, value.take::<i32>().unwrap());
+    }
+
+    #[test]
+    fn should_call_closure() {
+        let offset = 10;
+        let func = (move |value: &mut i32| *value += offset).into_function();
+        assert_eq!(None, func.name());
+        assert_eq!(Ownership::Mut, func.info().args()[0].ownership());
+
+        let mut value = 5_i32;
+        let result = func.call(ArgList::new().with_mut(&mut value)).unwrap();
+        assert!(result.is_unit());
+        assert_eq!(15, value);
+
+        let func = func.with_name("add_offset").with_arg_names(["value"]);
+        assert_eq!(Some("add_offset"), func.name());
+        assert_eq!(Some("value"), func.info().args()[0].name());
+    }
+
+    #[test]
+    fn should_call_methods() {
+        let mut foo = Foo { value: 1 };
+
+        let func = Foo::value.into_function();
+        assert_eq!(Ownership::Ref, func.info().args()[0].ownership());
+        assert!(func.info().args()[0].is::<Foo>());
+        assert_eq!(
+            "bevy_reflect::func::tests::Foo",
+            func.info().args()[0].type_path()
+        );
+        assert_eq!(Ownership::Ref, func.info().return_info().ownership());
+        let value = func.call(ArgList::new().with_ref(&foo)).unwrap();
+        assert_eq!(Some(&1), value.unwrap_ref().downcast_ref::<i32>());
+
+        let func = Foo::value_mut.into_function();
+        assert_eq!(Ownership::Mut, func.info().return_info().ownership());
+        let value = func.call(ArgList::new().with_mut(&mut foo)).unwrap();
+        *value.unwrap_mut().downcast_mut::<i32>().unwrap() = 5;
+        assert_eq!(5, foo.value);
+
+        let func = Foo::add.into_function();
+        let args = ArgList::new().with_mut(&mut foo).with_owned(3_i32);
+        assert!(func.call(args).unwrap().is_unit());
+        assert_eq!(8, foo.value);
+    }
+
+    #[test]
+    fn should_take_dynamic_owned_args() {
+        let func = (|foo: Foo| foo.value * 2).into_function();
+
+        let mut dynamic = DynamicStruct::default();
+        dynamic.insert("value", 21_i32);
+        let value = func.call(ArgList::new().with_owned(dynamic)).unwrap();
+        assert_eq!(Some(&42), value.value().downcast_ref::<i32>());
+    }
+
+    #[test]
+    fn should_error_on_invalid_args() {
+        let func = add.into_function();
+
+        let result = func.call(ArgList::new().with_owned(1_i32));
+        assert_eq!(
+            FunctionError::ArgCountMismatch {
+                expected: 2,
+                received: 1
+            },
+            result.unwrap_err()
+        );
+
+        let args = ArgList::new().with_owned(1_i32).with_owned(2_i32);
+        assert_eq!(
+            FunctionError::InvalidOwnership {
+                index: 1,
+                expected: Ownership::Ref,
+                received: Ownership::Owned,
+            },
+            func.call(args).unwrap_err()
+        );
+
+        let args = ArgList::new().with_owned(1_u32).with_ref(&2_i32);
+        assert_eq!(
+            FunctionError::UnexpectedType {
+                index: 0,
+                expected: "i32",
+                received: String::from("u32"),
+            },
+            func.call(args).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn should_register_methods() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Foo>();
+        registry.register_method::<Foo, _, _>("value", Foo::value);
+        registry.register_method::<Foo, _, _>("add", Foo::add);
+
+        let registration = registry.get(TypeId::of::<Foo>()).unwrap().clone();
+        let mut names = registration
+            .methods()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        assert_eq!(vec!["add", "value"], names);
+
+        let foo = Foo { value: 4 };
+        let value = registration
+            .method("value")
+            .unwrap()
+            .call(ArgList::new().with_ref(&foo))
+            .unwrap();
+        assert_eq!(Some(&4), value.value().downcast_ref::<i32>());
+        assert!(registration.method("missing").is_none());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "attempted to call `TypeRegistry::register_method` for type `bevy_reflect::func::tests::Foo` with method `value` without registering `bevy_reflect::func::tests::Foo` first"
+    )]
+    fn should_panic_on_unregistered_type() {
+        let mut registry = TypeRegistry::empty();
+        registry.register_method::<Foo, _, _>("value", Foo::value);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "attempted to register method `add` for type `bevy_reflect::func::tests::Foo` with a receiver of type `i32`"
+    )]
+    fn should_panic_on_mismatched_receiver() {
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Foo>();
+        registry.register_method::<Foo, _, _>("add", add);
+    }
+}
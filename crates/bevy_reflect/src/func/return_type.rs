@@ -0,0 +1,82 @@
+use crate::func::Ownership;
+use crate::Reflect;
+
+/// The value returned by a [`DynamicFunction`].
+///
+/// Functions returning `()` return [`Return::Owned`] containing `()`,
+/// which can be checked with [`is_unit`](Self::is_unit).
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug)]
+pub enum Return<'a> {
+    /// An owned value, returned by value.
+    Owned(Box<dyn Reflect>),
+    /// A value borrowed from the arguments, returned by reference.
+    Ref(&'a dyn Reflect),
+    /// A value mutably borrowed from the arguments, returned by mutable reference.
+    Mut(&'a mut dyn Reflect),
+}
+
+impl<'a> Return<'a> {
+    /// Returns the [`Ownership`] of the returned value.
+    pub fn ownership(&self) -> Ownership {
+        match self {
+            Self::Owned(_) => Ownership::Owned,
+            Self::Ref(_) => Ownership::Ref,
+            Self::Mut(_) => Ownership::Mut,
+        }
+    }
+
+    /// Returns `true` if the function returned `()`.
+    pub fn is_unit(&self) -> bool {
+        matches!(self, Self::Owned(value) if value.is::<()>())
+    }
+
+    /// Returns the returned value.
+    pub fn value(&self) -> &dyn Reflect {
+        match self {
+            Self::Owned(value) => value.as_ref(),
+            Self::Ref(value) => *value,
+            Self::Mut(value) => *value,
+        }
+    }
+
+    /// Returns the owned value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value wasn't returned by value.
+    pub fn unwrap_owned(self) -> Box<dyn Reflect> {
+        match self {
+            Self::Owned(value) => value,
+            _ => panic!("expected an owned value but found {}", self.ownership()),
+        }
+    }
+
+    /// Returns the reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value wasn't returned by reference.
+    pub fn unwrap_ref(self) -> &'a dyn Reflect {
+        match self {
+            Self::Ref(value) => value,
+            _ => panic!("expected a reference but found {}", self.ownership()),
+        }
+    }
+
+    /// Returns the mutable reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value wasn't returned by mutable reference.
+    pub fn unwrap_mut(self) -> &'a mut dyn Reflect {
+        match self {
+            Self::Mut(value) => value,
+            _ => panic!(
+                "expected a mutable reference but found {}",
+                self.ownership()
+            ),
+        }
+    }
+}
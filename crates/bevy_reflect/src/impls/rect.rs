@@ -5,6 +5,7 @@ use bevy_math::{Rect, Vec2};
 use bevy_reflect_derive::impl_reflect_struct;
 
 impl_reflect_struct!(
+    #[type_path = "bevy_math"]
     #[reflect(Debug, PartialEq, Serialize, Deserialize, Default)]
     struct Rect {
         min: Vec2,
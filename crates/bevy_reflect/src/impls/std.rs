@@ -4,12 +4,14 @@ use crate::{
     map_apply, map_partial_eq, Array, ArrayInfo, ArrayIter, DynamicEnum, DynamicMap, Enum,
     EnumInfo, FromReflect, FromType, GetTypeRegistration, List, ListInfo, Map, MapInfo, MapIter,
     Reflect, ReflectDeserialize, ReflectMut, ReflectRef, ReflectSerialize, TupleVariantInfo,
-    TypeInfo, TypeRegistration, Typed, UnitVariantInfo, UnnamedField, ValueInfo, VariantFieldIter,
-    VariantInfo, VariantType,
+    TypeInfo, TypePath, TypeRegistration, Typed, UnitVariantInfo, UnnamedField, ValueInfo,
+    VariantFieldIter, VariantInfo, VariantType,
 };
 
-use crate::utility::{reflect_hasher, GenericTypeInfoCell, NonGenericTypeInfoCell};
-use bevy_reflect_derive::{impl_from_reflect_value, impl_reflect_value};
+use crate::utility::{
+    reflect_hasher, GenericTypeInfoCell, GenericTypePathCell, NonGenericTypeInfoCell,
+};
+use bevy_reflect_derive::{impl_from_reflect_value, impl_reflect_value, impl_type_path};
 use bevy_utils::{Duration, Instant};
 use bevy_utils::{HashMap, HashSet};
 use std::{
@@ -84,7 +86,7 @@ impl_reflect_value!(isize(
 ));
 impl_reflect_value!(f32(Debug, PartialEq, Serialize, Deserialize, Default));
 impl_reflect_value!(f64(Debug, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(String(
+impl_reflect_value!(::alloc::string::String(
     Debug,
     Hash,
     PartialEq,
@@ -92,7 +94,7 @@ impl_reflect_value!(String(
     Deserialize,
     Default
 ));
-impl_reflect_value!(PathBuf(
+impl_reflect_value!(::std::path::PathBuf(
     Debug,
     Hash,
     PartialEq,
@@ -100,15 +102,15 @@ impl_reflect_value!(PathBuf(
     Deserialize,
     Default
 ));
-impl_reflect_value!(Result<T: Clone + Reflect + 'static, E: Clone + Reflect + 'static>());
-impl_reflect_value!(HashSet<T: Hash + Eq + Clone + Send + Sync + 'static>());
-impl_reflect_value!(Range<T: Clone + Send + Sync + 'static>());
-impl_reflect_value!(RangeInclusive<T: Clone + Send + Sync + 'static>());
-impl_reflect_value!(RangeFrom<T: Clone + Send + Sync + 'static>());
-impl_reflect_value!(RangeTo<T: Clone + Send + Sync + 'static>());
-impl_reflect_value!(RangeToInclusive<T: Clone + Send + Sync + 'static>());
-impl_reflect_value!(RangeFull());
-impl_reflect_value!(Duration(
+impl_reflect_value!(::core::result::Result<T: Clone + Reflect + 'static, E: Clone + Reflect + 'static>());
+impl_reflect_value!(::bevy_utils::HashSet<T: Hash + Eq + Clone + Send + Sync + 'static>());
+impl_reflect_value!(::core::ops::Range<T: Clone + Send + Sync + 'static>());
+impl_reflect_value!(::core::ops::RangeInclusive<T: Clone + Send + Sync + 'static>());
+impl_reflect_value!(::core::ops::RangeFrom<T: Clone + Send + Sync + 'static>());
+impl_reflect_value!(::core::ops::RangeTo<T: Clone + Send + Sync + 'static>());
+impl_reflect_value!(::core::ops::RangeToInclusive<T: Clone + Send + Sync + 'static>());
+impl_reflect_value!(::core::ops::RangeFull());
+impl_reflect_value!(::core::time::Duration(
     Debug,
     Hash,
     PartialEq,
@@ -116,26 +118,104 @@ impl_reflect_value!(Duration(
     Deserialize,
     Default
 ));
-impl_reflect_value!(Instant(Debug, Hash, PartialEq));
-impl_reflect_value!(NonZeroI128(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroU128(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroIsize(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroUsize(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroI64(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroU64(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroU32(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroI32(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroI16(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroU16(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroU8(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroI8(Debug, Hash, PartialEq, Serialize, Deserialize));
+impl_reflect_value!(::bevy_utils::Instant(Debug, Hash, PartialEq));
+impl_reflect_value!(::core::num::NonZeroI128(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(::core::num::NonZeroU128(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(::core::num::NonZeroIsize(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(::core::num::NonZeroUsize(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(::core::num::NonZeroI64(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(::core::num::NonZeroU64(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(::core::num::NonZeroU32(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(::core::num::NonZeroI32(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(::core::num::NonZeroI16(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(::core::num::NonZeroU16(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(::core::num::NonZeroU8(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(::core::num::NonZeroI8(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize
+));
 
 // `Serialize` and `Deserialize` only for platforms supported by serde:
 // https://github.com/serde-rs/serde/blob/3ffb86fc70efd3d329519e2dddfa306cc04f167c/serde/src/de/impls.rs#L1732
 #[cfg(any(unix, windows))]
-impl_reflect_value!(OsString(Debug, Hash, PartialEq, Serialize, Deserialize));
+impl_reflect_value!(::std::ffi::OsString(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize
+));
 #[cfg(not(any(unix, windows)))]
-impl_reflect_value!(OsString(Debug, Hash, PartialEq));
+impl_reflect_value!(::std::ffi::OsString(Debug, Hash, PartialEq));
 
 impl_from_reflect_value!(bool);
 impl_from_reflect_value!(char);
@@ -180,7 +260,7 @@ impl_from_reflect_value!(NonZeroI8);
 
 macro_rules! impl_reflect_for_veclike {
     ($ty:ty, $insert:expr, $remove:expr, $push:expr, $pop:expr, $sub:ty) => {
-        impl<T: FromReflect> List for $ty {
+        impl<T: FromReflect + TypePath> List for $ty {
             #[inline]
             fn get(&self, index: usize) -> Option<&dyn Reflect> {
                 <$sub>::get(self, index).map(|value| value as &dyn Reflect)
@@ -192,15 +272,25 @@ macro_rules! impl_reflect_for_veclike {
             }
 
             fn insert(&mut self, index: usize, value: Box<dyn Reflect>) {
-                let value = value.take::<T>().unwrap_or_else(|value| {
-                    T::from_reflect(&*value).unwrap_or_else(|| {
-                        panic!(
-                            "Attempted to insert invalid value of type {}.",
-                            value.type_name()
-                        )
-                    })
+                List::try_insert(self, index, value).unwrap_or_else(|value| {
+                    panic!(
+                        "Attempted to insert invalid value of type {}.",
+                        value.type_name()
+                    )
                 });
+            }
+
+            fn try_insert(
+                &mut self,
+                index: usize,
+                value: Box<dyn Reflect>,
+            ) -> Result<(), Box<dyn Reflect>> {
+                let value = match value.take::<T>() {
+                    Ok(value) => value,
+                    Err(value) => T::from_reflect(&*value).ok_or(value)?,
+                };
                 $insert(self, index, value);
+                Ok(())
             }
 
             fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
@@ -239,7 +329,7 @@ macro_rules! impl_reflect_for_veclike {
             }
         }
 
-        impl<T: FromReflect> Reflect for $ty {
+        impl<T: FromReflect + TypePath> Reflect for $ty {
             fn type_name(&self) -> &str {
                 std::any::type_name::<Self>()
             }
@@ -306,14 +396,14 @@ macro_rules! impl_reflect_for_veclike {
             }
         }
 
-        impl<T: FromReflect> Typed for $ty {
+        impl<T: FromReflect + TypePath> Typed for $ty {
             fn type_info() -> &'static TypeInfo {
                 static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
                 CELL.get_or_insert::<Self, _>(|| TypeInfo::List(ListInfo::new::<Self, T>()))
             }
         }
 
-        impl<T: FromReflect> GetTypeRegistration for $ty {
+        impl<T: FromReflect + TypePath> GetTypeRegistration for $ty {
             fn get_type_registration() -> TypeRegistration {
                 let mut registration = TypeRegistration::of::<$ty>();
                 registration.insert::<ReflectFromPtr>(FromType::<$ty>::from_type());
@@ -321,7 +411,7 @@ macro_rules! impl_reflect_for_veclike {
             }
         }
 
-        impl<T: FromReflect> FromReflect for $ty {
+        impl<T: FromReflect + TypePath> FromReflect for $ty {
             fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
                 if let ReflectRef::List(ref_list) = reflect.reflect_ref() {
                     let mut new_list = Self::with_capacity(ref_list.len());
@@ -346,8 +436,10 @@ impl_reflect_for_veclike!(
     VecDeque::pop_back,
     VecDeque::<T>
 );
+impl_type_path!(::alloc::vec::Vec<T>);
+impl_type_path!(::alloc::collections::VecDeque<T>);
 
-impl<K: FromReflect + Eq + Hash, V: FromReflect> Map for HashMap<K, V> {
+impl<K: FromReflect + TypePath + Eq + Hash, V: FromReflect + TypePath> Map for HashMap<K, V> {
     fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
         key.downcast_ref::<K>()
             .and_then(|key| HashMap::get(self, key))
@@ -418,6 +510,18 @@ impl<K: FromReflect + Eq + Hash, V: FromReflect> Map for HashMap<K, V> {
             .map(|old_value| Box::new(old_value) as Box<dyn Reflect>)
     }
 
+    fn try_insert_boxed(
+        &mut self,
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    ) -> Result<Option<Box<dyn Reflect>>, Box<dyn Reflect>> {
+        let key = K::take_from_reflect(key)?;
+        let value = V::take_from_reflect(value)?;
+        Ok(self
+            .insert(key, value)
+            .map(|old_value| Box::new(old_value) as Box<dyn Reflect>))
+    }
+
     fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
         let mut from_reflect = None;
         key.downcast_ref::<K>()
@@ -430,7 +534,7 @@ impl<K: FromReflect + Eq + Hash, V: FromReflect> Map for HashMap<K, V> {
     }
 }
 
-impl<K: FromReflect + Eq + Hash, V: FromReflect> Reflect for HashMap<K, V> {
+impl<K: FromReflect + TypePath + Eq + Hash, V: FromReflect + TypePath> Reflect for HashMap<K, V> {
     fn type_name(&self) -> &str {
         std::any::type_name::<Self>()
     }
@@ -494,17 +598,19 @@ impl<K: FromReflect + Eq + Hash, V: FromReflect> Reflect for HashMap<K, V> {
     }
 }
 
-impl<K: FromReflect + Eq + Hash, V: FromReflect> Typed for HashMap<K, V> {
+impl<K: FromReflect + TypePath + Eq + Hash, V: FromReflect + TypePath> Typed for HashMap<K, V> {
     fn type_info() -> &'static TypeInfo {
         static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
         CELL.get_or_insert::<Self, _>(|| TypeInfo::Map(MapInfo::new::<Self, K, V>()))
     }
 }
 
+impl_type_path!(::bevy_utils::HashMap<K, V>);
+
 impl<K, V> GetTypeRegistration for HashMap<K, V>
 where
-    K: FromReflect + Eq + Hash,
-    V: FromReflect,
+    K: FromReflect + Eq + Hash + TypePath,
+    V: FromReflect + TypePath,
 {
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<HashMap<K, V>>();
@@ -513,7 +619,9 @@ where
     }
 }
 
-impl<K: FromReflect + Eq + Hash, V: FromReflect> FromReflect for HashMap<K, V> {
+impl<K: FromReflect + TypePath + Eq + Hash, V: FromReflect + TypePath> FromReflect
+    for HashMap<K, V>
+{
     fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
         if let ReflectRef::Map(ref_map) = reflect.reflect_ref() {
             let mut new_map = Self::with_capacity(ref_map.len());
@@ -529,7 +637,7 @@ impl<K: FromReflect + Eq + Hash, V: FromReflect> FromReflect for HashMap<K, V> {
     }
 }
 
-impl<T: Reflect, const N: usize> Array for [T; N] {
+impl<T: Reflect + TypePath, const N: usize> Array for [T; N] {
     #[inline]
     fn get(&self, index: usize) -> Option<&dyn Reflect> {
         <[T]>::get(self, index).map(|value| value as &dyn Reflect)
@@ -558,7 +666,7 @@ impl<T: Reflect, const N: usize> Array for [T; N] {
     }
 }
 
-impl<T: Reflect, const N: usize> Reflect for [T; N] {
+impl<T: Reflect + TypePath, const N: usize> Reflect for [T; N] {
     #[inline]
     fn type_name(&self) -> &str {
         std::any::type_name::<Self>()
@@ -640,7 +748,7 @@ impl<T: Reflect, const N: usize> Reflect for [T; N] {
     }
 }
 
-impl<T: FromReflect, const N: usize> FromReflect for [T; N] {
+impl<T: FromReflect + TypePath, const N: usize> FromReflect for [T; N] {
     fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
         if let ReflectRef::Array(ref_array) = reflect.reflect_ref() {
             let mut temp_vec = Vec::with_capacity(ref_array.len());
@@ -654,13 +762,25 @@ impl<T: FromReflect, const N: usize> FromReflect for [T; N] {
     }
 }
 
-impl<T: Reflect, const N: usize> Typed for [T; N] {
+impl<T: Reflect + TypePath, const N: usize> Typed for [T; N] {
     fn type_info() -> &'static TypeInfo {
         static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
         CELL.get_or_insert::<Self, _>(|| TypeInfo::Array(ArrayInfo::new::<Self, T>(N)))
     }
 }
 
+impl<T: TypePath, const N: usize> TypePath for [T; N] {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("[{}; {N}]", T::type_path()))
+    }
+
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("[{}; {N}]", T::short_type_path()))
+    }
+}
+
 // TODO:
 // `FromType::from_type` requires `Deserialize<'de>` to be implemented for `T`.
 // Currently serde only supports `Deserialize<'de>` for arrays up to size 32.
@@ -669,7 +789,7 @@ impl<T: Reflect, const N: usize> Typed for [T; N] {
 macro_rules! impl_array_get_type_registration {
     ($($N:expr)+) => {
         $(
-            impl<T: Reflect > GetTypeRegistration for [T; $N] {
+            impl<T: Reflect + TypePath> GetTypeRegistration for [T; $N] {
                 fn get_type_registration() -> TypeRegistration {
                     TypeRegistration::of::<[T; $N]>()
                 }
@@ -685,13 +805,15 @@ impl_array_get_type_registration! {
     30 31 32
 }
 
-impl<T: FromReflect> GetTypeRegistration for Option<T> {
+impl_type_path!(::core::option::Option<T>);
+
+impl<T: FromReflect + TypePath> GetTypeRegistration for Option<T> {
     fn get_type_registration() -> TypeRegistration {
         TypeRegistration::of::<Option<T>>()
     }
 }
 
-impl<T: FromReflect> Enum for Option<T> {
+impl<T: FromReflect + TypePath> Enum for Option<T> {
     fn field(&self, _name: &str) -> Option<&dyn Reflect> {
         None
     }
@@ -762,7 +884,7 @@ impl<T: FromReflect> Enum for Option<T> {
     }
 }
 
-impl<T: FromReflect> Reflect for Option<T> {
+impl<T: FromReflect + TypePath> Reflect for Option<T> {
     #[inline]
     fn type_name(&self) -> &str {
         std::any::type_name::<Self>()
@@ -876,7 +998,7 @@ impl<T: FromReflect> Reflect for Option<T> {
     }
 }
 
-impl<T: FromReflect> FromReflect for Option<T> {
+impl<T: FromReflect + TypePath> FromReflect for Option<T> {
     fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
         if let ReflectRef::Enum(dyn_enum) = reflect.reflect_ref() {
             match dyn_enum.variant_name() {
@@ -914,7 +1036,7 @@ impl<T: FromReflect> FromReflect for Option<T> {
     }
 }
 
-impl<T: FromReflect> Typed for Option<T> {
+impl<T: FromReflect + TypePath> Typed for Option<T> {
     fn type_info() -> &'static TypeInfo {
         static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
         CELL.get_or_insert::<Self, _>(|| {
@@ -1016,6 +1138,28 @@ impl Typed for Cow<'static, str> {
     }
 }
 
+impl TypePath for Cow<'static, str> {
+    fn type_path() -> &'static str {
+        "alloc::borrow::Cow<str>"
+    }
+
+    fn short_type_path() -> &'static str {
+        "Cow<str>"
+    }
+
+    fn type_ident() -> Option<&'static str> {
+        Some("Cow")
+    }
+
+    fn crate_name() -> Option<&'static str> {
+        Some("alloc")
+    }
+
+    fn module_path() -> Option<&'static str> {
+        Some("alloc::borrow")
+    }
+}
+
 impl GetTypeRegistration for Cow<'static, str> {
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<Cow<'static, str>>();
@@ -1124,6 +1268,16 @@ impl Typed for &'static Path {
     }
 }
 
+impl TypePath for &'static Path {
+    fn type_path() -> &'static str {
+        "&std::path::Path"
+    }
+
+    fn short_type_path() -> &'static str {
+        "&Path"
+    }
+}
+
 impl GetTypeRegistration for &'static Path {
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<Self>();
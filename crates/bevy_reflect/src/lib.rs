@@ -307,8 +307,11 @@
 //! The general entry point are the "untyped" versions of these structs.
 //! These will automatically extract the type information and pass them into their respective "typed" version.
 //!
-//! The output of the `ReflectSerializer` will be a map, where the key is the [type name]
+//! The output of the `ReflectSerializer` will be a map, where the key is the [type path]
 //! and the value is the serialized data.
+//! Unlike the [type name], which may change across compiler versions, the type path is given by
+//! the [`TypePath`] trait and is stable. It's implemented when deriving `Reflect`,
+//! and may be customized with the `#[type_path = "..."]` and `#[type_name = "..."]` attributes.
 //! The `TypedReflectSerializer` will simply output the serialized data.
 //!
 //! The `UntypedReflectDeserializer` can be used to deserialize this map and return a `Box<dyn Reflect>`,
@@ -428,6 +431,7 @@
 //! [registry]: TypeRegistry
 //! [type information]: TypeInfo
 //! [type name]: Reflect::type_name
+//! [type path]: TypePath::type_path
 //! [type registry]: TypeRegistry
 //! [`bevy_math`]: https://docs.rs/bevy_math/latest/bevy_math/
 //! [`glam`]: https://docs.rs/glam/latest/glam/
@@ -437,6 +441,8 @@
 //! [derive `Reflect`]: derive@crate::Reflect
 
 mod array;
+mod attributes;
+mod diff;
 mod fields;
 mod from_reflect;
 mod list;
@@ -447,6 +453,7 @@ mod struct_trait;
 mod tuple;
 mod tuple_struct;
 mod type_info;
+mod type_path;
 mod type_registry;
 mod type_uuid;
 mod type_uuid_impl;
@@ -469,6 +476,7 @@ mod impls {
 }
 
 mod enums;
+pub mod func;
 pub mod serde;
 pub mod std_traits;
 pub mod utility;
@@ -478,11 +486,13 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
         reflect_trait, FromReflect, GetField, GetTupleStructField, Reflect, ReflectDeserialize,
-        ReflectSerialize, Struct, TupleStruct,
+        ReflectSerialize, Struct, TupleStruct, TypePath,
     };
 }
 
 pub use array::*;
+pub use attributes::*;
+pub use diff::*;
 pub use enums::*;
 pub use fields::*;
 pub use from_reflect::*;
@@ -495,6 +505,7 @@ pub use struct_trait::*;
 pub use tuple::*;
 pub use tuple_struct::*;
 pub use type_info::*;
+pub use type_path::*;
 pub use type_registry::*;
 pub use type_uuid::*;
 
@@ -1084,6 +1095,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reflect_type_path() {
+        #[derive(Reflect)]
+        struct Foo<T, const N: usize>([T; N]);
+
+        #[derive(Reflect)]
+        #[type_path = "my_crate::items"]
+        #[type_name = "Item"]
+        enum Bar {
+            A,
+        }
+
+        assert_eq!(Bar::type_path(), "my_crate::items::Item");
+        assert_eq!(Bar::short_type_path(), "Item");
+        assert_eq!(Bar::type_ident(), Some("Item"));
+        assert_eq!(Bar::crate_name(), Some("my_crate"));
+        assert_eq!(Bar::module_path(), Some("my_crate::items"));
+
+        type MyFoo = Foo<(Bar, Option<String>), 2>;
+        assert_eq!(
+            MyFoo::type_path(),
+            "bevy_reflect::tests::Foo<(my_crate::items::Item, core::option::Option<alloc::string::String>), 2>"
+        );
+        assert_eq!(MyFoo::short_type_path(), "Foo<(Item, Option<String>), 2>");
+        assert_eq!(MyFoo::crate_name(), Some("bevy_reflect"));
+        assert_eq!(MyFoo::module_path(), Some("bevy_reflect::tests"));
+
+        assert_eq!(<[u8; 4]>::type_path(), "[u8; 4]");
+        assert_eq!(<(u8,)>::type_path(), "(u8,)");
+        assert_eq!(u8::crate_name(), None);
+        assert_eq!(<Vec<f32>>::type_path(), "alloc::vec::Vec<f32>");
+    }
+
     #[test]
     fn reflect_type_info() {
         // TypeInfo
@@ -1311,6 +1355,134 @@ mod tests {
         assert!(info.is::<MyDynamic>());
     }
 
+    #[test]
+    fn reflect_custom_attributes() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Tooltip(String);
+
+        impl Tooltip {
+            fn new(text: &str) -> Self {
+                Self(text.to_string())
+            }
+        }
+
+        #[derive(Reflect, Debug)]
+        #[reflect(@Tooltip::new("A struct"), Debug)]
+        struct MyStruct {
+            #[reflect(@0.0..=1.0, @Tooltip::new("A field"))]
+            value: f64,
+            #[reflect(@5_u8)]
+            #[reflect(default, @7_u8)]
+            count: u8,
+            no_attributes: u8,
+        }
+
+        let info = MyStruct::type_info();
+        let TypeInfo::Struct(struct_info) = info else {
+            panic!("Expected `TypeInfo::Struct`");
+        };
+        assert_eq!(
+            Some(&Tooltip::new("A struct")),
+            struct_info.custom_attributes().get::<Tooltip>()
+        );
+        assert!(info.custom_attributes().unwrap().contains::<Tooltip>());
+
+        let attributes = struct_info.field("value").unwrap().custom_attributes();
+        assert_eq!(2, attributes.len());
+        assert_eq!(
+            Some(&(0.0..=1.0)),
+            attributes.get::<std::ops::RangeInclusive<f64>>()
+        );
+        assert_eq!(Some(&Tooltip::new("A field")), attributes.get::<Tooltip>());
+        assert!(attributes
+            .get_by_id(TypeId::of::<Tooltip>())
+            .unwrap()
+            .reflect_partial_eq(&Tooltip::new("A field"))
+            .unwrap());
+
+        let attributes = struct_info.field("count").unwrap().custom_attributes();
+        assert_eq!(1, attributes.len());
+        assert_eq!(Some(&7), attributes.get::<u8>());
+
+        let attributes = struct_info
+            .field("no_attributes")
+            .unwrap()
+            .custom_attributes();
+        assert!(attributes.is_empty());
+
+        #[derive(Reflect)]
+        #[reflect(@Tooltip::new("A tuple struct"))]
+        #[allow(dead_code)]
+        struct MyTupleStruct(#[reflect(ignore)] u8, #[reflect(@10_usize)] usize);
+
+        let TypeInfo::TupleStruct(info) = MyTupleStruct::type_info() else {
+            panic!("Expected `TypeInfo::TupleStruct`");
+        };
+        assert!(info.custom_attributes().contains::<Tooltip>());
+        assert_eq!(
+            Some(&10),
+            info.field_at(0).unwrap().custom_attributes().get::<usize>()
+        );
+
+        #[derive(Reflect)]
+        #[reflect(@Tooltip::new("An enum"))]
+        enum MyEnum {
+            #[reflect(@Tooltip::new("A unit variant"))]
+            Unit,
+            Tuple(#[reflect(@1_i32)] i32),
+            #[reflect(@Tooltip::new("A struct variant"))]
+            Struct {
+                #[reflect(@-1_i32)]
+                value: i32,
+            },
+        }
+
+        let info = MyEnum::type_info();
+        let TypeInfo::Enum(enum_info) = info else {
+            panic!("Expected `TypeInfo::Enum`");
+        };
+        assert_eq!(
+            Some(&Tooltip::new("An enum")),
+            info.custom_attributes().unwrap().get::<Tooltip>()
+        );
+        assert_eq!(
+            Some(&Tooltip::new("A unit variant")),
+            enum_info
+                .variant("Unit")
+                .unwrap()
+                .custom_attributes()
+                .get::<Tooltip>()
+        );
+
+        let VariantInfo::Tuple(variant) = enum_info.variant("Tuple").unwrap() else {
+            panic!("Expected `VariantInfo::Tuple`");
+        };
+        assert!(variant.custom_attributes().is_empty());
+        assert_eq!(
+            Some(&1),
+            variant
+                .field_at(0)
+                .unwrap()
+                .custom_attributes()
+                .get::<i32>()
+        );
+
+        let VariantInfo::Struct(variant) = enum_info.variant("Struct").unwrap() else {
+            panic!("Expected `VariantInfo::Struct`");
+        };
+        assert!(variant.custom_attributes().contains::<Tooltip>());
+        assert_eq!(
+            Some(&-1),
+            variant
+                .field("value")
+                .unwrap()
+                .custom_attributes()
+                .get::<i32>()
+        );
+
+        assert!(DynamicList::type_info().custom_attributes().is_none());
+    }
+
     #[cfg(feature = "documentation")]
     mod docstrings {
         use super::*;
@@ -1655,7 +1827,7 @@ bevy_reflect::tests::should_reflect_debug::Test {
             let output = to_string_pretty(&ser, config).unwrap();
             let expected = r#"
 {
-    "glam::f32::vec3::Vec3": (
+    "glam::Vec3": (
         x: 12.0,
         y: 3.0,
         z: -6.9,
@@ -1669,7 +1841,7 @@ bevy_reflect::tests::should_reflect_debug::Test {
         fn vec3_deserialization() {
             let data = r#"
 {
-    "glam::f32::vec3::Vec3": (
+    "glam::Vec3": (
         x: 12.0,
         y: 3.0,
         z: -6.9,
@@ -4,7 +4,8 @@ use std::hash::{Hash, Hasher};
 
 use crate::utility::{reflect_hasher, NonGenericTypeInfoCell};
 use crate::{
-    DynamicInfo, FromReflect, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, Typed,
+    DynamicInfo, FromReflect, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypePath,
+    Typed,
 };
 
 /// A trait used to power [list-like] operations via [reflection].
@@ -59,6 +60,23 @@ pub trait List: Reflect {
     /// Panics if `index > len`.
     fn insert(&mut self, index: usize, element: Box<dyn Reflect>);
 
+    /// Inserts an element at position `index` within the list, like [`List::insert`], but
+    /// returns it back if it can't be converted to the type of the elements of the list instead
+    /// of panicking.
+    ///
+    /// The default implementation calls [`List::insert`].
+    ///
+    /// # Panics
+    /// Panics if `index > len`.
+    fn try_insert(
+        &mut self,
+        index: usize,
+        element: Box<dyn Reflect>,
+    ) -> Result<(), Box<dyn Reflect>> {
+        self.insert(index, element);
+        Ok(())
+    }
+
     /// Removes and returns the element at position `index` within the list,
     /// shifting all elements before it towards the front of the list.
     ///
@@ -107,6 +125,7 @@ pub trait List: Reflect {
 #[derive(Clone, Debug)]
 pub struct ListInfo {
     type_name: &'static str,
+    type_path: &'static str,
     type_id: TypeId,
     item_type_name: &'static str,
     item_type_id: TypeId,
@@ -116,9 +135,10 @@ pub struct ListInfo {
 
 impl ListInfo {
     /// Create a new [`ListInfo`].
-    pub fn new<TList: List, TItem: FromReflect>() -> Self {
+    pub fn new<TList: List + TypePath, TItem: FromReflect>() -> Self {
         Self {
             type_name: std::any::type_name::<TList>(),
+            type_path: TList::type_path(),
             type_id: TypeId::of::<TList>(),
             item_type_name: std::any::type_name::<TItem>(),
             item_type_id: TypeId::of::<TItem>(),
@@ -140,6 +160,13 @@ impl ListInfo {
         self.type_name
     }
 
+    /// The [type path] of the list.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
     /// The [`TypeId`] of the list.
     pub fn type_id(&self) -> TypeId {
         self.type_id
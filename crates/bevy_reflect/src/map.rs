@@ -5,7 +5,9 @@ use std::hash::Hash;
 use bevy_utils::{Entry, HashMap};
 
 use crate::utility::NonGenericTypeInfoCell;
-use crate::{DynamicInfo, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, Typed};
+use crate::{
+    DynamicInfo, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypePath, Typed,
+};
 
 /// A trait used to power [map-like] operations via [reflection].
 ///
@@ -80,6 +82,19 @@ pub trait Map: Reflect {
         value: Box<dyn Reflect>,
     ) -> Option<Box<dyn Reflect>>;
 
+    /// Inserts a key-value pair into the map, like [`Map::insert_boxed`], but returns back the key
+    /// or value that can't be converted to the type of the keys or values of the map instead of
+    /// panicking.
+    ///
+    /// The default implementation calls [`Map::insert_boxed`].
+    fn try_insert_boxed(
+        &mut self,
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    ) -> Result<Option<Box<dyn Reflect>>, Box<dyn Reflect>> {
+        Ok(self.insert_boxed(key, value))
+    }
+
     /// Removes an entry from the map.
     ///
     /// If the map did not have this key present, `None` is returned.
@@ -91,6 +106,7 @@ pub trait Map: Reflect {
 #[derive(Clone, Debug)]
 pub struct MapInfo {
     type_name: &'static str,
+    type_path: &'static str,
     type_id: TypeId,
     key_type_name: &'static str,
     key_type_id: TypeId,
@@ -102,9 +118,10 @@ pub struct MapInfo {
 
 impl MapInfo {
     /// Create a new [`MapInfo`].
-    pub fn new<TMap: Map, TKey: Hash + Reflect, TValue: Reflect>() -> Self {
+    pub fn new<TMap: Map + TypePath, TKey: Hash + Reflect, TValue: Reflect>() -> Self {
         Self {
             type_name: std::any::type_name::<TMap>(),
+            type_path: TMap::type_path(),
             type_id: TypeId::of::<TMap>(),
             key_type_name: std::any::type_name::<TKey>(),
             key_type_id: TypeId::of::<TKey>(),
@@ -128,6 +145,13 @@ impl MapInfo {
         self.type_name
     }
 
+    /// The [type path] of the map.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
     /// The [`TypeId`] of the map.
     pub fn type_id(&self) -> TypeId {
         self.type_id
@@ -223,7 +223,7 @@ impl<'de> Visitor<'de> for U32Visitor {
 ///
 /// Because the type isn't known ahead of time, the serialized data must take the form of
 /// a map containing the following entries (in order):
-/// 1. `type`: The _full_ [type name]
+/// 1. `type`: The _full_ [type path]
 /// 2. `value`: The serialized value of the reflected type
 ///
 /// If the type is already known and the [`TypeInfo`] for it can be retrieved,
@@ -233,7 +233,7 @@ impl<'de> Visitor<'de> for U32Visitor {
 /// [`DynamicStruct`]: crate::DynamicStruct
 /// [`DynamicList`]: crate::DynamicList
 /// [`FromReflect`]: crate::FromReflect
-/// [type name]: std::any::type_name
+/// [type path]: crate::TypePath
 pub struct UntypedReflectDeserializer<'a> {
     registry: &'a TypeRegistry,
 }
@@ -260,11 +260,15 @@ impl<'a, 'de> DeserializeSeed<'de> for UntypedReflectDeserializer<'a> {
 /// A deserializer for type registrations.
 ///
 /// This will return a [`&TypeRegistration`] corresponding to the given type.
-/// This deserializer expects a string containing the _full_ [type name] of the
+/// This deserializer expects a string containing the _full_ [type path] of the
 /// type to find the `TypeRegistration` of.
 ///
+/// Data serialized before type paths were used contains [type names] instead,
+/// which are looked up if no type has the given type path.
+///
 /// [`&TypeRegistration`]: crate::TypeRegistration
-/// [type name]: std::any::type_name
+/// [type path]: crate::TypePath
+/// [type names]: std::any::type_name
 pub struct TypeRegistrationDeserializer<'a> {
     registry: &'a TypeRegistry,
 }
@@ -291,13 +295,16 @@ impl<'a, 'de> DeserializeSeed<'de> for TypeRegistrationDeserializer<'a> {
                 formatter.write_str("string containing `type` entry for the reflected value")
             }
 
-            fn visit_str<E>(self, type_name: &str) -> Result<Self::Value, E>
+            fn visit_str<E>(self, type_path: &str) -> Result<Self::Value, E>
             where
                 E: Error,
             {
-                self.0.get_with_name(type_name).ok_or_else(|| {
-                    Error::custom(format_args!("No registration found for `{type_name}`"))
-                })
+                self.0
+                    .get_with_type_path(type_path)
+                    .or_else(|| self.0.get_with_name(type_path))
+                    .ok_or_else(|| {
+                        Error::custom(format_args!("No registration found for `{type_path}`"))
+                    })
             }
         }
 
@@ -1356,7 +1363,7 @@ mod tests {
 
         // === Normal === //
         let input = r#"{
-            "bevy_reflect::serde::de::tests::should_deserialize_option::OptionTest": (
+            "bevy_reflect::serde::de::tests::OptionTest": (
                 none: None,
                 simple: Some("Hello world!"),
                 complex: Some((
@@ -1378,7 +1385,7 @@ mod tests {
         let input = r#"
         #![enable(implicit_some)]
         {
-            "bevy_reflect::serde::de::tests::should_deserialize_option::OptionTest": (
+            "bevy_reflect::serde::de::tests::OptionTest": (
                 none: None,
                 simple: "Hello world!",
                 complex: (
@@ -1415,7 +1422,7 @@ mod tests {
 
         // === Unit Variant === //
         let input = r#"{
-    "bevy_reflect::serde::de::tests::enum_should_deserialize::MyEnum": Unit,
+    "bevy_reflect::serde::de::tests::MyEnum": Unit,
 }"#;
         let reflect_deserializer = UntypedReflectDeserializer::new(&registry);
         let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
@@ -1426,7 +1433,7 @@ mod tests {
 
         // === NewType Variant === //
         let input = r#"{
-    "bevy_reflect::serde::de::tests::enum_should_deserialize::MyEnum": NewType(123),
+    "bevy_reflect::serde::de::tests::MyEnum": NewType(123),
 }"#;
         let reflect_deserializer = UntypedReflectDeserializer::new(&registry);
         let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
@@ -1437,7 +1444,7 @@ mod tests {
 
         // === Tuple Variant === //
         let input = r#"{
-    "bevy_reflect::serde::de::tests::enum_should_deserialize::MyEnum": Tuple(1.23, 3.21),
+    "bevy_reflect::serde::de::tests::MyEnum": Tuple(1.23, 3.21),
 }"#;
         let reflect_deserializer = UntypedReflectDeserializer::new(&registry);
         let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
@@ -1448,7 +1455,7 @@ mod tests {
 
         // === Struct Variant === //
         let input = r#"{
-    "bevy_reflect::serde::de::tests::enum_should_deserialize::MyEnum": Struct(
+    "bevy_reflect::serde::de::tests::MyEnum": Struct(
         value: "I <3 Enums",
     ),
 }"#;
@@ -1462,6 +1469,26 @@ mod tests {
         assert!(expected.reflect_partial_eq(output.as_ref()).unwrap());
     }
 
+    #[test]
+    fn should_deserialize_with_type_name() {
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Foo(usize);
+
+        let mut registry = get_registry();
+        registry.register::<Foo>();
+
+        // Data serialized before type paths were used is keyed by the type name
+        let input = r#"{
+    "bevy_reflect::serde::de::tests::should_deserialize_with_type_name::Foo": (123),
+}"#;
+        let reflect_deserializer = UntypedReflectDeserializer::new(&registry);
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let output = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+
+        let output = <Foo as FromReflect>::from_reflect(output.as_ref()).unwrap();
+        assert_eq!(Foo(123), output);
+    }
+
     #[test]
     fn should_deserialize_non_self_describing_binary() {
         let mut map = HashMap::new();
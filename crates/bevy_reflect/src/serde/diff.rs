@@ -0,0 +1,666 @@
+use crate::serde::{ReflectSerializer, UntypedReflectDeserializer};
+use crate::{Diff, ListChange, MapChange, TypeRegistry};
+use serde::de::{DeserializeSeed, EnumAccess, Error, SeqAccess, VariantAccess, Visitor};
+use serde::ser::{SerializeSeq, SerializeTupleVariant};
+use serde::{Serialize, Serializer};
+use std::fmt::{self, Formatter};
+use std::marker::PhantomData;
+
+const DIFF: &str = "Diff";
+const DIFF_VARIANTS: &[&str] = &[
+    "Unchanged",
+    "Replaced",
+    "Struct",
+    "TupleStruct",
+    "Tuple",
+    "Array",
+    "List",
+    "Map",
+    "StructVariant",
+    "TupleVariant",
+];
+
+const LIST_CHANGE: &str = "ListChange";
+const MAP_CHANGE: &str = "MapChange";
+const CHANGE_VARIANTS: &[&str] = &["Insert", "Remove", "Modify"];
+
+/// A serializer for [`Diff`]s.
+///
+/// The values held by the diff are serialized with a [`ReflectSerializer`], so their types need
+/// to be registered.
+pub struct DiffSerializer<'a> {
+    pub diff: &'a Diff,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> DiffSerializer<'a> {
+    pub fn new(diff: &'a Diff, registry: &'a TypeRegistry) -> Self {
+        DiffSerializer { diff, registry }
+    }
+}
+
+impl<'a> Serialize for DiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let registry = self.registry;
+        match self.diff {
+            Diff::Unchanged => serializer.serialize_unit_variant(DIFF, 0, DIFF_VARIANTS[0]),
+            Diff::Replaced(value) => serializer.serialize_newtype_variant(
+                DIFF,
+                1,
+                DIFF_VARIANTS[1],
+                &ReflectSerializer::new(value.as_ref(), registry),
+            ),
+            Diff::Struct(fields) => serializer.serialize_newtype_variant(
+                DIFF,
+                2,
+                DIFF_VARIANTS[2],
+                &FieldsSerializer { fields, registry },
+            ),
+            Diff::TupleStruct(fields) => serializer.serialize_newtype_variant(
+                DIFF,
+                3,
+                DIFF_VARIANTS[3],
+                &FieldsSerializer { fields, registry },
+            ),
+            Diff::Tuple(fields) => serializer.serialize_newtype_variant(
+                DIFF,
+                4,
+                DIFF_VARIANTS[4],
+                &FieldsSerializer { fields, registry },
+            ),
+            Diff::Array(elements) => serializer.serialize_newtype_variant(
+                DIFF,
+                5,
+                DIFF_VARIANTS[5],
+                &FieldsSerializer {
+                    fields: elements,
+                    registry,
+                },
+            ),
+            Diff::List(changes) => serializer.serialize_newtype_variant(
+                DIFF,
+                6,
+                DIFF_VARIANTS[6],
+                &ChangesSerializer { changes, registry },
+            ),
+            Diff::Map(changes) => serializer.serialize_newtype_variant(
+                DIFF,
+                7,
+                DIFF_VARIANTS[7],
+                &ChangesSerializer { changes, registry },
+            ),
+            Diff::StructVariant { variant, fields } => {
+                let mut state = serializer.serialize_tuple_variant(DIFF, 8, DIFF_VARIANTS[8], 2)?;
+                state.serialize_field(variant)?;
+                state.serialize_field(&FieldsSerializer { fields, registry })?;
+                state.end()
+            }
+            Diff::TupleVariant { variant, fields } => {
+                let mut state = serializer.serialize_tuple_variant(DIFF, 9, DIFF_VARIANTS[9], 2)?;
+                state.serialize_field(variant)?;
+                state.serialize_field(&FieldsSerializer { fields, registry })?;
+                state.end()
+            }
+        }
+    }
+}
+
+/// Serializes the changed fields of a diff as a sequence of `(field, diff)` pairs.
+struct FieldsSerializer<'a, K> {
+    fields: &'a [(K, Diff)],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, K: Serialize> Serialize for FieldsSerializer<'a, K> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.fields.len()))?;
+        for (field, diff) in self.fields {
+            state.serialize_element(&(field, DiffSerializer::new(diff, self.registry)))?;
+        }
+        state.end()
+    }
+}
+
+/// Serializes the changes of a list or map diff as a sequence.
+struct ChangesSerializer<'a, C> {
+    changes: &'a [C],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ChangesSerializer<'a, ListChange> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.changes.len()))?;
+        for change in self.changes {
+            state.serialize_element(&ListChangeSerializer {
+                change,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+impl<'a> Serialize for ChangesSerializer<'a, MapChange> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.changes.len()))?;
+        for change in self.changes {
+            state.serialize_element(&MapChangeSerializer {
+                change,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+struct ListChangeSerializer<'a> {
+    change: &'a ListChange,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ListChangeSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.change {
+            ListChange::Insert(index, element) => {
+                let mut state =
+                    serializer.serialize_tuple_variant(LIST_CHANGE, 0, CHANGE_VARIANTS[0], 2)?;
+                state.serialize_field(index)?;
+                state.serialize_field(&ReflectSerializer::new(element.as_ref(), self.registry))?;
+                state.end()
+            }
+            ListChange::Remove(index) => {
+                serializer.serialize_newtype_variant(LIST_CHANGE, 1, CHANGE_VARIANTS[1], index)
+            }
+            ListChange::Modify(index, diff) => {
+                let mut state =
+                    serializer.serialize_tuple_variant(LIST_CHANGE, 2, CHANGE_VARIANTS[2], 2)?;
+                state.serialize_field(index)?;
+                state.serialize_field(&DiffSerializer::new(diff, self.registry))?;
+                state.end()
+            }
+        }
+    }
+}
+
+struct MapChangeSerializer<'a> {
+    change: &'a MapChange,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for MapChangeSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.change {
+            MapChange::Insert(key, value) => {
+                let mut state =
+                    serializer.serialize_tuple_variant(MAP_CHANGE, 0, CHANGE_VARIANTS[0], 2)?;
+                state.serialize_field(&ReflectSerializer::new(key.as_ref(), self.registry))?;
+                state.serialize_field(&ReflectSerializer::new(value.as_ref(), self.registry))?;
+                state.end()
+            }
+            MapChange::Remove(key) => serializer.serialize_newtype_variant(
+                MAP_CHANGE,
+                1,
+                CHANGE_VARIANTS[1],
+                &ReflectSerializer::new(key.as_ref(), self.registry),
+            ),
+            MapChange::Modify(key, diff) => {
+                let mut state =
+                    serializer.serialize_tuple_variant(MAP_CHANGE, 2, CHANGE_VARIANTS[2], 2)?;
+                state.serialize_field(&ReflectSerializer::new(key.as_ref(), self.registry))?;
+                state.serialize_field(&DiffSerializer::new(diff, self.registry))?;
+                state.end()
+            }
+        }
+    }
+}
+
+/// A deserializer for [`Diff`]s written by a [`DiffSerializer`].
+///
+/// Like with an [`UntypedReflectDeserializer`], the values held by the diff are deserialized as
+/// dynamic values for non-value types, which can still be applied to the concrete types they
+/// represent.
+#[derive(Clone, Copy)]
+pub struct DiffDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> DiffDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for DiffDeserializer<'a> {
+    type Value = Diff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(DIFF, DIFF_VARIANTS, DiffVisitor(self))
+    }
+}
+
+struct DiffVisitor<'a>(DiffDeserializer<'a>);
+
+impl<'a, 'de> Visitor<'de> for DiffVisitor<'a> {
+    type Value = Diff;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflect diff")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let diff = self.0;
+        let registry = diff.registry;
+        let named_fields = SeqDeserializer(PairDeserializer(PhantomData::<String>, diff));
+        let indexed_fields = SeqDeserializer(PairDeserializer(PhantomData::<usize>, diff));
+
+        let (index, variant) = data.variant_seed(VariantIndex(DIFF_VARIANTS))?;
+        Ok(match index {
+            0 => {
+                variant.unit_variant()?;
+                Diff::Unchanged
+            }
+            1 => Diff::Replaced(
+                variant.newtype_variant_seed(UntypedReflectDeserializer::new(registry))?,
+            ),
+            2 => Diff::Struct(variant.newtype_variant_seed(named_fields)?),
+            3 => Diff::TupleStruct(variant.newtype_variant_seed(indexed_fields)?),
+            4 => Diff::Tuple(variant.newtype_variant_seed(indexed_fields)?),
+            5 => Diff::Array(variant.newtype_variant_seed(indexed_fields)?),
+            6 => Diff::List(
+                variant.newtype_variant_seed(SeqDeserializer(ListChangeDeserializer(diff)))?,
+            ),
+            7 => Diff::Map(
+                variant.newtype_variant_seed(SeqDeserializer(MapChangeDeserializer(diff)))?,
+            ),
+            8 => {
+                let (variant, fields) =
+                    variant.tuple_variant(2, PairVisitor(PhantomData::<String>, named_fields))?;
+                Diff::StructVariant { variant, fields }
+            }
+            _ => {
+                let (variant, fields) =
+                    variant.tuple_variant(2, PairVisitor(PhantomData::<String>, indexed_fields))?;
+                Diff::TupleVariant { variant, fields }
+            }
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ListChangeDeserializer<'a>(DiffDeserializer<'a>);
+
+impl<'a, 'de> DeserializeSeed<'de> for ListChangeDeserializer<'a> {
+    type Value = ListChange;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(LIST_CHANGE, CHANGE_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ListChangeDeserializer<'a> {
+    type Value = ListChange;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflect list change")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let element = UntypedReflectDeserializer::new(self.0.registry);
+        let (index, variant) = data.variant_seed(VariantIndex(CHANGE_VARIANTS))?;
+        Ok(match index {
+            0 => {
+                let (index, element) =
+                    variant.tuple_variant(2, PairVisitor(PhantomData::<usize>, element))?;
+                ListChange::Insert(index, element)
+            }
+            1 => ListChange::Remove(variant.newtype_variant()?),
+            _ => {
+                let (index, diff) =
+                    variant.tuple_variant(2, PairVisitor(PhantomData::<usize>, self.0))?;
+                ListChange::Modify(index, diff)
+            }
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+struct MapChangeDeserializer<'a>(DiffDeserializer<'a>);
+
+impl<'a, 'de> DeserializeSeed<'de> for MapChangeDeserializer<'a> {
+    type Value = MapChange;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(MAP_CHANGE, CHANGE_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for MapChangeDeserializer<'a> {
+    type Value = MapChange;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflect map change")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let registry = self.0.registry;
+        let (index, variant) = data.variant_seed(VariantIndex(CHANGE_VARIANTS))?;
+        Ok(match index {
+            0 => {
+                let (key, value) = variant.tuple_variant(
+                    2,
+                    PairVisitor(
+                        UntypedReflectDeserializer::new(registry),
+                        UntypedReflectDeserializer::new(registry),
+                    ),
+                )?;
+                MapChange::Insert(key, value)
+            }
+            1 => MapChange::Remove(
+                variant.newtype_variant_seed(UntypedReflectDeserializer::new(registry))?,
+            ),
+            _ => {
+                let (key, diff) = variant.tuple_variant(
+                    2,
+                    PairVisitor(UntypedReflectDeserializer::new(registry), self.0),
+                )?;
+                MapChange::Modify(key, diff)
+            }
+        })
+    }
+}
+
+/// Deserializes the index of a variant from either its name or its index.
+struct VariantIndex(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for VariantIndex {
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for VariantIndex {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("variant identifier")
+    }
+
+    fn visit_u64<E>(self, index: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        usize::try_from(index)
+            .ok()
+            .filter(|index| *index < self.0.len())
+            .ok_or_else(|| {
+                Error::custom(format_args!(
+                    "no variant found at index `{index}`, expected an index below {}",
+                    self.0.len()
+                ))
+            })
+    }
+
+    fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.0
+            .iter()
+            .position(|variant| *variant == name)
+            .ok_or_else(|| Error::unknown_variant(name, self.0))
+    }
+}
+
+/// Deserializes a sequence of values with the same seed.
+#[derive(Clone, Copy)]
+struct SeqDeserializer<T>(T);
+
+impl<'de, T> DeserializeSeed<'de> for SeqDeserializer<T>
+where
+    T: DeserializeSeed<'de> + Copy,
+{
+    type Value = Vec<T::Value>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T> Visitor<'de> for SeqDeserializer<T>
+where
+    T: DeserializeSeed<'de> + Copy,
+{
+    type Value = Vec<T::Value>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("sequence")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(value) = seq.next_element_seed(self.0)? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+/// Deserializes a pair of values, such as a changed field and its diff.
+#[derive(Clone, Copy)]
+struct PairDeserializer<A, B>(A, B);
+
+impl<'de, A, B> DeserializeSeed<'de> for PairDeserializer<A, B>
+where
+    A: DeserializeSeed<'de>,
+    B: DeserializeSeed<'de>,
+{
+    type Value = (A::Value, B::Value);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, PairVisitor(self.0, self.1))
+    }
+}
+
+struct PairVisitor<A, B>(A, B);
+
+impl<'de, A, B> Visitor<'de> for PairVisitor<A, B>
+where
+    A: DeserializeSeed<'de>,
+    B: DeserializeSeed<'de>,
+{
+    type Value = (A::Value, B::Value);
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("pair")
+    }
+
+    fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        let first = seq
+            .next_element_seed(self.0)?
+            .ok_or_else(|| Error::invalid_length(0, &"pair"))?;
+        let second = seq
+            .next_element_seed(self.1)?
+            .ok_or_else(|| Error::invalid_length(1, &"pair"))?;
+        Ok((first, second))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiffDeserializer, DiffSerializer};
+    use crate as bevy_reflect;
+    use crate::{diff, FromReflect, Reflect, TypeRegistry};
+    use bevy_utils::HashMap;
+    use bincode::Options;
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, FromReflect, Clone, Debug, PartialEq)]
+    struct Item {
+        name: String,
+        count: u32,
+    }
+
+    #[derive(Reflect, FromReflect, Clone, Debug, PartialEq)]
+    enum Slot {
+        Empty,
+        Full { item: Item },
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Inventory {
+        items: Vec<Item>,
+        slots: [Slot; 2],
+        prices: HashMap<String, u32>,
+        gold: (u32, u32),
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Item>();
+        registry.register::<Slot>();
+        registry.register::<String>();
+        registry.register::<u32>();
+        registry
+    }
+
+    fn inventories() -> (Inventory, Inventory) {
+        let sword = Item {
+            name: String::from("sword"),
+            count: 1,
+        };
+        let a = Inventory {
+            items: vec![sword.clone()],
+            slots: [
+                Slot::Empty,
+                Slot::Full {
+                    item: sword.clone(),
+                },
+            ],
+            prices: HashMap::from_iter([(String::from("sword"), 10), (String::from("bow"), 8)]),
+            gold: (1, 2),
+        };
+        let mut b = a.clone();
+        b.items.insert(
+            0,
+            Item {
+                name: String::from("shield"),
+                count: 2,
+            },
+        );
+        b.items[1].count = 3;
+        b.slots = [
+            Slot::Full {
+                item: sword.clone(),
+            },
+            Slot::Empty,
+        ];
+        b.prices.remove("bow");
+        b.prices.insert(String::from("sword"), 12);
+        b.prices.insert(String::from("shield"), 5);
+        b.gold.1 = 4;
+        (a, b)
+    }
+
+    #[test]
+    fn should_roundtrip_diff_ron() {
+        let registry = get_registry();
+        let (a, b) = inventories();
+        let diff = diff(&a, &b);
+
+        let output = ron::to_string(&DiffSerializer::new(&diff, &registry)).unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let deserialized = DiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        let mut patched = a.clone();
+        deserialized.apply(&mut patched).unwrap();
+        assert_eq!(patched, b);
+    }
+
+    #[test]
+    fn should_roundtrip_diff_bincode() {
+        let registry = get_registry();
+        let (a, b) = inventories();
+        let diff = diff(&a, &b);
+
+        let bytes = bincode::serialize(&DiffSerializer::new(&diff, &registry)).unwrap();
+        let deserialized = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(DiffDeserializer::new(&registry), &bytes)
+            .unwrap();
+
+        let mut patched = a.clone();
+        deserialized.apply(&mut patched).unwrap();
+        assert_eq!(patched, b);
+    }
+
+    #[test]
+    fn should_serialize_changes_only() {
+        let registry = get_registry();
+        let (a, _) = inventories();
+        let b = Inventory {
+            gold: (5, 2),
+            ..a.clone()
+        };
+
+        let output = ron::to_string(&DiffSerializer::new(&diff(&a, &b), &registry)).unwrap();
+        assert_eq!(
+            output,
+            r#"Struct([("gold",Tuple([(0,Replaced({"u32":5}))]))])"#
+        );
+    }
+}
@@ -1,8 +1,10 @@
 mod de;
+mod diff;
 mod ser;
 mod type_data;
 
 pub use de::*;
+pub use diff::*;
 pub use ser::*;
 pub use type_data::*;
 
@@ -1,6 +1,7 @@
 use crate::utility::NonGenericTypeInfoCell;
 use crate::{
-    DynamicInfo, NamedField, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, Typed,
+    CustomAttributes, DynamicInfo, NamedField, Reflect, ReflectMut, ReflectOwned, ReflectRef,
+    TypeInfo, TypePath, Typed,
 };
 use bevy_utils::{Entry, HashMap};
 use std::fmt::{Debug, Formatter};
@@ -77,10 +78,12 @@ pub trait Struct: Reflect {
 pub struct StructInfo {
     name: &'static str,
     type_name: &'static str,
+    type_path: &'static str,
     type_id: TypeId,
     fields: Box<[NamedField]>,
     field_names: Box<[&'static str]>,
     field_indices: HashMap<&'static str, usize>,
+    custom_attributes: CustomAttributes,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -93,7 +96,7 @@ impl StructInfo {
     /// * `name`: The name of this struct (_without_ generics or lifetimes)
     /// * `fields`: The fields of this struct in the order they are defined
     ///
-    pub fn new<T: Reflect>(name: &'static str, fields: &[NamedField]) -> Self {
+    pub fn new<T: Reflect + TypePath>(name: &'static str, fields: &[NamedField]) -> Self {
         let field_indices = fields
             .iter()
             .enumerate()
@@ -105,10 +108,12 @@ impl StructInfo {
         Self {
             name,
             type_name: std::any::type_name::<T>(),
+            type_path: T::type_path(),
             type_id: TypeId::of::<T>(),
             fields: fields.to_vec().into_boxed_slice(),
             field_names,
             field_indices,
+            custom_attributes: CustomAttributes::default(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -120,6 +125,14 @@ impl StructInfo {
         Self { docs, ..self }
     }
 
+    /// Sets the custom attributes for this struct.
+    pub fn with_custom_attributes(self, custom_attributes: CustomAttributes) -> Self {
+        Self {
+            custom_attributes,
+            ..self
+        }
+    }
+
     /// A slice containing the names of all fields in order.
     pub fn field_names(&self) -> &[&'static str] {
         &self.field_names
@@ -168,6 +181,13 @@ impl StructInfo {
         self.type_name
     }
 
+    /// The [type path] of the struct.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
     /// The [`TypeId`] of the struct.
     pub fn type_id(&self) -> TypeId {
         self.type_id
@@ -178,6 +198,11 @@ impl StructInfo {
         TypeId::of::<T>() == self.type_id
     }
 
+    /// The custom attributes of this struct.
+    pub fn custom_attributes(&self) -> &CustomAttributes {
+        &self.custom_attributes
+    }
+
     /// The docstring of this struct, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
@@ -1,7 +1,7 @@
 use crate::utility::NonGenericTypeInfoCell;
 use crate::{
     DynamicInfo, FromReflect, GetTypeRegistration, Reflect, ReflectMut, ReflectOwned, ReflectRef,
-    TypeInfo, TypeRegistration, Typed, UnnamedField,
+    TypeInfo, TypePath, TypeRegistration, Typed, UnnamedField,
 };
 use std::any::{Any, TypeId};
 use std::fmt::{Debug, Formatter};
@@ -136,6 +136,7 @@ impl GetTupleField for dyn Tuple {
 #[derive(Clone, Debug)]
 pub struct TupleInfo {
     type_name: &'static str,
+    type_path: &'static str,
     type_id: TypeId,
     fields: Box<[UnnamedField]>,
     #[cfg(feature = "documentation")]
@@ -149,9 +150,10 @@ impl TupleInfo {
     ///
     /// * `fields`: The fields of this tuple in the order they are defined
     ///
-    pub fn new<T: Reflect>(fields: &[UnnamedField]) -> Self {
+    pub fn new<T: Reflect + TypePath>(fields: &[UnnamedField]) -> Self {
         Self {
             type_name: std::any::type_name::<T>(),
+            type_path: T::type_path(),
             type_id: TypeId::of::<T>(),
             fields: fields.to_vec().into_boxed_slice(),
             #[cfg(feature = "documentation")]
@@ -187,6 +189,13 @@ impl TupleInfo {
         self.type_name
     }
 
+    /// The [type path] of the tuple.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
     /// The [`TypeId`] of the tuple.
     pub fn type_id(&self) -> TypeId {
         self.type_id
@@ -454,9 +463,17 @@ pub fn tuple_debug(dyn_tuple: &dyn Tuple, f: &mut std::fmt::Formatter<'_>) -> st
     debug.finish()
 }
 
+/// Returns the type path of a tuple from the type paths of its fields, such as `(u8, f32)`.
+fn tuple_type_path(fields: &[&str]) -> String {
+    match fields {
+        [field] => format!("({field},)"),
+        fields => format!("({})", fields.join(", ")),
+    }
+}
+
 macro_rules! impl_reflect_tuple {
     {$($index:tt : $name:tt),*} => {
-        impl<$($name: Reflect),*> Tuple for ($($name,)*) {
+        impl<$($name: Reflect + TypePath),*> Tuple for ($($name,)*) {
             #[inline]
             fn field(&self, index: usize) -> Option<&dyn Reflect> {
                 match index {
@@ -508,7 +525,7 @@ macro_rules! impl_reflect_tuple {
             }
         }
 
-        impl<$($name: Reflect),*> Reflect for ($($name,)*) {
+        impl<$($name: Reflect + TypePath),*> Reflect for ($($name,)*) {
             fn type_name(&self) -> &str {
                 std::any::type_name::<Self>()
             }
@@ -571,7 +588,7 @@ macro_rules! impl_reflect_tuple {
             }
         }
 
-        impl <$($name: Reflect),*> Typed for ($($name,)*) {
+        impl <$($name: Reflect + TypePath),*> Typed for ($($name,)*) {
             fn type_info() -> &'static TypeInfo {
                 static CELL: $crate::utility::GenericTypeInfoCell = $crate::utility::GenericTypeInfoCell::new();
                 CELL.get_or_insert::<Self, _>(|| {
@@ -584,13 +601,25 @@ macro_rules! impl_reflect_tuple {
             }
         }
 
-        impl<$($name: Reflect + Typed),*> GetTypeRegistration for ($($name,)*) {
+        impl <$($name: TypePath),*> TypePath for ($($name,)*) {
+            fn type_path() -> &'static str {
+                static CELL: $crate::utility::GenericTypePathCell = $crate::utility::GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| tuple_type_path(&[$($name::type_path(),)*]))
+            }
+
+            fn short_type_path() -> &'static str {
+                static CELL: $crate::utility::GenericTypePathCell = $crate::utility::GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| tuple_type_path(&[$($name::short_type_path(),)*]))
+            }
+        }
+
+        impl<$($name: Reflect + Typed + TypePath),*> GetTypeRegistration for ($($name,)*) {
             fn get_type_registration() -> TypeRegistration {
                 TypeRegistration::of::<($($name,)*)>()
             }
         }
 
-        impl<$($name: FromReflect),*> FromReflect for ($($name,)*)
+        impl<$($name: FromReflect + TypePath),*> FromReflect for ($($name,)*)
         {
             fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
                 if let ReflectRef::Tuple(_ref_tuple) = reflect.reflect_ref() {
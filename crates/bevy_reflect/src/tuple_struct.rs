@@ -1,6 +1,7 @@
 use crate::utility::NonGenericTypeInfoCell;
 use crate::{
-    DynamicInfo, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, Typed, UnnamedField,
+    CustomAttributes, DynamicInfo, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo,
+    TypePath, Typed, UnnamedField,
 };
 use std::any::{Any, TypeId};
 use std::fmt::{Debug, Formatter};
@@ -56,8 +57,10 @@ pub trait TupleStruct: Reflect {
 pub struct TupleStructInfo {
     name: &'static str,
     type_name: &'static str,
+    type_path: &'static str,
     type_id: TypeId,
     fields: Box<[UnnamedField]>,
+    custom_attributes: CustomAttributes,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -70,12 +73,14 @@ impl TupleStructInfo {
     /// * `name`: The name of this struct (_without_ generics or lifetimes)
     /// * `fields`: The fields of this struct in the order they are defined
     ///
-    pub fn new<T: Reflect>(name: &'static str, fields: &[UnnamedField]) -> Self {
+    pub fn new<T: Reflect + TypePath>(name: &'static str, fields: &[UnnamedField]) -> Self {
         Self {
             name,
             type_name: std::any::type_name::<T>(),
+            type_path: T::type_path(),
             type_id: TypeId::of::<T>(),
             fields: fields.to_vec().into_boxed_slice(),
+            custom_attributes: CustomAttributes::default(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -87,6 +92,14 @@ impl TupleStructInfo {
         Self { docs, ..self }
     }
 
+    /// Sets the custom attributes for this struct.
+    pub fn with_custom_attributes(self, custom_attributes: CustomAttributes) -> Self {
+        Self {
+            custom_attributes,
+            ..self
+        }
+    }
+
     /// Get the field at the given index.
     pub fn field_at(&self, index: usize) -> Option<&UnnamedField> {
         self.fields.get(index)
@@ -118,6 +131,13 @@ impl TupleStructInfo {
         self.type_name
     }
 
+    /// The [type path] of the tuple struct.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
     /// The [`TypeId`] of the tuple struct.
     pub fn type_id(&self) -> TypeId {
         self.type_id
@@ -128,6 +148,11 @@ impl TupleStructInfo {
         TypeId::of::<T>() == self.type_id
     }
 
+    /// The custom attributes of this struct.
+    pub fn custom_attributes(&self) -> &CustomAttributes {
+        &self.custom_attributes
+    }
+
     /// The docstring of this struct, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
@@ -1,5 +1,6 @@
 use crate::{
-    ArrayInfo, EnumInfo, ListInfo, MapInfo, Reflect, StructInfo, TupleInfo, TupleStructInfo,
+    ArrayInfo, CustomAttributes, EnumInfo, ListInfo, MapInfo, Reflect, StructInfo, TupleInfo,
+    TupleStructInfo, TypePath,
 };
 use std::any::{Any, TypeId};
 
@@ -25,8 +26,9 @@ use std::any::{Any, TypeId};
 /// # use std::any::Any;
 /// # use bevy_reflect::{NamedField, Reflect, ReflectMut, ReflectOwned, ReflectRef, StructInfo, TypeInfo, ValueInfo};
 /// # use bevy_reflect::utility::NonGenericTypeInfoCell;
-/// use bevy_reflect::Typed;
+/// use bevy_reflect::{TypePath, Typed};
 ///
+/// #[derive(TypePath)]
 /// struct MyStruct {
 ///   foo: usize,
 ///   bar: (f32, f32)
@@ -144,11 +146,43 @@ impl TypeInfo {
         }
     }
 
+    /// The [type path] of the underlying type.
+    ///
+    /// Returns `None` for dynamic types, since the type they represent isn't known statically.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> Option<&'static str> {
+        match self {
+            Self::Struct(info) => Some(info.type_path()),
+            Self::TupleStruct(info) => Some(info.type_path()),
+            Self::Tuple(info) => Some(info.type_path()),
+            Self::List(info) => Some(info.type_path()),
+            Self::Array(info) => Some(info.type_path()),
+            Self::Map(info) => Some(info.type_path()),
+            Self::Enum(info) => Some(info.type_path()),
+            Self::Value(info) => Some(info.type_path()),
+            Self::Dynamic(_) => None,
+        }
+    }
+
     /// Check if the given type matches the underlying type.
     pub fn is<T: Any>(&self) -> bool {
         TypeId::of::<T>() == self.type_id()
     }
 
+    /// The custom attributes of the underlying type.
+    ///
+    /// Returns `None` for kinds of types that can't have custom attributes,
+    /// which are all kinds other than structs, tuple structs and enums.
+    pub fn custom_attributes(&self) -> Option<&CustomAttributes> {
+        match self {
+            Self::Struct(info) => Some(info.custom_attributes()),
+            Self::TupleStruct(info) => Some(info.custom_attributes()),
+            Self::Enum(info) => Some(info.custom_attributes()),
+            _ => None,
+        }
+    }
+
     /// The docstring of the underlying type, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&str> {
@@ -177,15 +211,17 @@ impl TypeInfo {
 #[derive(Debug, Clone)]
 pub struct ValueInfo {
     type_name: &'static str,
+    type_path: &'static str,
     type_id: TypeId,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
 
 impl ValueInfo {
-    pub fn new<T: Reflect + ?Sized>() -> Self {
+    pub fn new<T: Reflect + TypePath + ?Sized>() -> Self {
         Self {
             type_name: std::any::type_name::<T>(),
+            type_path: T::type_path(),
             type_id: TypeId::of::<T>(),
             #[cfg(feature = "documentation")]
             docs: None,
@@ -205,6 +241,13 @@ impl ValueInfo {
         self.type_name
     }
 
+    /// The [type path] of the value.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
     /// The [`TypeId`] of the value.
     pub fn type_id(&self) -> TypeId {
         self.type_id
@@ -0,0 +1,113 @@
+/// A static accessor to the path and name of a type.
+///
+/// Unlike [`std::any::type_name`], whose output isn't guaranteed to be stable across compiler
+/// versions, the paths provided by this trait are stable. They are used to identify types in the
+/// [`TypeRegistry`] and in serialized data.
+///
+/// This trait is automatically implemented by [`#[derive(Reflect)]`](derive@crate::Reflect),
+/// and may be derived on its own using [`#[derive(TypePath)]`](derive@crate::TypePath).
+/// Both derives use the module the type is defined in and the type's identifier by default,
+/// which can be overridden with the `#[type_path = "my_crate::foo"]` and
+/// `#[type_name = "Bar"]` attributes.
+///
+/// Foreign types can implement this trait using [`impl_type_path!`](crate::impl_type_path),
+/// or through [`impl_reflect_value!`](crate::impl_reflect_value).
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::TypePath;
+/// #[derive(TypePath)]
+/// #[type_path = "my_crate"]
+/// struct Foo<T>(T);
+///
+/// #[derive(TypePath)]
+/// #[type_path = "my_crate::items"]
+/// #[type_name = "Item"]
+/// struct Bar;
+///
+/// assert_eq!(Foo::<Bar>::type_path(), "my_crate::Foo<my_crate::items::Item>");
+/// assert_eq!(Foo::<Bar>::short_type_path(), "Foo<Item>");
+/// assert_eq!(Foo::<Bar>::type_ident(), Some("Foo"));
+/// assert_eq!(Bar::crate_name(), Some("my_crate"));
+/// assert_eq!(Bar::module_path(), Some("my_crate::items"));
+/// ```
+///
+/// [`TypeRegistry`]: crate::TypeRegistry
+pub trait TypePath: 'static {
+    /// Returns the fully qualified path of the type, such as
+    /// `core::option::Option<alloc::vec::Vec<u32>>`.
+    fn type_path() -> &'static str;
+
+    /// Returns the path of the type without its module path, such as `Option<Vec<u32>>`.
+    fn short_type_path() -> &'static str;
+
+    /// Returns the name of the type without its generic parameters, such as `Option`.
+    ///
+    /// Returns `None` for anonymous types, such as tuples and arrays.
+    fn type_ident() -> Option<&'static str> {
+        None
+    }
+
+    /// Returns the name of the crate the type is defined in, such as `core`.
+    ///
+    /// Returns `None` for primitive and anonymous types.
+    fn crate_name() -> Option<&'static str> {
+        None
+    }
+
+    /// Returns the path of the module the type is defined in, such as `core::option`.
+    ///
+    /// Returns `None` for primitive and anonymous types.
+    fn module_path() -> Option<&'static str> {
+        None
+    }
+}
+
+/// The [`TypePath`] of a type, stored at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypePathTable {
+    type_path: &'static str,
+    short_type_path: &'static str,
+    type_ident: Option<&'static str>,
+    crate_name: Option<&'static str>,
+    module_path: Option<&'static str>,
+}
+
+impl TypePathTable {
+    /// Creates the table of the type paths of `T`.
+    pub fn of<T: TypePath + ?Sized>() -> Self {
+        Self {
+            type_path: T::type_path(),
+            short_type_path: T::short_type_path(),
+            type_ident: T::type_ident(),
+            crate_name: T::crate_name(),
+            module_path: T::module_path(),
+        }
+    }
+
+    /// See [`TypePath::type_path`].
+    pub fn path(&self) -> &'static str {
+        self.type_path
+    }
+
+    /// See [`TypePath::short_type_path`].
+    pub fn short_path(&self) -> &'static str {
+        self.short_type_path
+    }
+
+    /// See [`TypePath::type_ident`].
+    pub fn ident(&self) -> Option<&'static str> {
+        self.type_ident
+    }
+
+    /// See [`TypePath::crate_name`].
+    pub fn crate_name(&self) -> Option<&'static str> {
+        self.crate_name
+    }
+
+    /// See [`TypePath::module_path`].
+    pub fn module_path(&self) -> Option<&'static str> {
+        self.module_path
+    }
+}
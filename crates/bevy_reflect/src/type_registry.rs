@@ -1,10 +1,11 @@
-use crate::{serde::Serializable, Reflect, TypeInfo, Typed};
+use crate::func::{DynamicFunction, IntoFunction};
+use crate::{serde::Serializable, Reflect, TypeInfo, TypePath, TypePathTable, Typed};
 use bevy_ptr::{Ptr, PtrMut};
 use bevy_utils::{HashMap, HashSet};
 use downcast_rs::{impl_downcast, Downcast};
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use serde::Deserialize;
-use std::{any::TypeId, fmt::Debug, sync::Arc};
+use std::{any::TypeId, borrow::Cow, fmt::Debug, sync::Arc};
 
 /// A registry of [reflected] types.
 ///
@@ -23,6 +24,9 @@ pub struct TypeRegistry {
     short_name_to_id: HashMap<String, TypeId>,
     full_name_to_id: HashMap<String, TypeId>,
     ambiguous_names: HashSet<String>,
+    type_path_to_id: HashMap<&'static str, TypeId>,
+    short_type_path_to_id: HashMap<&'static str, TypeId>,
+    ambiguous_short_type_paths: HashSet<&'static str>,
 }
 
 // TODO:  remove this wrapper once we migrate to Atelier Assets and the Scene AssetLoader doesn't
@@ -66,6 +70,9 @@ impl TypeRegistry {
             short_name_to_id: Default::default(),
             full_name_to_id: Default::default(),
             ambiguous_names: Default::default(),
+            type_path_to_id: Default::default(),
+            short_type_path_to_id: Default::default(),
+            ambiguous_short_type_paths: Default::default(),
         }
     }
 
@@ -103,10 +110,25 @@ impl TypeRegistry {
     }
 
     /// Registers the type described by `registration`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a different type with the same [type path] has already been registered,
+    /// since type paths are used to identify types in serialized data.
+    ///
+    /// [type path]: TypePath::type_path
     pub fn add_registration(&mut self, registration: TypeRegistration) {
         if self.registrations.contains_key(&registration.type_id()) {
             return;
         }
+        if let Some(existing) = self.type_path_to_id.get(registration.type_path()) {
+            panic!(
+                "attempted to register type `{}` with type path `{}`, which is already used by type `{}`",
+                registration.type_name(),
+                registration.type_path(),
+                self.registrations[existing].type_name(),
+            );
+        }
 
         let short_name = registration.short_name.to_string();
         if self.short_name_to_id.contains_key(&short_name)
@@ -121,6 +143,19 @@ impl TypeRegistry {
         }
         self.full_name_to_id
             .insert(registration.type_name().to_string(), registration.type_id());
+
+        let short_type_path = registration.short_type_path();
+        if self.short_type_path_to_id.contains_key(short_type_path)
+            || self.ambiguous_short_type_paths.contains(short_type_path)
+        {
+            self.short_type_path_to_id.remove(short_type_path);
+            self.ambiguous_short_type_paths.insert(short_type_path);
+        } else {
+            self.short_type_path_to_id
+                .insert(short_type_path, registration.type_id());
+        }
+        self.type_path_to_id
+            .insert(registration.type_path(), registration.type_id());
         self.registrations
             .insert(registration.type_id(), registration);
     }
@@ -152,6 +187,53 @@ impl TypeRegistry {
         data.insert(D::from_type());
     }
 
+    /// Registers a function as a method of type `T` with the given name,
+    /// replacing any method of `T` with the same name.
+    ///
+    /// Methods can be found with [`TypeRegistration::method`] and called dynamically,
+    /// such as from a debug console or a scripting language.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` hasn't been registered, or if the first argument of the function,
+    /// its receiver, isn't of type `T`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_reflect::{func::ArgList, Reflect, TypeRegistry};
+    ///
+    /// #[derive(Reflect)]
+    /// struct Counter(u32);
+    ///
+    /// impl Counter {
+    ///     fn get(&self) -> u32 {
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// let mut type_registry = TypeRegistry::default();
+    /// type_registry.register::<Counter>();
+    /// type_registry.register_method::<Counter, _, _>("get", Counter::get);
+    ///
+    /// let registration = type_registry.get(std::any::TypeId::of::<Counter>()).unwrap();
+    /// let get = registration.method("get").unwrap();
+    /// let value = get.call(ArgList::new().with_ref(&Counter(3))).unwrap();
+    /// assert_eq!(value.value().downcast_ref::<u32>(), Some(&3));
+    /// ```
+    pub fn register_method<T: Reflect, F: IntoFunction<Marker>, Marker>(
+        &mut self,
+        name: &'static str,
+        function: F,
+    ) {
+        let registration = self.get_mut(TypeId::of::<T>()).unwrap_or_else(|| {
+            panic!(
+                "attempted to call `TypeRegistry::register_method` for type `{T}` with method `{name}` without registering `{T}` first",
+                T = std::any::type_name::<T>(),
+            )
+        });
+        registration.register_method(name, function);
+    }
+
     /// Returns a reference to the [`TypeRegistration`] of the type with the
     /// given [`TypeId`].
     ///
@@ -176,6 +258,9 @@ impl TypeRegistry {
     /// given name.
     ///
     /// If no type with the given name has been registered, returns `None`.
+    ///
+    /// Type names are given by [`std::any::type_name`] and may change across compiler versions,
+    /// so [`TypeRegistry::get_with_type_path`] should be preferred for names which are stored.
     pub fn get_with_name(&self, type_name: &str) -> Option<&TypeRegistration> {
         self.full_name_to_id
             .get(type_name)
@@ -218,6 +303,73 @@ impl TypeRegistry {
             .and_then(|id| self.registrations.get_mut(id))
     }
 
+    /// Returns a reference to the [`TypeRegistration`] of the type with the
+    /// given [type path].
+    ///
+    /// If no type with the given type path has been registered, returns `None`.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn get_with_type_path(&self, type_path: &str) -> Option<&TypeRegistration> {
+        self.type_path_to_id
+            .get(type_path)
+            .and_then(|id| self.get(*id))
+    }
+
+    /// Returns a mutable reference to the [`TypeRegistration`] of the type with
+    /// the given [type path].
+    ///
+    /// If no type with the given type path has been registered, returns `None`.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn get_with_type_path_mut(&mut self, type_path: &str) -> Option<&mut TypeRegistration> {
+        self.type_path_to_id
+            .get(type_path)
+            .cloned()
+            .and_then(move |id| self.get_mut(id))
+    }
+
+    /// Returns a reference to the [`TypeRegistration`] of the type with
+    /// the given [short type path].
+    ///
+    /// If the short type path is ambiguous, or if no type with the given
+    /// short type path has been registered, returns `None`.
+    ///
+    /// [short type path]: TypePath::short_type_path
+    pub fn get_with_short_type_path(&self, short_type_path: &str) -> Option<&TypeRegistration> {
+        self.short_type_path_to_id
+            .get(short_type_path)
+            .and_then(|id| self.registrations.get(id))
+    }
+
+    /// Returns a mutable reference to the [`TypeRegistration`] of the type with
+    /// the given [short type path].
+    ///
+    /// If the short type path is ambiguous, or if no type with the given
+    /// short type path has been registered, returns `None`.
+    ///
+    /// [short type path]: TypePath::short_type_path
+    pub fn get_with_short_type_path_mut(
+        &mut self,
+        short_type_path: &str,
+    ) -> Option<&mut TypeRegistration> {
+        self.short_type_path_to_id
+            .get(short_type_path)
+            .and_then(|id| self.registrations.get_mut(id))
+    }
+
+    /// Returns a reference to the [`TypeRegistration`] of the type of the given value.
+    ///
+    /// Dynamic values, such as a [`DynamicStruct`], are looked up by their name and return the
+    /// registration of the type they represent.
+    ///
+    /// If the type has not been registered, returns `None`.
+    ///
+    /// [`DynamicStruct`]: crate::DynamicStruct
+    pub fn get_for_value(&self, value: &dyn Reflect) -> Option<&TypeRegistration> {
+        self.get(value.type_id())
+            .or_else(|| self.get_with_name(value.type_name()))
+    }
+
     /// Returns a reference to the [`TypeData`] of type `T` associated with the given `TypeId`.
     ///
     /// The returned value may be used to downcast [`Reflect`] trait objects to
@@ -280,7 +432,7 @@ impl TypeRegistryArc {
 /// but is more often automatically generated using [`#[derive(Reflect)]`](derive@crate::Reflect) which itself generates
 /// an implementation of the [`GetTypeRegistration`] trait.
 ///
-/// Along with the type's [`TypeInfo`] and [short name],
+/// Along with the type's [`TypeInfo`], [type path] and [short name],
 /// this struct also contains a type's registered [`TypeData`].
 ///
 /// See the [crate-level documentation] for more information on type registration.
@@ -291,25 +443,29 @@ impl TypeRegistryArc {
 /// # use bevy_reflect::{TypeRegistration, std_traits::ReflectDefault, FromType};
 /// let mut registration = TypeRegistration::of::<Option<String>>();
 ///
-/// assert_eq!("core::option::Option<alloc::string::String>", registration.type_name());
-/// assert_eq!("Option<String>", registration.short_name());
+/// assert_eq!("core::option::Option<alloc::string::String>", registration.type_path());
+/// assert_eq!("Option<String>", registration.short_type_path());
 ///
 /// registration.insert::<ReflectDefault>(FromType::<Option<String>>::from_type());
 /// assert!(registration.data::<ReflectDefault>().is_some())
 /// ```
 ///
+/// [type path]: TypePath
 /// [short name]: bevy_utils::get_short_name
 /// [crate-level documentation]: crate
 pub struct TypeRegistration {
     short_name: String,
     data: HashMap<TypeId, Box<dyn TypeData>>,
     type_info: &'static TypeInfo,
+    type_path_table: TypePathTable,
+    methods: HashMap<Cow<'static, str>, DynamicFunction>,
 }
 
 impl Debug for TypeRegistration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("TypeRegistration")
             .field("short_name", &self.short_name)
+            .field("type_path", &self.type_path_table.path())
             .field("type_info", &self.type_info)
             .finish()
     }
@@ -356,13 +512,56 @@ impl TypeRegistration {
         self.data.insert(TypeId::of::<T>(), Box::new(data));
     }
 
+    /// Registers a function as a method of the type with the given name.
+    ///
+    /// If another method with the same name was previously registered, it is replaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the first argument of the function, its receiver, isn't of this type.
+    pub fn register_method<Marker>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        function: impl IntoFunction<Marker>,
+    ) {
+        let name = name.into();
+        let function = function.into_function();
+        match function.info().args().first() {
+            Some(receiver) if receiver.type_id() == self.type_id() => {}
+            Some(receiver) => panic!(
+                "attempted to register method `{name}` for type `{T}` with a receiver of type `{R}`",
+                T = self.type_path(),
+                R = receiver.type_path(),
+            ),
+            None => panic!(
+                "attempted to register method `{name}` for type `{T}` without a receiver",
+                T = self.type_path(),
+            ),
+        }
+        self.methods.insert(name, function);
+    }
+
+    /// Returns the method of the type with the given name, if any.
+    pub fn method(&self, name: &str) -> Option<&DynamicFunction> {
+        self.methods.get(name)
+    }
+
+    /// Returns an iterator over the methods of the type and their names.
+    pub fn methods(&self) -> impl Iterator<Item = (&str, &DynamicFunction)> {
+        self.methods
+            .iter()
+            .map(|(name, function)| (name.as_ref(), function))
+    }
+
     /// Creates type registration information for `T`.
-    pub fn of<T: Reflect + Typed>() -> Self {
+    pub fn of<T: Reflect + Typed + TypePath>() -> Self {
         let type_name = std::any::type_name::<T>();
         Self {
             data: HashMap::default(),
             short_name: bevy_utils::get_short_name(type_name),
             type_info: T::type_info(),
+            type_path_table: TypePathTable::of::<T>(),
+            methods: HashMap::default(),
         }
     }
 
@@ -379,6 +578,28 @@ impl TypeRegistration {
     pub fn type_name(&self) -> &'static str {
         self.type_info.type_name()
     }
+
+    /// Returns the [type path] of the type.
+    ///
+    /// Unlike the [type name](TypeRegistration::type_name), the type path is stable across
+    /// compiler versions and is used to identify the type in serialized data.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path_table.path()
+    }
+
+    /// Returns the [short type path] of the type.
+    ///
+    /// [short type path]: TypePath::short_type_path
+    pub fn short_type_path(&self) -> &'static str {
+        self.type_path_table.short_path()
+    }
+
+    /// Returns the [`TypePathTable`] of the type.
+    pub fn type_path_table(&self) -> &TypePathTable {
+        &self.type_path_table
+    }
 }
 
 impl Clone for TypeRegistration {
@@ -392,6 +613,8 @@ impl Clone for TypeRegistration {
             data,
             short_name: self.short_name.clone(),
             type_info: self.type_info,
+            type_path_table: self.type_path_table,
+            methods: self.methods.clone(),
         }
     }
 }
@@ -493,6 +716,20 @@ impl<T: for<'a> Deserialize<'a> + Reflect> FromType<T> for ReflectDeserialize {
     }
 }
 
+/// A marker for types whose values are runtime-only state, which shouldn't be saved along with
+/// other reflected values.
+///
+/// It's registered with `#[reflect(SkipSerializing)]`, and scene builders skip components and
+/// resources of types with this type data unless they are explicitly allowed.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ReflectSkipSerializing;
+
+impl<T: Reflect> FromType<T> for ReflectSkipSerializing {
+    fn from_type() -> Self {
+        ReflectSkipSerializing
+    }
+}
+
 /// [`Reflect`] values are commonly used in situations where the actual types of values
 /// are not known at runtime. In such situations you might have access to a `*const ()` pointer
 /// that you know implements [`Reflect`], but have no way of turning it into a `&dyn Reflect`.
@@ -573,9 +810,10 @@ impl<T: Reflect> FromType<T> for ReflectFromPtr {
 
 #[cfg(test)]
 mod test {
-    use crate::{GetTypeRegistration, ReflectFromPtr, TypeRegistration};
+    use crate::{GetTypeRegistration, ReflectFromPtr, TypeRegistration, TypeRegistry};
     use bevy_ptr::{Ptr, PtrMut};
     use bevy_utils::HashMap;
+    use std::any::TypeId;
 
     use crate as bevy_reflect;
     use crate::Reflect;
@@ -653,4 +891,61 @@ mod test {
             "Option<HashMap<Option<String>, (String, Option<String>)>>"
         );
     }
+
+    #[test]
+    fn test_type_path_registration() {
+        #[derive(Reflect)]
+        #[type_path = "my_crate::items"]
+        struct Foo<T>(T);
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Foo<u32>>();
+        registry.register::<Option<String>>();
+
+        let registration = registry
+            .get_with_type_path("my_crate::items::Foo<u32>")
+            .unwrap();
+        assert_eq!(registration.type_id(), TypeId::of::<Foo<u32>>());
+        assert_eq!(registration.short_type_path(), "Foo<u32>");
+
+        let registration = registry
+            .get_with_type_path("core::option::Option<alloc::string::String>")
+            .unwrap();
+        assert_eq!(registration.type_id(), TypeId::of::<Option<String>>());
+        assert_eq!(
+            registry
+                .get_with_short_type_path("Option<String>")
+                .map(TypeRegistration::type_id),
+            Some(TypeId::of::<Option<String>>())
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "attempted to register type `bevy_reflect::type_registry::test::test_type_path_collision::b::Item` with type path `my_crate::items::Item`, which is already used by type `bevy_reflect::type_registry::test::test_type_path_collision::a::Item`"
+    )]
+    fn test_type_path_collision() {
+        mod a {
+            use crate as bevy_reflect;
+            use crate::Reflect;
+
+            #[derive(Reflect)]
+            #[type_path = "my_crate::items"]
+            pub struct Item;
+        }
+
+        mod b {
+            use crate as bevy_reflect;
+            use crate::Reflect;
+
+            #[derive(Reflect)]
+            #[type_path = "my_crate::items"]
+            pub struct Item;
+        }
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<a::Item>();
+        registry.register::<a::Item>();
+        registry.register::<b::Item>();
+    }
 }
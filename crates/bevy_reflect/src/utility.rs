@@ -19,9 +19,10 @@ use std::{
 ///
 /// ```
 /// # use std::any::Any;
-/// # use bevy_reflect::{NamedField, Reflect, ReflectMut, ReflectOwned, ReflectRef, StructInfo, Typed, TypeInfo};
+/// # use bevy_reflect::{NamedField, Reflect, ReflectMut, ReflectOwned, ReflectRef, StructInfo, Typed, TypeInfo, TypePath};
 /// use bevy_reflect::utility::NonGenericTypeInfoCell;
 ///
+/// #[derive(TypePath)]
 /// struct Foo {
 ///   bar: i32
 /// }
@@ -84,12 +85,13 @@ impl NonGenericTypeInfoCell {
 ///
 /// ```
 /// # use std::any::Any;
-/// # use bevy_reflect::{Reflect, ReflectMut, ReflectOwned, ReflectRef, TupleStructInfo, Typed, TypeInfo, UnnamedField};
+/// # use bevy_reflect::{Reflect, ReflectMut, ReflectOwned, ReflectRef, TupleStructInfo, Typed, TypeInfo, TypePath, UnnamedField};
 /// use bevy_reflect::utility::GenericTypeInfoCell;
 ///
+/// #[derive(TypePath)]
 /// struct Foo<T: Reflect>(T);
 ///
-/// impl<T: Reflect> Typed for Foo<T> {
+/// impl<T: Reflect + TypePath> Typed for Foo<T> {
 ///   fn type_info() -> &'static TypeInfo {
 ///     static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
 ///     CELL.get_or_insert::<Self, _>(|| {
@@ -100,7 +102,7 @@ impl NonGenericTypeInfoCell {
 ///   }
 /// }
 /// #
-/// # impl<T: Reflect> Reflect for Foo<T> {
+/// # impl<T: Reflect + TypePath> Reflect for Foo<T> {
 /// #   fn type_name(&self) -> &str { todo!() }
 /// #   fn get_type_info(&self) -> &'static TypeInfo { todo!() }
 /// #   fn into_any(self: Box<Self>) -> Box<dyn Any> { todo!() }
@@ -151,6 +153,68 @@ impl GenericTypeInfoCell {
     }
 }
 
+/// A container for the [type paths] of generic types, allowing them to be stored statically.
+///
+/// The paths of generic types include the paths of their type parameters, so they are
+/// generated at runtime, once for each monomorphization of the type.
+///
+/// ## Example
+///
+/// ```
+/// # use bevy_reflect::TypePath;
+/// use bevy_reflect::utility::GenericTypePathCell;
+///
+/// struct Foo<T>(T);
+///
+/// impl<T: TypePath> TypePath for Foo<T> {
+///     fn type_path() -> &'static str {
+///         static CELL: GenericTypePathCell = GenericTypePathCell::new();
+///         CELL.get_or_insert::<Self, _>(|| format!("my_crate::Foo<{}>", T::type_path()))
+///     }
+///
+///     fn short_type_path() -> &'static str {
+///         static CELL: GenericTypePathCell = GenericTypePathCell::new();
+///         CELL.get_or_insert::<Self, _>(|| format!("Foo<{}>", T::short_type_path()))
+///     }
+/// }
+///
+/// assert_eq!(Foo::<u32>::type_path(), "my_crate::Foo<u32>");
+/// ```
+///
+/// [type paths]: crate::TypePath
+pub struct GenericTypePathCell(OnceBox<RwLock<HashMap<TypeId, &'static str>>>);
+
+impl GenericTypePathCell {
+    /// Initialize a [`GenericTypePathCell`] for generic types.
+    pub const fn new() -> Self {
+        Self(OnceBox::new())
+    }
+
+    /// Returns the path stored in the cell for the type `T`.
+    ///
+    /// If there is no path found, a new one will be generated from the given function.
+    pub fn get_or_insert<T, F>(&self, f: F) -> &'static str
+    where
+        T: Any + ?Sized,
+        F: FnOnce() -> String,
+    {
+        let type_id = TypeId::of::<T>();
+        let mapping = self.0.get_or_init(Box::default);
+        if let Some(path) = mapping.read().get(&type_id) {
+            return path;
+        }
+
+        // The path is generated before locking the mapping, since generating it may require the
+        // paths of other monomorphizations of the same type, such as `Option<Option<u32>>`.
+        let path = f();
+        // Like `GenericTypeInfoCell`, the path is leaked to obtain a `&'static` reference.
+        mapping
+            .write()
+            .entry(type_id)
+            .or_insert_with(|| Box::leak(path.into_boxed_str()))
+    }
+}
+
 /// Deterministic fixed state hasher to be used by implementors of [`Reflect::reflect_hash`].
 ///
 /// Hashes should be deterministic across processes so hashes can be used as
@@ -1,8 +1,8 @@
 use super::ShaderDefVal;
 use crate::define_atomic_id;
 use bevy_asset::{AssetLoader, AssetPath, Handle, LoadContext, LoadedAsset};
-use bevy_reflect::TypeUuid;
-use bevy_utils::{tracing::error, BoxedFuture, HashMap};
+use bevy_reflect::{TypePath, TypeUuid};
+use bevy_utils::{tracing::error, BoxedFuture, HashMap, Uuid};
 use naga::{back::wgsl::WriterFlags, valid::Capabilities, valid::ModuleInfo, Module};
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -25,7 +25,7 @@ pub enum ShaderReflectError {
 }
 /// A shader, as defined by its [`ShaderSource`] and [`ShaderStage`](naga::ShaderStage)
 /// This is an "unprocessed" shader. It can contain preprocessor directives.
-#[derive(Debug, Clone, TypeUuid)]
+#[derive(Debug, Clone, TypePath, TypeUuid)]
 #[uuid = "d95bc916-6c55-4de3-9622-37e7b6969fda"]
 pub struct Shader {
     source: Source,
@@ -232,7 +232,7 @@ impl ShaderReflection {
     }
 }
 
-#[derive(Default)]
+#[derive(Default, TypePath)]
 pub struct ShaderLoader;
 
 impl AssetLoader for ShaderLoader {
@@ -286,6 +286,10 @@ impl AssetLoader for ShaderLoader {
     fn extensions(&self) -> &[&str] {
         &["spv", "wgsl", "vert", "frag", "comp"]
     }
+
+    fn asset_type_uuid(&self) -> Option<Uuid> {
+        Some(Shader::TYPE_UUID)
+    }
 }
 
 #[derive(Error, Debug, PartialEq, Eq)]
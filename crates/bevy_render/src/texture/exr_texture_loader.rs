@@ -1,12 +1,13 @@
 use crate::texture::{Image, TextureFormatPixelInfo};
 use anyhow::Result;
 use bevy_asset::{AssetLoader, LoadContext, LoadedAsset};
-use bevy_utils::BoxedFuture;
+use bevy_reflect::{TypePath, TypeUuid};
+use bevy_utils::{BoxedFuture, Uuid};
 use image::ImageDecoder;
 use wgpu::{Extent3d, TextureDimension, TextureFormat};
 
 /// Loads EXR textures as Texture assets
-#[derive(Clone, Default)]
+#[derive(Clone, Default, TypePath)]
 pub struct ExrTextureLoader;
 
 impl AssetLoader for ExrTextureLoader {
@@ -53,4 +54,8 @@ impl AssetLoader for ExrTextureLoader {
     fn extensions(&self) -> &[&str] {
         &["exr"]
     }
+
+    fn asset_type_uuid(&self) -> Option<Uuid> {
+        Some(Image::TYPE_UUID)
+    }
 }
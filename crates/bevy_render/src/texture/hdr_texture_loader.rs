@@ -1,11 +1,12 @@
 use crate::texture::{Image, TextureFormatPixelInfo};
 use anyhow::Result;
 use bevy_asset::{AssetLoader, LoadContext, LoadedAsset};
-use bevy_utils::BoxedFuture;
+use bevy_reflect::{TypePath, TypeUuid};
+use bevy_utils::{BoxedFuture, Uuid};
 use wgpu::{Extent3d, TextureDimension, TextureFormat};
 
 /// Loads HDR textures as Texture assets
-#[derive(Clone, Default)]
+#[derive(Clone, Default, TypePath)]
 pub struct HdrTextureLoader;
 
 impl AssetLoader for HdrTextureLoader {
@@ -55,4 +56,8 @@ impl AssetLoader for HdrTextureLoader {
     fn extensions(&self) -> &[&str] {
         &["hdr"]
     }
+
+    fn asset_type_uuid(&self) -> Option<Uuid> {
+        Some(Image::TYPE_UUID)
+    }
 }
@@ -1,7 +1,8 @@
 use anyhow::Result;
 use bevy_asset::{AssetLoader, LoadContext, LoadedAsset};
 use bevy_ecs::prelude::{FromWorld, World};
-use bevy_utils::BoxedFuture;
+use bevy_reflect::{TypePath, TypeUuid};
+use bevy_utils::{BoxedFuture, Uuid};
 use thiserror::Error;
 
 use crate::{
@@ -12,7 +13,7 @@ use crate::{
 use super::CompressedImageFormats;
 
 /// Loader for images that can be read by the `image` crate.
-#[derive(Clone)]
+#[derive(Clone, TypePath)]
 pub struct ImageTextureLoader {
     supported_compressed_formats: CompressedImageFormats,
 }
@@ -65,6 +66,10 @@ impl AssetLoader for ImageTextureLoader {
     fn extensions(&self) -> &[&str] {
         FILE_EXTENSIONS
     }
+
+    fn asset_type_uuid(&self) -> Option<Uuid> {
+        Some(Image::TYPE_UUID)
+    }
 }
 
 impl FromWorld for ImageTextureLoader {
@@ -0,0 +1,64 @@
+use anyhow::Result;
+use bevy_asset::AssetSaver;
+use std::{io::Cursor, path::Path};
+
+use super::Image;
+
+/// Saves [`Image`]s to PNG files.
+///
+/// Only the texture formats supported by [`Image::try_into_dynamic`] can be saved.
+#[derive(Clone, Default)]
+pub struct ImageTextureSaver;
+
+impl AssetSaver for ImageTextureSaver {
+    type Asset = Image;
+
+    fn save(&self, image: &Image, _path: &Path) -> Result<Vec<u8>> {
+        let mut bytes = Cursor::new(Vec::new());
+        image
+            .clone()
+            .try_into_dynamic()?
+            .write_to(&mut bytes, image::ImageOutputFormat::Png)?;
+        Ok(bytes.into_inner())
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["png"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+    #[test]
+    fn saved_png_can_be_loaded() {
+        let image = Image::new(
+            Extent3d {
+                width: 2,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            vec![255, 0, 0, 255, 0, 0, 255, 128],
+            TextureFormat::Rgba8UnormSrgb,
+        );
+
+        let bytes = ImageTextureSaver
+            .save(&image, Path::new("image.png"))
+            .unwrap();
+        let loaded = Image::from_buffer(
+            &bytes,
+            crate::texture::ImageType::Extension("png"),
+            crate::texture::CompressedImageFormats::NONE,
+            true,
+        )
+        .unwrap();
+        assert_eq!(
+            loaded.texture_descriptor.size,
+            image.texture_descriptor.size
+        );
+        assert_eq!(loaded.data, image.data);
+    }
+}
@@ -10,6 +10,8 @@ mod hdr_texture_loader;
 #[allow(clippy::module_inception)]
 mod image;
 mod image_texture_loader;
+#[cfg(feature = "png")]
+mod image_texture_saver;
 #[cfg(feature = "ktx2")]
 mod ktx2;
 mod texture_cache;
@@ -28,6 +30,8 @@ pub use hdr_texture_loader::*;
 
 pub use fallback_image::*;
 pub use image_texture_loader::*;
+#[cfg(feature = "png")]
+pub use image_texture_saver::*;
 pub use texture_cache::*;
 
 use crate::{
@@ -99,6 +103,12 @@ impl Plugin for ImagePlugin {
         .register_type::<Image>()
         .add_asset::<Image>()
         .register_asset_reflect::<Image>();
+
+        #[cfg(feature = "png")]
+        {
+            app.init_asset_saver::<ImageTextureSaver>();
+        }
+
         app.world
             .resource_mut::<Assets<Image>>()
             .set_untracked(DEFAULT_IMAGE_HANDLE, Image::default());
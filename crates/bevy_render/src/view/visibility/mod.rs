@@ -6,8 +6,8 @@ use bevy_app::{CoreSet, Plugin};
 use bevy_asset::{Assets, Handle};
 use bevy_ecs::prelude::*;
 use bevy_hierarchy::{Children, Parent};
-use bevy_reflect::Reflect;
 use bevy_reflect::{std_traits::ReflectDefault, FromReflect};
+use bevy_reflect::{Reflect, ReflectSkipSerializing};
 use bevy_transform::components::GlobalTransform;
 use bevy_transform::TransformSystem;
 use std::cell::Cell;
@@ -72,7 +72,7 @@ bitflags::bitflags! {
 
 /// Algorithmically-computed indication of whether an entity is visible and should be extracted for rendering
 #[derive(Component, Clone, Reflect, Debug, Eq, PartialEq)]
-#[reflect(Component, Default)]
+#[reflect(Component, Default, SkipSerializing)]
 pub struct ComputedVisibility {
     flags: ComputedVisibilityFlags,
 }
@@ -171,7 +171,7 @@ pub struct NoFrustumCulling;
 /// Currently this component is ignored by the sprite renderer, so sprite rendering
 /// is not optimized per view.
 #[derive(Clone, Component, Default, Debug, Reflect)]
-#[reflect(Component)]
+#[reflect(Component, SkipSerializing)]
 pub struct VisibleEntities {
     #[reflect(ignore)]
     pub entities: Vec<Entity>,
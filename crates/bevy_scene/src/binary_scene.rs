@@ -0,0 +1,474 @@
+use crate::{
+    serde::{SceneDeserializer, SceneSerializer},
+    DynamicScene,
+};
+use bevy_reflect::{TypeInfo, TypeRegistration, TypeRegistry, TypeRegistryArc, VariantInfo};
+use bevy_utils::HashSet;
+use serde::{de::DeserializeSeed, Deserialize, Serialize};
+use std::any::TypeId;
+use thiserror::Error;
+
+/// The bytes every binary scene starts with.
+pub const BINARY_SCENE_MAGIC: [u8; 4] = *b"BSCN";
+
+/// The version of the binary scene format written by [`serialize_binary_scene`].
+pub const BINARY_SCENE_VERSION: u16 = 4;
+
+/// An error that occurs when serializing or deserializing a binary scene.
+#[derive(Error, Debug)]
+pub enum BinarySceneError {
+    #[error("not a binary scene: invalid header")]
+    InvalidHeader,
+    #[error("unsupported binary scene version {version}, expected version {BINARY_SCENE_VERSION}")]
+    UnsupportedVersion { version: u16 },
+    #[error("binary scene contains the unregistered type `{type_name}`")]
+    UnregisteredType { type_name: String },
+    #[error("the layout of `{type_name}` changed since the binary scene was saved")]
+    TypeMismatch { type_name: String },
+    #[error("invalid binary scene data: {0}")]
+    Postcard(#[from] postcard::Error),
+}
+
+/// The header following the magic bytes and the version of a binary scene.
+///
+/// The binary format isn't self-describing: the values of a scene can only be read back if their
+/// types have the same layout as when they were written. The header lists the
+/// [type path](bevy_reflect::TypePath) and layout hash of every resource and component type of
+/// the scene, including the components overriding those of nested scenes, to reject scenes saved
+/// with different types.
+#[derive(Serialize, Deserialize)]
+struct BinarySceneHeader {
+    types: Vec<(String, u64)>,
+}
+
+/// Serializes a [`DynamicScene`] to the compact binary scene format.
+///
+/// The scene is written with [`postcard`] after a header made of [`BINARY_SCENE_MAGIC`],
+/// [`BINARY_SCENE_VERSION`] and the [layout hash](type_layout_hash) of each type of the scene.
+/// It's much faster to load than RON, but can only be loaded back by an app whose types have the
+/// same layout.
+pub fn serialize_binary_scene(
+    scene: &DynamicScene,
+    registry: &TypeRegistryArc,
+) -> Result<Vec<u8>, BinarySceneError> {
+    let header = {
+        let registry = registry.read();
+        let mut registrations: Vec<&TypeRegistration> = scene
+            .resources
+            .iter()
+            .chain(scene.entities.iter().flat_map(|entity| &entity.components))
+            .chain(
+                scene
+                    .scenes
+                    .iter()
+                    .flat_map(|nested| &nested.overrides)
+                    .flat_map(|scene_override| &scene_override.components),
+            )
+            .map(|value| {
+                registry
+                    .get_for_value(&**value)
+                    .ok_or_else(|| BinarySceneError::UnregisteredType {
+                        type_name: value.type_name().to_string(),
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+        registrations.sort_unstable_by_key(|registration| registration.type_path());
+        registrations.dedup_by_key(|registration| registration.type_id());
+        let types = registrations
+            .into_iter()
+            .map(|registration| {
+                let hash = registration_layout_hash(registration, &registry)?;
+                Ok((registration.type_path().to_string(), hash))
+            })
+            .collect::<Result<_, BinarySceneError>>()?;
+        BinarySceneHeader { types }
+    };
+
+    let mut bytes = BINARY_SCENE_MAGIC.to_vec();
+    bytes.extend_from_slice(&BINARY_SCENE_VERSION.to_le_bytes());
+    let bytes = postcard::to_extend(&header, bytes)?;
+    Ok(postcard::to_extend(
+        &SceneSerializer::new(scene, registry),
+        bytes,
+    )?)
+}
+
+/// Deserializes a [`DynamicScene`] written by [`serialize_binary_scene`].
+///
+/// The header is checked first: the types of the scene must be registered, with the same layout
+/// as when the scene was written.
+pub fn deserialize_binary_scene(
+    bytes: &[u8],
+    registry: &TypeRegistry,
+) -> Result<DynamicScene, BinarySceneError> {
+    let bytes = bytes
+        .strip_prefix(&BINARY_SCENE_MAGIC)
+        .ok_or(BinarySceneError::InvalidHeader)?;
+    let (version, bytes) = match bytes {
+        [a, b, bytes @ ..] => (u16::from_le_bytes([*a, *b]), bytes),
+        _ => return Err(BinarySceneError::InvalidHeader),
+    };
+    if version != BINARY_SCENE_VERSION {
+        return Err(BinarySceneError::UnsupportedVersion { version });
+    }
+
+    let (header, bytes): (BinarySceneHeader, _) = postcard::take_from_bytes(bytes)?;
+    for (type_name, hash) in header.types {
+        if type_layout_hash(&type_name, registry)? != hash {
+            return Err(BinarySceneError::TypeMismatch { type_name });
+        }
+    }
+
+    let scene_deserializer = SceneDeserializer {
+        type_registry: registry,
+    };
+    Ok(scene_deserializer.deserialize(&mut postcard::Deserializer::from_bytes(bytes))?)
+}
+
+/// Returns a hash of the layout of the registered type with the given
+/// [type path](bevy_reflect::TypePath).
+///
+/// The hash covers the kind of the type, the names of its fields and variants and the layout of
+/// the types of its fields, so that it changes whenever values of the type would be serialized
+/// differently by a non-self-describing format. Types are identified by their type paths, so the
+/// hash is stable across builds, compiler versions and platforms.
+///
+/// Returns [`BinarySceneError::UnregisteredType`] if the type, or the type of one of its fields,
+/// isn't registered, since its layout can't be known.
+pub fn type_layout_hash(type_path: &str, registry: &TypeRegistry) -> Result<u64, BinarySceneError> {
+    let registration = registry.get_with_type_path(type_path).ok_or_else(|| {
+        BinarySceneError::UnregisteredType {
+            type_name: type_path.to_string(),
+        }
+    })?;
+    registration_layout_hash(registration, registry)
+}
+
+fn registration_layout_hash(
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+) -> Result<u64, BinarySceneError> {
+    let mut hasher = LayoutHasher::default();
+    hasher.write_type_info(registration.type_info(), registry, &mut HashSet::default())?;
+    Ok(hasher.0)
+}
+
+/// A 64 bit FNV-1a hasher, which unlike the hashers of the standard library is guaranteed to be
+/// stable.
+struct LayoutHasher(u64);
+
+impl Default for LayoutHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl LayoutHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_str(&mut self, s: &str) {
+        self.write(&(s.len() as u64).to_le_bytes());
+        self.write(s.as_bytes());
+    }
+
+    /// Writes the layout of the type with the given name, recursing into types which aren't
+    /// already being written.
+    ///
+    /// Types are written as their type path, since their type name isn't stable, so they must be
+    /// registered.
+    fn write_type(
+        &mut self,
+        type_name: &'static str,
+        registry: &TypeRegistry,
+        visited: &mut HashSet<&'static str>,
+    ) -> Result<(), BinarySceneError> {
+        let registration = registry.get_with_name(type_name).ok_or_else(|| {
+            BinarySceneError::UnregisteredType {
+                type_name: type_name.to_string(),
+            }
+        })?;
+        let type_path = registration.type_path();
+        self.write_str(type_path);
+        if visited.insert(type_path) {
+            self.write_type_info(registration.type_info(), registry, visited)?;
+            visited.remove(type_path);
+        }
+        Ok(())
+    }
+
+    fn write_type_info(
+        &mut self,
+        type_info: &TypeInfo,
+        registry: &TypeRegistry,
+        visited: &mut HashSet<&'static str>,
+    ) -> Result<(), BinarySceneError> {
+        match type_info {
+            TypeInfo::Struct(info) => {
+                self.write_str("struct");
+                for field in info.iter() {
+                    self.write_str(field.name());
+                    self.write_type(field.type_name(), registry, visited)?;
+                }
+            }
+            TypeInfo::TupleStruct(info) => {
+                self.write_str("tuple_struct");
+                for field in info.iter() {
+                    self.write_type(field.type_name(), registry, visited)?;
+                }
+            }
+            TypeInfo::Tuple(info) => {
+                self.write_str("tuple");
+                for field in info.iter() {
+                    self.write_type(field.type_name(), registry, visited)?;
+                }
+            }
+            TypeInfo::List(info) => {
+                self.write_str("list");
+                self.write_type(info.item_type_name(), registry, visited)?;
+            }
+            TypeInfo::Array(info) => {
+                self.write_str("array");
+                self.write(&(info.capacity() as u64).to_le_bytes());
+                self.write_type(info.item_type_name(), registry, visited)?;
+            }
+            TypeInfo::Map(info) => {
+                self.write_str("map");
+                self.write_type(info.key_type_name(), registry, visited)?;
+                self.write_type(info.value_type_name(), registry, visited)?;
+            }
+            TypeInfo::Enum(info) => {
+                self.write_str("enum");
+                for variant in info.iter() {
+                    match variant {
+                        VariantInfo::Struct(variant) => {
+                            self.write_str(variant.name());
+                            for field in variant.iter() {
+                                self.write_str(field.name());
+                                self.write_type(field.type_name(), registry, visited)?;
+                            }
+                        }
+                        VariantInfo::Tuple(variant) => {
+                            self.write_str(variant.name());
+                            for field in variant.iter() {
+                                self.write_type(field.type_name(), registry, visited)?;
+                            }
+                        }
+                        VariantInfo::Unit(variant) => self.write_str(variant.name()),
+                    }
+                }
+            }
+            TypeInfo::Value(info) => {
+                self.write_str("value");
+                self.write_registered_type_path(info.type_id(), info.type_name(), registry)?;
+            }
+            TypeInfo::Dynamic(info) => {
+                self.write_str("dynamic");
+                self.write_registered_type_path(info.type_id(), info.type_name(), registry)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_registered_type_path(
+        &mut self,
+        type_id: TypeId,
+        type_name: &str,
+        registry: &TypeRegistry,
+    ) -> Result<(), BinarySceneError> {
+        let registration =
+            registry
+                .get(type_id)
+                .ok_or_else(|| BinarySceneError::UnregisteredType {
+                    type_name: type_name.to_string(),
+                })?;
+        self.write_str(registration.type_path());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        deserialize_binary_scene, serialize_binary_scene, type_layout_hash, BinarySceneError,
+        BinarySceneHeader,
+    };
+    use crate::{DynamicScene, DynamicSceneBuilder, NestedScene};
+    use bevy_app::AppTypeRegistry;
+    use bevy_ecs::{prelude::*, reflect::ReflectResource};
+    use bevy_reflect::{Reflect, TypePath, TypeRegistry};
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Resource, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    // a type named like `Position`, but with a different layout
+    mod changed {
+        use bevy_ecs::prelude::*;
+        use bevy_reflect::Reflect;
+
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component)]
+        pub struct Position {
+            pub x: f32,
+        }
+    }
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Position>();
+            registry.register::<Score>();
+            registry.register::<f32>();
+            registry.register::<u32>();
+        }
+        world.insert_resource(registry);
+        world
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut world = create_world();
+        let entity = world.spawn(Position { x: 1.0, y: 2.0 }).id();
+        world.insert_resource(Score(3));
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.extract_entity(entity).extract_resources();
+        let scene = builder.build();
+
+        let bytes = serialize_binary_scene(&scene, &registry).unwrap();
+        assert!(bytes.starts_with(b"BSCN"));
+        let deserialized = deserialize_binary_scene(&bytes, &registry.read()).unwrap();
+
+        let mut destination = World::new();
+        destination.insert_resource(registry);
+        deserialized
+            .write_to_world(&mut destination, &mut Default::default())
+            .unwrap();
+        assert_eq!(destination.resource::<Score>(), &Score(3));
+        let mut query = destination.query::<&Position>();
+        assert_eq!(query.single(&destination), &Position { x: 1.0, y: 2.0 });
+    }
+
+    #[test]
+    fn reject_invalid_scenes() {
+        let mut world = create_world();
+        let entity = world.spawn(Position { x: 1.0, y: 2.0 }).id();
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.extract_entity(entity);
+        let bytes = serialize_binary_scene(&builder.build(), &registry).unwrap();
+
+        assert!(matches!(
+            deserialize_binary_scene(b"(entities: {})", &registry.read()),
+            Err(BinarySceneError::InvalidHeader)
+        ));
+
+        let mut next_version = bytes.clone();
+        next_version[4] += 1;
+        assert!(matches!(
+            deserialize_binary_scene(&next_version, &registry.read()),
+            Err(BinarySceneError::UnsupportedVersion { version: 5 })
+        ));
+
+        assert!(matches!(
+            deserialize_binary_scene(&bytes, &TypeRegistry::default()),
+            Err(BinarySceneError::UnregisteredType { .. })
+        ));
+
+        // rewrite the header as if `Position` had another layout when the scene was saved
+        let (mut header, body): (BinarySceneHeader, _) =
+            postcard::take_from_bytes(&bytes[6..]).unwrap();
+        assert_eq!(
+            header.types,
+            [(
+                Position::type_path().to_string(),
+                type_layout_hash(Position::type_path(), &registry.read()).unwrap()
+            )]
+        );
+        header.types[0].1 += 1;
+        let mut changed = postcard::to_extend(&header, bytes[..6].to_vec()).unwrap();
+        changed.extend_from_slice(body);
+        assert!(matches!(
+            deserialize_binary_scene(&changed, &registry.read()),
+            Err(BinarySceneError::TypeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn check_override_types() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let scene = DynamicScene {
+            scenes: vec![NestedScene::new("goblin.scn.ron", Default::default())
+                .with_override("Goblin", vec![Box::new(Position { x: 1.0, y: 2.0 })])],
+            ..Default::default()
+        };
+        let bytes = serialize_binary_scene(&scene, &registry).unwrap();
+
+        let (mut header, body): (BinarySceneHeader, _) =
+            postcard::take_from_bytes(&bytes[6..]).unwrap();
+        assert_eq!(
+            header.types,
+            [(
+                Position::type_path().to_string(),
+                type_layout_hash(Position::type_path(), &registry.read()).unwrap()
+            )]
+        );
+        let deserialized = deserialize_binary_scene(&bytes, &registry.read()).unwrap();
+        assert_eq!(deserialized.scenes[0].overrides[0].components.len(), 1);
+
+        header.types[0].1 += 1;
+        let mut changed = postcard::to_extend(&header, bytes[..6].to_vec()).unwrap();
+        changed.extend_from_slice(body);
+        assert!(matches!(
+            deserialize_binary_scene(&changed, &registry.read()),
+            Err(BinarySceneError::TypeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn layout_hash_changes_with_layout() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Position>();
+        registry.register::<changed::Position>();
+        registry.register::<Score>();
+        let hash = |type_path| type_layout_hash(type_path, &registry).unwrap();
+
+        assert_eq!(hash(Position::type_path()), hash(Position::type_path()));
+        // only the layouts are hashed, not the paths of the types themselves
+        assert_ne!(
+            hash(Position::type_path()),
+            hash(changed::Position::type_path())
+        );
+        assert_ne!(hash(Position::type_path()), hash(Score::type_path()));
+        assert!(matches!(
+            type_layout_hash("unregistered::Type", &registry),
+            Err(BinarySceneError::UnregisteredType { type_name }) if type_name == "unregistered::Type"
+        ));
+    }
+
+    #[test]
+    fn unregistered_field_type() {
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Position>();
+
+        assert!(matches!(
+            type_layout_hash(Position::type_path(), &registry),
+            Err(BinarySceneError::UnregisteredType { type_name }) if type_name == "f32"
+        ));
+    }
+}
@@ -20,7 +20,8 @@ pub struct SceneInstance(InstanceId);
 /// A component bundle for a [`Scene`] root.
 ///
 /// The scene from `scene` will be spawn as a child of the entity with this component.
-/// Once it's spawned, the entity will have a [`SceneInstance`] component.
+/// Once it's spawned, the entity will have a [`SceneInstance`] component, and a
+/// [`SceneInstanceReady`](crate::SceneInstanceReady) event is sent with the entity as `parent`.
 #[derive(Default, Bundle)]
 pub struct SceneBundle {
     /// Handle to the scene to spawn
@@ -34,7 +35,8 @@ pub struct SceneBundle {
 /// A component bundle for a [`DynamicScene`] root.
 ///
 /// The dynamic scene from `scene` will be spawn as a child of the entity with this component.
-/// Once it's spawned, the entity will have a [`SceneInstance`] component.
+/// Once it's spawned, the entity will have a [`SceneInstance`] component, and a
+/// [`SceneInstanceReady`](crate::SceneInstanceReady) event is sent with the entity as `parent`.
 #[derive(Default, Bundle)]
 pub struct DynamicSceneBundle {
     /// Handle to the scene to spawn
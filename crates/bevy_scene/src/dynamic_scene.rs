@@ -1,29 +1,34 @@
-use crate::{DynamicSceneBuilder, Scene, SceneSpawnError};
+use crate::{DynamicSceneBuilder, NestedScene, Scene, SceneSpawnError};
 use anyhow::Result;
 use bevy_app::AppTypeRegistry;
 use bevy_ecs::{
     entity::EntityMap,
-    reflect::{ReflectComponent, ReflectMapEntities},
+    reflect::{ReflectComponent, ReflectMapEntities, ReflectMapEntitiesResource, ReflectResource},
     world::World,
 };
-use bevy_reflect::{Reflect, TypeRegistryArc, TypeUuid};
+use bevy_reflect::{Reflect, TypePath, TypeRegistryArc, TypeUuid};
 
 #[cfg(feature = "serialize")]
 use crate::serde::SceneSerializer;
 #[cfg(feature = "serialize")]
 use serde::Serialize;
 
-/// A collection of serializable dynamic entities, each with its own run-time defined set of components.
+/// A collection of serializable resources and dynamic entities, each with its own run-time defined
+/// set of components.
 /// To spawn a dynamic scene, you can use either:
 /// * [`SceneSpawner::spawn_dynamic`](crate::SceneSpawner::spawn_dynamic)
 /// * adding the [`DynamicSceneBundle`](crate::DynamicSceneBundle) to an entity
 /// * adding the [`Handle<DynamicScene>`](bevy_asset::Handle) to an entity (the scene will only be
 /// visible if the entity already has [`Transform`](bevy_transform::components::Transform) and
 /// [`GlobalTransform`](bevy_transform::components::GlobalTransform) components)
-#[derive(Default, TypeUuid)]
+#[derive(Default, TypePath, TypeUuid)]
 #[uuid = "749479b1-fb8c-4ff8-a775-623aa76014f5"]
 pub struct DynamicScene {
+    /// The resources of the scene, which implement the `Reflect` trait.
+    pub resources: Vec<Box<dyn Reflect>>,
     pub entities: Vec<DynamicEntity>,
+    /// Other scenes nested in the scene, which are spawned with it.
+    pub scenes: Vec<NestedScene>,
 }
 
 /// A reflection-powered serializable representation of an entity and its components.
@@ -42,6 +47,9 @@ impl DynamicScene {
     }
 
     /// Create a new dynamic scene from a given world.
+    ///
+    /// Only the entities of the world are extracted. Resources, which include engine resources
+    /// such as `Time`, can be extracted with [`DynamicSceneBuilder::extract_resources`].
     pub fn from_world(world: &World, type_registry: &AppTypeRegistry) -> Self {
         let mut builder =
             DynamicSceneBuilder::from_world_with_type_registry(world, type_registry.clone());
@@ -51,11 +59,13 @@ impl DynamicScene {
         builder.build()
     }
 
-    /// Write the dynamic entities and their corresponding components to the given world.
+    /// Write the resources, the dynamic entities and their corresponding components to the given
+    /// world.
     ///
     /// This method will return a [`SceneSpawnError`] if a type either is not registered
     /// in the provided [`AppTypeRegistry`] resource, or doesn't reflect the
-    /// [`Component`](bevy_ecs::component::Component) trait.
+    /// [`Component`](bevy_ecs::component::Component) or
+    /// [`Resource`](bevy_ecs::system::Resource) trait.
     pub fn write_to_world_with(
         &self,
         world: &mut World,
@@ -64,6 +74,23 @@ impl DynamicScene {
     ) -> Result<(), SceneSpawnError> {
         let type_registry = type_registry.read();
 
+        for resource in &self.resources {
+            let registration = type_registry
+                .get_with_name(resource.type_name())
+                .ok_or_else(|| SceneSpawnError::UnregisteredType {
+                    type_name: resource.type_name().to_string(),
+                })?;
+            let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+                SceneSpawnError::UnregisteredResource {
+                    type_name: resource.type_name().to_string(),
+                }
+            })?;
+
+            // If the world already contains an instance of the given resource,
+            // just apply the (possibly) new value, otherwise insert the resource.
+            reflect_resource.apply_or_insert(world, &**resource);
+        }
+
         for scene_entity in &self.entities {
             // Fetch the entity with the given entity id from the `entity_map`
             // or spawn a new entity with a transiently unique id if there is
@@ -102,6 +129,16 @@ impl DynamicScene {
             }
         }
 
+        // Entities referenced by the resources of the scene are mapped to the spawned entities.
+        for resource in &self.resources {
+            let map_entities_reflect = type_registry
+                .get_with_name(resource.type_name())
+                .and_then(|registration| registration.data::<ReflectMapEntitiesResource>());
+            if let Some(map_entities_reflect) = map_entities_reflect {
+                map_entities_reflect.map_entities(world, entity_map)?;
+            }
+        }
+
         Ok(())
     }
 
@@ -119,12 +156,27 @@ impl DynamicScene {
         self.write_to_world_with(world, entity_map, &registry)
     }
 
-    // TODO: move to AssetSaver when it is implemented
     /// Serialize this dynamic scene into rust object notation (ron).
+    ///
+    /// To save a dynamic scene asset to a file, use [`AssetServer::save`](bevy_asset::AssetServer::save)
+    /// with a `.scn.ron` path, which uses the [`SceneSaver`](crate::SceneSaver).
     #[cfg(feature = "serialize")]
     pub fn serialize_ron(&self, registry: &TypeRegistryArc) -> Result<String, ron::Error> {
         serialize_ron(SceneSerializer::new(self, registry))
     }
+
+    /// Serialize this dynamic scene into the compact binary scene format, see
+    /// [`serialize_binary_scene`](crate::serialize_binary_scene).
+    ///
+    /// To save a dynamic scene asset to a file, use [`AssetServer::save`](bevy_asset::AssetServer::save)
+    /// with a `.scn.bin` path, which uses the [`BinarySceneSaver`](crate::BinarySceneSaver).
+    #[cfg(feature = "serialize")]
+    pub fn serialize_binary(
+        &self,
+        registry: &TypeRegistryArc,
+    ) -> Result<Vec<u8>, crate::BinarySceneError> {
+        crate::serialize_binary_scene(self, registry)
+    }
 }
 
 /// Serialize a given Rust data structure into rust object notation (ron).
@@ -138,3 +190,61 @@ where
         .new_line("\n".to_string());
     ron::ser::to_string_pretty(&serialize, pretty_config)
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::AppTypeRegistry;
+    use bevy_ecs::{
+        entity::{Entity, EntityMap, MapEntities, MapEntitiesError},
+        reflect::{ReflectMapEntitiesResource, ReflectResource},
+        system::Resource,
+        world::World,
+    };
+    use bevy_reflect::Reflect;
+
+    use crate::DynamicSceneBuilder;
+
+    #[derive(Resource, Reflect, Debug)]
+    #[reflect(Resource, MapEntitiesResource)]
+    struct Target(Entity);
+
+    impl Default for Target {
+        fn default() -> Self {
+            Self(Entity::from_raw(u32::MAX))
+        }
+    }
+
+    impl MapEntities for Target {
+        fn map_entities(&mut self, entity_map: &EntityMap) -> Result<(), MapEntitiesError> {
+            self.0 = entity_map.get(self.0)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn resource_entities_are_mapped() {
+        let mut world = World::default();
+        let atr = AppTypeRegistry::default();
+        atr.write().register::<Target>();
+        world.insert_resource(atr.clone());
+        let target = world.spawn_empty().id();
+        world.insert_resource(Target(target));
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.extract_entity(target).extract_resources();
+        let scene = builder.build();
+
+        let mut destination = World::default();
+        destination.insert_resource(atr);
+        // offset the spawned entities
+        destination.spawn_empty();
+        let mut entity_map = EntityMap::default();
+        scene
+            .write_to_world(&mut destination, &mut entity_map)
+            .unwrap();
+
+        let spawned = entity_map.get(target).unwrap();
+        assert_ne!(spawned, target);
+        assert_eq!(destination.resource::<Target>().0, spawned);
+    }
+}
@@ -1,10 +1,17 @@
-use crate::{DynamicEntity, DynamicScene};
+use crate::{DynamicEntity, DynamicScene, SceneFilter};
 use bevy_app::AppTypeRegistry;
-use bevy_ecs::{prelude::Entity, reflect::ReflectComponent, world::World};
+use bevy_ecs::{
+    component::ComponentId,
+    prelude::Entity,
+    reflect::{ReflectComponent, ReflectResource},
+    world::World,
+};
+use bevy_reflect::Reflect;
 use bevy_utils::default;
-use std::collections::BTreeMap;
+use std::{any::Any, collections::BTreeMap};
 
-/// A [`DynamicScene`] builder, used to build a scene from a [`World`] by extracting some entities.
+/// A [`DynamicScene`] builder, used to build a scene from a [`World`] by extracting some entities
+/// and resources.
 ///
 /// # Entity Order
 ///
@@ -31,7 +38,10 @@ use std::collections::BTreeMap;
 /// let dynamic_scene = builder.build();
 /// ```
 pub struct DynamicSceneBuilder<'w> {
+    extracted_resources: BTreeMap<ComponentId, Box<dyn Reflect>>,
     extracted_scene: BTreeMap<u32, DynamicEntity>,
+    component_filter: SceneFilter,
+    resource_filter: SceneFilter,
     type_registry: AppTypeRegistry,
     original_world: &'w World,
 }
@@ -41,7 +51,10 @@ impl<'w> DynamicSceneBuilder<'w> {
     /// All components registered in that world's [`AppTypeRegistry`] resource will be extracted.
     pub fn from_world(world: &'w World) -> Self {
         Self {
+            extracted_resources: default(),
             extracted_scene: default(),
+            component_filter: default(),
+            resource_filter: default(),
             type_registry: world.resource::<AppTypeRegistry>().clone(),
             original_world: world,
         }
@@ -51,7 +64,10 @@ impl<'w> DynamicSceneBuilder<'w> {
     /// Only components registered in the given [`AppTypeRegistry`] will be extracted.
     pub fn from_world_with_type_registry(world: &'w World, type_registry: AppTypeRegistry) -> Self {
         Self {
+            extracted_resources: default(),
             extracted_scene: default(),
+            component_filter: default(),
+            resource_filter: default(),
             type_registry,
             original_world: world,
         }
@@ -63,10 +79,54 @@ impl<'w> DynamicSceneBuilder<'w> {
     /// [`Self::remove_empty_entities`] before building the scene.
     pub fn build(self) -> DynamicScene {
         DynamicScene {
+            resources: self.extracted_resources.into_values().collect(),
             entities: self.extracted_scene.into_values().collect(),
+            scenes: Vec::new(),
         }
     }
 
+    /// Sets the filter selecting the components extracted by [`Self::extract_entities`].
+    pub fn with_filter(&mut self, filter: SceneFilter) -> &mut Self {
+        self.component_filter = filter;
+        self
+    }
+
+    /// Allows the component `T` to be extracted by [`Self::extract_entities`].
+    ///
+    /// See [`SceneFilter::allow`]: unless a component was denied first, allowing a component
+    /// denies all the components which aren't explicitly allowed.
+    pub fn allow<T: Any>(&mut self) -> &mut Self {
+        self.component_filter = std::mem::take(&mut self.component_filter).allow::<T>();
+        self
+    }
+
+    /// Prevents the component `T` from being extracted by [`Self::extract_entities`].
+    pub fn deny<T: Any>(&mut self) -> &mut Self {
+        self.component_filter = std::mem::take(&mut self.component_filter).deny::<T>();
+        self
+    }
+
+    /// Sets the filter selecting the resources extracted by [`Self::extract_resources`].
+    pub fn with_resource_filter(&mut self, filter: SceneFilter) -> &mut Self {
+        self.resource_filter = filter;
+        self
+    }
+
+    /// Allows the resource `T` to be extracted by [`Self::extract_resources`].
+    ///
+    /// See [`SceneFilter::allow`]: unless a resource was denied first, allowing a resource denies
+    /// all the resources which aren't explicitly allowed.
+    pub fn allow_resource<T: Any>(&mut self) -> &mut Self {
+        self.resource_filter = std::mem::take(&mut self.resource_filter).allow::<T>();
+        self
+    }
+
+    /// Prevents the resource `T` from being extracted by [`Self::extract_resources`].
+    pub fn deny_resource<T: Any>(&mut self) -> &mut Self {
+        self.resource_filter = std::mem::take(&mut self.resource_filter).deny::<T>();
+        self
+    }
+
     /// Extract one entity from the builder's [`World`].
     ///
     /// Re-extracting an entity that was already extracted will have no effect.
@@ -84,7 +144,8 @@ impl<'w> DynamicSceneBuilder<'w> {
         self
     }
 
-    /// Extract entities from the builder's [`World`].
+    /// Extract entities from the builder's [`World`], with their components registered with
+    /// [`ReflectComponent`] and allowed by the component filter.
     ///
     /// Re-extracting an entity that was already extracted will have no effect.
     ///
@@ -131,6 +192,7 @@ impl<'w> DynamicSceneBuilder<'w> {
                     .components()
                     .get_info(component_id)
                     .and_then(|info| type_registry.get(info.type_id().unwrap()))
+                    .filter(|registration| self.component_filter.is_extracted(registration))
                     .and_then(|registration| registration.data::<ReflectComponent>())
                     .and_then(|reflect_component| reflect_component.reflect(entity));
 
@@ -144,18 +206,77 @@ impl<'w> DynamicSceneBuilder<'w> {
         drop(type_registry);
         self
     }
+
+    /// Extract the resources of the builder's [`World`] which are registered with
+    /// [`ReflectResource`] and allowed by the resource filter.
+    ///
+    /// Re-extracting a resource that was already extracted will update its value.
+    ///
+    /// ```
+    /// # use bevy_scene::DynamicSceneBuilder;
+    /// # use bevy_app::AppTypeRegistry;
+    /// # use bevy_ecs::prelude::{ReflectResource, Resource, World};
+    /// # use bevy_reflect::Reflect;
+    /// #[derive(Resource, Default, Reflect)]
+    /// #[reflect(Resource)]
+    /// struct Score(u32);
+    ///
+    /// # let mut world = World::default();
+    /// # world.init_resource::<AppTypeRegistry>();
+    /// # world.resource::<AppTypeRegistry>().write().register::<Score>();
+    /// world.insert_resource(Score(42));
+    ///
+    /// let mut builder = DynamicSceneBuilder::from_world(&world);
+    /// builder.allow_resource::<Score>().extract_resources();
+    /// let scene = builder.build();
+    /// assert_eq!(scene.resources.len(), 1);
+    /// ```
+    pub fn extract_resources(&mut self) -> &mut Self {
+        let type_registry = self.type_registry.read();
+
+        for (component_id, resource) in self.original_world.storages().resources.iter() {
+            if !resource.is_present() {
+                continue;
+            }
+            let Some(type_id) = self
+                .original_world
+                .components()
+                .get_info(component_id)
+                .and_then(|info| info.type_id())
+            else {
+                continue;
+            };
+            let resource = type_registry
+                .get(type_id)
+                .filter(|registration| self.resource_filter.is_extracted(registration))
+                .and_then(|registration| registration.data::<ReflectResource>())
+                .and_then(|reflect_resource| reflect_resource.reflect(self.original_world));
+            if let Some(resource) = resource {
+                self.extracted_resources
+                    .insert(component_id, resource.clone_value());
+            }
+        }
+
+        drop(type_registry);
+        self
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use bevy_app::AppTypeRegistry;
     use bevy_ecs::{
-        component::Component, prelude::Entity, query::With, reflect::ReflectComponent, world::World,
+        component::Component,
+        prelude::{Entity, Resource},
+        query::With,
+        reflect::{ReflectComponent, ReflectResource},
+        world::World,
     };
 
-    use bevy_reflect::Reflect;
+    use bevy_reflect::{Reflect, ReflectSkipSerializing};
 
     use super::DynamicSceneBuilder;
+    use crate::SceneFilter;
 
     #[derive(Component, Reflect, Default, Eq, PartialEq, Debug)]
     #[reflect(Component)]
@@ -164,6 +285,17 @@ mod tests {
     #[reflect(Component)]
     struct ComponentB;
 
+    #[derive(Component, Reflect, Default, Eq, PartialEq, Debug)]
+    #[reflect(Component, SkipSerializing)]
+    struct RuntimeComponent;
+
+    #[derive(Resource, Reflect, Default, Eq, PartialEq, Debug)]
+    #[reflect(Resource)]
+    struct ResourceA;
+    #[derive(Resource, Reflect, Default, Eq, PartialEq, Debug)]
+    #[reflect(Resource)]
+    struct ResourceB;
+
     #[test]
     fn extract_one_entity() {
         let mut world = World::default();
@@ -303,4 +435,108 @@ mod tests {
         assert_eq!(scene.entities.len(), 1);
         assert_eq!(scene.entities[0].entity, entity_a.index());
     }
+
+    #[test]
+    fn extract_resources() {
+        let mut world = World::default();
+
+        let atr = AppTypeRegistry::default();
+        {
+            let mut register = atr.write();
+            register.register::<ResourceA>();
+            register.register::<ResourceB>();
+        }
+        world.insert_resource(atr);
+        world.insert_resource(ResourceA);
+        world.insert_resource(ResourceB);
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.extract_resources();
+        builder.extract_resources();
+        let scene = builder.build();
+
+        assert_eq!(scene.resources.len(), 2);
+        assert!(scene.resources[0].represents::<ResourceA>());
+        assert!(scene.resources[1].represents::<ResourceB>());
+    }
+
+    #[test]
+    fn extract_filtered_resources() {
+        let mut world = World::default();
+
+        let atr = AppTypeRegistry::default();
+        {
+            let mut register = atr.write();
+            register.register::<ResourceA>();
+            register.register::<ResourceB>();
+        }
+        world.insert_resource(atr);
+        world.insert_resource(ResourceA);
+        world.insert_resource(ResourceB);
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.allow_resource::<ResourceB>().extract_resources();
+        let scene = builder.build();
+        assert_eq!(scene.resources.len(), 1);
+        assert!(scene.resources[0].represents::<ResourceB>());
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.deny_resource::<ResourceB>().extract_resources();
+        let scene = builder.build();
+        assert_eq!(scene.resources.len(), 1);
+        assert!(scene.resources[0].represents::<ResourceA>());
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder
+            .with_resource_filter(SceneFilter::deny_all())
+            .extract_resources();
+        assert!(builder.build().resources.is_empty());
+    }
+
+    #[test]
+    fn extract_filtered_components() {
+        let mut world = World::default();
+
+        let atr = AppTypeRegistry::default();
+        {
+            let mut register = atr.write();
+            register.register::<ComponentA>();
+            register.register::<ComponentB>();
+            register.register::<RuntimeComponent>();
+        }
+        world.insert_resource(atr);
+
+        let entity = world.spawn((ComponentA, ComponentB, RuntimeComponent)).id();
+
+        // types registered with `ReflectSkipSerializing` are skipped by default
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.extract_entity(entity);
+        let scene = builder.build();
+        assert_eq!(scene.entities[0].components.len(), 2);
+        assert!(scene.entities[0].components[0].represents::<ComponentA>());
+        assert!(scene.entities[0].components[1].represents::<ComponentB>());
+
+        // ...even by a filter allowing every type
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder
+            .with_filter(SceneFilter::allow_all())
+            .extract_entity(entity);
+        assert_eq!(builder.build().entities[0].components.len(), 2);
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.deny::<ComponentA>().extract_entity(entity);
+        let scene = builder.build();
+        assert_eq!(scene.entities[0].components.len(), 1);
+        assert!(scene.entities[0].components[0].represents::<ComponentB>());
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder
+            .allow::<ComponentA>()
+            .allow::<RuntimeComponent>()
+            .extract_entity(entity);
+        let scene = builder.build();
+        assert_eq!(scene.entities[0].components.len(), 2);
+        assert!(scene.entities[0].components[0].represents::<ComponentA>());
+        assert!(scene.entities[0].components[1].represents::<RuntimeComponent>());
+    }
 }
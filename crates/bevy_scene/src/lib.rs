@@ -1,18 +1,28 @@
+#[cfg(feature = "serialize")]
+mod binary_scene;
 mod bundle;
 mod dynamic_scene;
 mod dynamic_scene_builder;
+mod nested_scene;
 mod scene;
+mod scene_filter;
 mod scene_loader;
+mod scene_saver;
 mod scene_spawner;
 
 #[cfg(feature = "serialize")]
 pub mod serde;
 
+#[cfg(feature = "serialize")]
+pub use binary_scene::*;
 pub use bundle::*;
 pub use dynamic_scene::*;
 pub use dynamic_scene_builder::*;
+pub use nested_scene::*;
 pub use scene::*;
+pub use scene_filter::*;
 pub use scene_loader::*;
+pub use scene_saver::*;
 pub use scene_spawner::*;
 
 pub mod prelude {
@@ -35,7 +45,13 @@ impl Plugin for ScenePlugin {
         app.add_asset::<DynamicScene>()
             .add_asset::<Scene>()
             .init_asset_loader::<SceneLoader>()
+            .init_asset_loader::<BinarySceneLoader>()
+            .init_asset_saver::<SceneSaver>()
+            .init_asset_saver::<BinarySceneSaver>()
             .init_resource::<SceneSpawner>()
+            .add_event::<SceneInstanceReady>()
+            .add_event::<SceneInstanceDespawned>()
+            .add_event::<SceneInstanceSpawnFailed>()
             .add_system(scene_spawner_system)
             // Systems `*_bundle_spawner` must run before `scene_spawner_system`
             .add_system(scene_spawner.in_base_set(CoreSet::PreUpdate));
@@ -0,0 +1,507 @@
+use crate::{DynamicEntity, DynamicScene, SceneSpawnError};
+use bevy_asset::{AssetPath, Assets, Handle, HandleId};
+use bevy_core::Name;
+use bevy_ecs::entity::Entity;
+use bevy_hierarchy::{Children, Parent};
+use bevy_reflect::{DynamicList, DynamicTupleStruct, FromReflect, Reflect, ReflectMut, ReflectRef};
+use bevy_utils::HashMap;
+use std::any::type_name;
+
+/// An instance of another [`DynamicScene`] nested in a dynamic scene, such as a prefab.
+///
+/// When the scene is spawned, the entities of the nested scene are spawned with it, with the
+/// [overrides](NestedScene::overrides) applied to them. Changes to the nested scene asset are
+/// propagated to the spawned instances of the scenes nesting it.
+///
+/// Nested scenes are saved in a `scenes` list next to the `entities` of the scene, rather than as
+/// a component of an entity such as `child: Scene("enemies/goblin.scn.ron")`:
+///
+/// ```ron
+/// (
+///   entities: {
+///     0: (components: {"bevy_core::name::Name": (name: "Camp")}),
+///   },
+///   scenes: [
+///     (
+///       path: "enemies/goblin.scn.ron",
+///       parent: Some(0),
+///       overrides: {
+///         "Goblin/Weapon": {"game::Damage": (5)},
+///       },
+///     ),
+///   ],
+/// )
+/// ```
+///
+/// A nested scene isn't an entity: it may have several root entities, or none, and its entities
+/// are only known once it's loaded. Entity components are also deserialized by their registered
+/// types, which a scene path isn't, and the overrides apply to entities of the nested scene
+/// rather than to an entity of the nesting scene. The `parent` entity gives the same hierarchy as
+/// the component would.
+pub struct NestedScene {
+    /// The path the nested scene is loaded from, which is how it is saved.
+    pub path: AssetPath<'static>,
+    /// The nested scene.
+    ///
+    /// The [`SceneLoader`](crate::SceneLoader) sets it from [`NestedScene::path`], and loads the
+    /// nested scene as a dependency of the scene nesting it.
+    pub scene: Handle<DynamicScene>,
+    /// The entity of the nesting scene the root entities of the nested scene are children of, if
+    /// any.
+    pub parent: Option<u32>,
+    /// Components applied to entities of the nested scene.
+    pub overrides: Vec<SceneOverride>,
+}
+
+/// Components applied to an entity of a [`NestedScene`].
+pub struct SceneOverride {
+    /// The path of the entity in the nested scene, made of the [`Name`]s of the entities from a
+    /// root entity of the nested scene to the entity, separated by `/`.
+    pub entity_path: String,
+    /// The components applied to the entity, which replace its components of the same types.
+    pub components: Vec<Box<dyn Reflect>>,
+}
+
+impl NestedScene {
+    /// Creates a nested instance of the scene loaded from `path`.
+    pub fn new<'a>(path: impl Into<AssetPath<'a>>, scene: Handle<DynamicScene>) -> Self {
+        Self {
+            path: path.into().to_owned(),
+            scene,
+            parent: None,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Makes the root entities of the nested scene children of the given entity of the nesting
+    /// scene.
+    #[must_use]
+    pub fn with_parent(mut self, parent: u32) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    /// Applies the given components to the entity at `entity_path` in the nested scene, see
+    /// [`SceneOverride::entity_path`].
+    #[must_use]
+    pub fn with_override(
+        mut self,
+        entity_path: impl Into<String>,
+        components: Vec<Box<dyn Reflect>>,
+    ) -> Self {
+        self.overrides.push(SceneOverride {
+            entity_path: entity_path.into(),
+            components,
+        });
+        self
+    }
+}
+
+impl DynamicScene {
+    /// Returns a copy of this scene with its nested scenes, and theirs, replaced by their entities.
+    ///
+    /// The entities of nested scenes get ids following the ids of the nesting scene, the entities
+    /// they reference are mapped accordingly, and the overrides are applied. Resources of nested
+    /// scenes are included unless the nesting scene has a resource of the same type.
+    ///
+    /// Returns [`SceneSpawnError::NonExistentScene`] if a nested scene isn't loaded.
+    pub fn flatten(&self, scenes: &Assets<DynamicScene>) -> Result<DynamicScene, SceneSpawnError> {
+        flatten(self, scenes, &mut Vec::new())
+    }
+
+    /// Returns `true` if the scene with the given handle is nested in this scene, directly or in
+    /// one of its nested scenes.
+    pub fn nests(&self, handle: &Handle<DynamicScene>, scenes: &Assets<DynamicScene>) -> bool {
+        nests(self, handle.id(), scenes, &mut Vec::new())
+    }
+}
+
+fn nests(
+    scene: &DynamicScene,
+    handle_id: HandleId,
+    scenes: &Assets<DynamicScene>,
+    visited: &mut Vec<HandleId>,
+) -> bool {
+    scene.scenes.iter().any(|nested| {
+        let nested_id = nested.scene.id();
+        if nested_id == handle_id {
+            return true;
+        }
+        if visited.contains(&nested_id) {
+            return false;
+        }
+        visited.push(nested_id);
+        matches!(
+            scenes.get(&nested.scene),
+            Some(nested_scene) if nests(nested_scene, handle_id, scenes, visited)
+        )
+    })
+}
+
+fn flatten(
+    scene: &DynamicScene,
+    scenes: &Assets<DynamicScene>,
+    ancestors: &mut Vec<HandleId>,
+) -> Result<DynamicScene, SceneSpawnError> {
+    let mut flattened = DynamicScene {
+        resources: scene
+            .resources
+            .iter()
+            .map(|resource| resource.clone_value())
+            .collect(),
+        entities: scene
+            .entities
+            .iter()
+            .map(|entity| DynamicEntity {
+                entity: entity.entity,
+                components: entity
+                    .components
+                    .iter()
+                    .map(|component| component.clone_value())
+                    .collect(),
+            })
+            .collect(),
+        scenes: Vec::new(),
+    };
+    let mut next_id = scene
+        .entities
+        .iter()
+        .map(|entity| entity.entity + 1)
+        .max()
+        .unwrap_or(0);
+
+    for nested in &scene.scenes {
+        let nested_id = nested.scene.id();
+        if ancestors.contains(&nested_id) {
+            return Err(SceneSpawnError::RecursiveNestedScene {
+                path: nested.path.to_string(),
+            });
+        }
+        let nested_scene =
+            scenes
+                .get(&nested.scene)
+                .ok_or_else(|| SceneSpawnError::NonExistentScene {
+                    handle: nested.scene.clone_weak(),
+                })?;
+        ancestors.push(nested_id);
+        let mut nested_scene = flatten(nested_scene, scenes, ancestors)?;
+        ancestors.pop();
+
+        let ids: HashMap<u32, u32> = nested_scene
+            .entities
+            .iter()
+            .map(|entity| {
+                next_id += 1;
+                (entity.entity, next_id - 1)
+            })
+            .collect();
+        for entity in &mut nested_scene.entities {
+            entity.entity = ids[&entity.entity];
+            for component in &mut entity.components {
+                map_reflect_entities(&mut **component, &ids);
+            }
+        }
+        for resource in &mut nested_scene.resources {
+            map_reflect_entities(&mut **resource, &ids);
+        }
+
+        for scene_override in &nested.overrides {
+            let entity = find_entity(&nested_scene.entities, &scene_override.entity_path)
+                .ok_or_else(|| SceneSpawnError::NonExistentNestedEntity {
+                    scene_path: nested.path.to_string(),
+                    entity_path: scene_override.entity_path.clone(),
+                })?;
+            let components = &mut nested_scene.entities[entity].components;
+            for patch in &scene_override.components {
+                match components
+                    .iter_mut()
+                    .find(|component| component.type_name() == patch.type_name())
+                {
+                    Some(component) => component.apply(&**patch),
+                    None => components.push(patch.clone_value()),
+                }
+            }
+        }
+
+        if let Some(parent) = nested.parent {
+            let roots: Vec<u32> = nested_scene
+                .entities
+                .iter_mut()
+                .filter(|entity| parent_of(entity).is_none())
+                .map(|entity| {
+                    let mut parent_component = DynamicTupleStruct::default();
+                    parent_component.set_name(type_name::<Parent>().to_string());
+                    parent_component.insert(Entity::from_raw(parent));
+                    entity.components.push(Box::new(parent_component));
+                    entity.entity
+                })
+                .collect();
+            let parent_entity = flattened
+                .entities
+                .iter_mut()
+                .find(|entity| entity.entity == parent)
+                .ok_or_else(|| SceneSpawnError::NonExistentNestedSceneParent {
+                    scene_path: nested.path.to_string(),
+                    parent,
+                })?;
+            add_children(parent_entity, roots);
+        }
+
+        for resource in nested_scene.resources {
+            if !flattened
+                .resources
+                .iter()
+                .any(|existing| existing.type_name() == resource.type_name())
+            {
+                flattened.resources.push(resource);
+            }
+        }
+        flattened.entities.extend(nested_scene.entities);
+    }
+
+    Ok(flattened)
+}
+
+/// Replaces the entities with the ids of `ids` referenced by `value` by the entities with the
+/// mapped ids.
+fn map_reflect_entities(value: &mut dyn Reflect, ids: &HashMap<u32, u32>) {
+    match value.reflect_mut() {
+        ReflectMut::Struct(value) => {
+            for i in 0..value.field_len() {
+                map_reflect_entities(value.field_at_mut(i).unwrap(), ids);
+            }
+        }
+        ReflectMut::TupleStruct(value) => {
+            for i in 0..value.field_len() {
+                map_reflect_entities(value.field_mut(i).unwrap(), ids);
+            }
+        }
+        ReflectMut::Tuple(value) => {
+            for i in 0..value.field_len() {
+                map_reflect_entities(value.field_mut(i).unwrap(), ids);
+            }
+        }
+        ReflectMut::List(value) => {
+            for i in 0..value.len() {
+                map_reflect_entities(value.get_mut(i).unwrap(), ids);
+            }
+        }
+        ReflectMut::Array(value) => {
+            for i in 0..value.len() {
+                map_reflect_entities(value.get_mut(i).unwrap(), ids);
+            }
+        }
+        ReflectMut::Map(value) => {
+            let keys: Vec<Box<dyn Reflect>> =
+                value.iter().map(|(key, _)| key.clone_value()).collect();
+            for key in keys {
+                map_reflect_entities(value.get_mut(&*key).unwrap(), ids);
+            }
+        }
+        ReflectMut::Enum(value) => {
+            for i in 0..value.field_len() {
+                map_reflect_entities(value.field_at_mut(i).unwrap(), ids);
+            }
+        }
+        ReflectMut::Value(value) => {
+            if let Some(entity) = value.downcast_mut::<Entity>() {
+                if let Some(id) = ids.get(&entity.index()) {
+                    *entity = Entity::from_raw(*id);
+                }
+            }
+        }
+    }
+}
+
+fn component<T: 'static>(entity: &DynamicEntity) -> Option<&dyn Reflect> {
+    entity
+        .components
+        .iter()
+        .find(|component| component.type_name() == type_name::<T>())
+        .map(|component| &**component)
+}
+
+fn name_of(entity: &DynamicEntity) -> Option<Name> {
+    component::<Name>(entity).and_then(Name::from_reflect)
+}
+
+fn parent_of(entity: &DynamicEntity) -> Option<u32> {
+    match component::<Parent>(entity)?.reflect_ref() {
+        ReflectRef::TupleStruct(parent) => parent
+            .field(0)?
+            .downcast_ref::<Entity>()
+            .map(|parent| parent.index()),
+        _ => None,
+    }
+}
+
+/// Returns the index of the entity at the given path of names.
+fn find_entity(entities: &[DynamicEntity], entity_path: &str) -> Option<usize> {
+    let mut parent = None;
+    let mut found = None;
+    for name in entity_path.split('/') {
+        let index = entities.iter().position(|entity| {
+            parent_of(entity) == parent && name_of(entity).as_ref().map(Name::as_str) == Some(name)
+        })?;
+        parent = Some(entities[index].entity);
+        found = Some(index);
+    }
+    found
+}
+
+fn add_children(parent: &mut DynamicEntity, children: Vec<u32>) {
+    let existing = parent
+        .components
+        .iter_mut()
+        .find(|component| component.type_name() == type_name::<Children>());
+    let children = children.into_iter().map(Entity::from_raw);
+    if let Some(existing) = existing {
+        if let ReflectMut::TupleStruct(existing) = existing.reflect_mut() {
+            if let Some(ReflectMut::List(list)) = existing.field_mut(0).map(Reflect::reflect_mut) {
+                for child in children {
+                    list.push(Box::new(child));
+                }
+            }
+        }
+    } else {
+        let mut list = DynamicList::default();
+        for child in children {
+            list.push(child);
+        }
+        let mut children_component = DynamicTupleStruct::default();
+        children_component.set_name(type_name::<Children>().to_string());
+        children_component.insert(list);
+        parent.components.push(Box::new(children_component));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DynamicEntity, DynamicScene, NestedScene, SceneSpawnError};
+    use bevy_app::{App, AppTypeRegistry};
+    use bevy_asset::{AddAsset, AssetPlugin, Assets};
+    use bevy_core::Name;
+    use bevy_ecs::{entity::EntityMap, prelude::*, reflect::ReflectComponent};
+    use bevy_hierarchy::{BuildWorldChildren, Children, Parent};
+    use bevy_reflect::Reflect;
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Health>();
+            registry.register::<Name>();
+            registry.register::<Parent>();
+            registry.register::<Children>();
+        }
+        world.insert_resource(registry);
+        world
+    }
+
+    /// A goblin with a weapon child.
+    fn goblin() -> DynamicScene {
+        let mut world = create_world();
+        world
+            .spawn((Name::new("Goblin"), Health(10)))
+            .with_children(|goblin| {
+                goblin.spawn((Name::new("Weapon"), Health(1)));
+            });
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        DynamicScene::from_world(&world, &registry)
+    }
+
+    #[test]
+    fn flatten_nested_scenes() {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_asset::<DynamicScene>();
+        let mut scenes = app.world.resource_mut::<Assets<DynamicScene>>();
+        let goblin = scenes.add(goblin());
+        let level = DynamicScene {
+            entities: vec![DynamicEntity {
+                entity: 0,
+                components: vec![Box::new(Name::new("Level"))],
+            }],
+            scenes: vec![NestedScene::new("goblin.scn.ron", goblin.clone())
+                .with_parent(0)
+                .with_override("Goblin/Weapon", vec![Box::new(Health(5))])],
+            ..Default::default()
+        };
+
+        let flattened = level.flatten(&scenes).unwrap();
+        assert_eq!(flattened.entities.len(), 3);
+
+        let mut world = create_world();
+        let mut entity_map = EntityMap::default();
+        flattened
+            .write_to_world(&mut world, &mut entity_map)
+            .unwrap();
+        let mut query = world.query::<(Entity, &Name, Option<&Parent>, Option<&Health>)>();
+        let mut find = |name: &str| {
+            query
+                .iter(&world)
+                .find(|(_, entity_name, ..)| entity_name.as_str() == name)
+                .map(|(entity, _, parent, health)| {
+                    (
+                        entity,
+                        parent.map(Parent::get),
+                        health.map(|health| health.0),
+                    )
+                })
+                .unwrap()
+        };
+        let (level, ..) = find("Level");
+        let (goblin_entity, goblin_parent, goblin_health) = find("Goblin");
+        let (_, weapon_parent, weapon_health) = find("Weapon");
+        assert_eq!(goblin_parent, Some(level));
+        assert_eq!(goblin_health, Some(10));
+        assert_eq!(weapon_parent, Some(goblin_entity));
+        assert_eq!(weapon_health, Some(5));
+        assert_eq!(&**world.get::<Children>(level).unwrap(), &[goblin_entity]);
+    }
+
+    #[test]
+    fn missing_override_target() {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_asset::<DynamicScene>();
+        let mut scenes = app.world.resource_mut::<Assets<DynamicScene>>();
+        let goblin = scenes.add(goblin());
+        let level = DynamicScene {
+            scenes: vec![NestedScene::new("goblin.scn.ron", goblin.clone())
+                .with_override("Goblin/Shield", vec![Box::new(Health(5))])],
+            ..Default::default()
+        };
+
+        assert!(level.nests(&goblin, &scenes));
+        assert!(matches!(
+            level.flatten(&scenes),
+            Err(SceneSpawnError::NonExistentNestedEntity { .. })
+        ));
+    }
+
+    #[test]
+    fn recursive_nested_scenes_are_rejected() {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_asset::<DynamicScene>();
+        let mut scenes = app.world.resource_mut::<Assets<DynamicScene>>();
+        let handle = scenes.add(DynamicScene::default());
+        let recursive = DynamicScene {
+            scenes: vec![NestedScene::new("recursive.scn.ron", handle.clone())],
+            ..Default::default()
+        };
+        scenes.set_untracked(handle.clone(), recursive);
+
+        let scene = scenes.get(&handle).unwrap();
+        assert!(matches!(
+            scene.flatten(&scenes),
+            Err(SceneSpawnError::RecursiveNestedScene { .. })
+        ));
+    }
+}
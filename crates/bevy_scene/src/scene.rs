@@ -1,10 +1,10 @@
 use bevy_app::AppTypeRegistry;
 use bevy_ecs::{
     entity::EntityMap,
-    reflect::{ReflectComponent, ReflectMapEntities},
+    reflect::{ReflectComponent, ReflectMapEntities, ReflectMapEntitiesResource, ReflectResource},
     world::World,
 };
-use bevy_reflect::TypeUuid;
+use bevy_reflect::{TypePath, TypeUuid};
 
 use crate::{DynamicScene, InstanceInfo, SceneSpawnError};
 
@@ -14,7 +14,7 @@ use crate::{DynamicScene, InstanceInfo, SceneSpawnError};
 /// * adding the [`Handle<Scene>`](bevy_asset::Handle) to an entity (the scene will only be
 /// visible if the entity already has [`Transform`](bevy_transform::components::Transform) and
 /// [`GlobalTransform`](bevy_transform::components::GlobalTransform) components)
-#[derive(Debug, TypeUuid)]
+#[derive(Debug, TypePath, TypeUuid)]
 #[uuid = "c156503c-edd9-4ec7-8d33-dab392df03cd"]
 pub struct Scene {
     pub world: World,
@@ -47,10 +47,11 @@ impl Scene {
         Ok(Self { world: new_world })
     }
 
-    /// Write the entities and their corresponding components to the given world.
+    /// Write the resources, the entities and their corresponding components to the given world.
     ///
     /// This method will return a [`SceneSpawnError`] if a type either is not registered in the
-    /// provided [`AppTypeRegistry`] or doesn't reflect the [`Component`](bevy_ecs::component::Component) trait.
+    /// provided [`AppTypeRegistry`] or doesn't reflect the [`Component`](bevy_ecs::component::Component)
+    /// or [`Resource`](bevy_ecs::system::Resource) trait.
     pub fn write_to_world_with(
         &self,
         world: &mut World,
@@ -59,12 +60,52 @@ impl Scene {
         let mut instance_info = InstanceInfo {
             entity_map: EntityMap::default(),
         };
+        self.write_to_world_with_entity_map(world, &mut instance_info.entity_map, type_registry)?;
+        Ok(instance_info)
+    }
 
+    /// Write the resources, the entities and their corresponding components to the given world,
+    /// like [`Self::write_to_world_with`] does, reusing the entities of the world that
+    /// `entity_map` maps the entities of the scene to.
+    ///
+    /// Scene entities missing from `entity_map` are spawned and added to it.
+    pub fn write_to_world_with_entity_map(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityMap,
+        type_registry: &AppTypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
         let type_registry = type_registry.read();
+
+        let mut map_entities_resources = Vec::new();
+        for (component_id, resource) in self.world.storages().resources.iter() {
+            if !resource.is_present() {
+                continue;
+            }
+            let component_info = self
+                .world
+                .components()
+                .get_info(component_id)
+                .expect("component_ids in resources should have ComponentInfo");
+
+            let registration = component_info
+                .type_id()
+                .and_then(|type_id| type_registry.get(type_id))
+                .ok_or_else(|| SceneSpawnError::UnregisteredType {
+                    type_name: component_info.name().to_string(),
+                })?;
+            let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+                SceneSpawnError::UnregisteredResource {
+                    type_name: component_info.name().to_string(),
+                }
+            })?;
+            reflect_resource.copy(&self.world, world);
+            map_entities_resources.extend(registration.data::<ReflectMapEntitiesResource>());
+        }
+
         for archetype in self.world.archetypes().iter() {
             for scene_entity in archetype.entities() {
-                let entity = *instance_info
-                    .entity_map
+                let entity = *entity_map
                     .entry(scene_entity.entity())
                     .or_insert_with(|| world.spawn_empty().id());
                 for component_id in archetype.components() {
@@ -93,11 +134,14 @@ impl Scene {
         for registration in type_registry.iter() {
             if let Some(map_entities_reflect) = registration.data::<ReflectMapEntities>() {
                 map_entities_reflect
-                    .map_entities(world, &instance_info.entity_map)
+                    .map_entities(world, entity_map)
                     .unwrap();
             }
         }
+        for map_entities_reflect in map_entities_resources {
+            map_entities_reflect.map_entities(world, entity_map)?;
+        }
 
-        Ok(instance_info)
+        Ok(())
     }
 }
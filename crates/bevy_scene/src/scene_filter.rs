@@ -0,0 +1,153 @@
+use bevy_reflect::{ReflectSkipSerializing, TypeRegistration};
+use bevy_utils::HashSet;
+use std::any::{Any, TypeId};
+
+/// A filter selecting the types extracted into a [`DynamicScene`](crate::DynamicScene) by a
+/// [`DynamicSceneBuilder`](crate::DynamicSceneBuilder).
+///
+/// A filter either allows every type except the ones it denies, or denies every type except the
+/// ones it allows, depending on whether [`SceneFilter::deny`] or [`SceneFilter::allow`] was called
+/// first.
+///
+/// Types registered with [`ReflectSkipSerializing`], using `#[reflect(SkipSerializing)]`, are
+/// only extracted if they are explicitly allowed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SceneFilter {
+    /// No type was allowed or denied yet: every type is allowed.
+    #[default]
+    Unset,
+    /// Only the listed types are allowed.
+    Allowlist(HashSet<TypeId>),
+    /// Every type except the listed ones is allowed.
+    Denylist(HashSet<TypeId>),
+}
+
+impl SceneFilter {
+    /// Creates a filter allowing every type.
+    ///
+    /// Like an [unset](SceneFilter::Unset) filter, it doesn't extract types registered with
+    /// [`ReflectSkipSerializing`], although [`SceneFilter::is_allowed`] returns `true` for them.
+    /// Only an [allowlist](SceneFilter::Allowlist) listing them extracts them.
+    pub fn allow_all() -> Self {
+        Self::Denylist(HashSet::default())
+    }
+
+    /// Creates a filter denying every type.
+    pub fn deny_all() -> Self {
+        Self::Allowlist(HashSet::default())
+    }
+
+    /// Allows the type `T`.
+    ///
+    /// An [unset](SceneFilter::Unset) filter becomes an allowlist denying every other type. A
+    /// [denylist](SceneFilter::Denylist) stops denying `T`.
+    #[must_use]
+    pub fn allow<T: Any>(self) -> Self {
+        self.allow_by_id(TypeId::of::<T>())
+    }
+
+    /// Allows the type with the given [`TypeId`], see [`SceneFilter::allow`].
+    #[must_use]
+    pub fn allow_by_id(mut self, type_id: TypeId) -> Self {
+        match &mut self {
+            Self::Allowlist(list) => {
+                list.insert(type_id);
+            }
+            Self::Denylist(list) => {
+                list.remove(&type_id);
+            }
+            Self::Unset => {
+                self = Self::Allowlist(HashSet::from_iter([type_id]));
+            }
+        }
+        self
+    }
+
+    /// Denies the type `T`.
+    ///
+    /// An [unset](SceneFilter::Unset) filter becomes a denylist allowing every other type. An
+    /// [allowlist](SceneFilter::Allowlist) stops allowing `T`.
+    #[must_use]
+    pub fn deny<T: Any>(self) -> Self {
+        self.deny_by_id(TypeId::of::<T>())
+    }
+
+    /// Denies the type with the given [`TypeId`], see [`SceneFilter::deny`].
+    #[must_use]
+    pub fn deny_by_id(mut self, type_id: TypeId) -> Self {
+        match &mut self {
+            Self::Allowlist(list) => {
+                list.remove(&type_id);
+            }
+            Self::Denylist(list) => {
+                list.insert(type_id);
+            }
+            Self::Unset => {
+                self = Self::Denylist(HashSet::from_iter([type_id]));
+            }
+        }
+        self
+    }
+
+    /// Returns `true` if the type `T` is allowed by this filter.
+    pub fn is_allowed<T: Any>(&self) -> bool {
+        self.is_allowed_by_id(TypeId::of::<T>())
+    }
+
+    /// Returns `true` if the type with the given [`TypeId`] is allowed by this filter.
+    pub fn is_allowed_by_id(&self, type_id: TypeId) -> bool {
+        match self {
+            Self::Unset => true,
+            Self::Allowlist(list) => list.contains(&type_id),
+            Self::Denylist(list) => !list.contains(&type_id),
+        }
+    }
+
+    /// Returns `true` if values of the registered type should be extracted into a scene, which
+    /// requires types registered with [`ReflectSkipSerializing`] to be explicitly allowed.
+    pub(crate) fn is_extracted(&self, registration: &TypeRegistration) -> bool {
+        match self {
+            Self::Allowlist(list) => list.contains(&registration.type_id()),
+            Self::Unset | Self::Denylist(_) => {
+                self.is_allowed_by_id(registration.type_id())
+                    && registration.data::<ReflectSkipSerializing>().is_none()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SceneFilter;
+
+    #[test]
+    fn allow_and_deny() {
+        assert!(SceneFilter::Unset.is_allowed::<u8>());
+        assert!(SceneFilter::allow_all().is_allowed::<u8>());
+        assert!(!SceneFilter::deny_all().is_allowed::<u8>());
+
+        let filter = SceneFilter::Unset.allow::<u8>().allow::<u16>();
+        assert!(filter.is_allowed::<u8>());
+        assert!(filter.is_allowed::<u16>());
+        assert!(!filter.is_allowed::<u32>());
+        assert!(!filter.deny::<u8>().is_allowed::<u8>());
+
+        let filter = SceneFilter::Unset.deny::<u8>();
+        assert!(!filter.is_allowed::<u8>());
+        assert!(filter.is_allowed::<u16>());
+        assert!(filter.allow::<u8>().is_allowed::<u8>());
+
+        // allowing a type in a filter allowing everything keeps allowing the other types
+        let filter = SceneFilter::allow_all().allow::<u8>();
+        assert_eq!(filter, SceneFilter::allow_all());
+        assert!(filter.is_allowed::<u16>());
+
+        let filter = SceneFilter::allow_all()
+            .deny::<u8>()
+            .allow::<u8>()
+            .allow::<u16>();
+        assert_eq!(filter, SceneFilter::allow_all());
+        assert!(filter.is_allowed::<u8>());
+        assert!(filter.is_allowed::<u32>());
+    }
+}
@@ -1,15 +1,17 @@
-use crate::serde::SceneDeserializer;
+#[cfg(feature = "serialize")]
+use crate::deserialize_binary_scene;
+use crate::{serde::SceneDeserializer, DynamicScene};
 use anyhow::Result;
 use bevy_app::AppTypeRegistry;
 use bevy_asset::{AssetLoader, LoadContext, LoadedAsset};
 use bevy_ecs::world::{FromWorld, World};
-use bevy_reflect::TypeRegistryArc;
-use bevy_utils::BoxedFuture;
+use bevy_reflect::{TypePath, TypeRegistryArc, TypeUuid};
+use bevy_utils::{BoxedFuture, Uuid};
 
 #[cfg(feature = "serialize")]
 use serde::de::DeserializeSeed;
 
-#[derive(Debug)]
+#[derive(Debug, TypePath)]
 pub struct SceneLoader {
     type_registry: TypeRegistryArc,
 }
@@ -23,6 +25,19 @@ impl FromWorld for SceneLoader {
     }
 }
 
+/// Sets the handles of the scenes nested in a loaded scene, which are loaded as its dependencies.
+fn load_nested_scenes(
+    mut scene: DynamicScene,
+    load_context: &LoadContext,
+) -> LoadedAsset<DynamicScene> {
+    let mut dependencies = Vec::new();
+    for nested in &mut scene.scenes {
+        nested.scene = load_context.get_handle(nested.path.clone());
+        dependencies.push(nested.path.clone());
+    }
+    LoadedAsset::new(scene).with_dependencies(dependencies)
+}
+
 #[cfg(feature = "serialize")]
 impl AssetLoader for SceneLoader {
     fn load<'a>(
@@ -36,7 +51,7 @@ impl AssetLoader for SceneLoader {
                 type_registry: &self.type_registry.read(),
             };
             let scene = scene_deserializer.deserialize(&mut deserializer)?;
-            load_context.set_default_asset(LoadedAsset::new(scene));
+            load_context.set_default_asset(load_nested_scenes(scene, load_context));
             Ok(())
         })
     }
@@ -44,4 +59,51 @@ impl AssetLoader for SceneLoader {
     fn extensions(&self) -> &[&str] {
         &["scn", "scn.ron"]
     }
+
+    fn asset_type_uuid(&self) -> Option<Uuid> {
+        Some(DynamicScene::TYPE_UUID)
+    }
+}
+
+/// Loads [`DynamicScene`]s from `.scn.bin` files, in the binary format written by the
+/// [`BinarySceneSaver`](crate::BinarySceneSaver).
+#[derive(Debug, TypePath)]
+pub struct BinarySceneLoader {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneLoader {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneLoader {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl AssetLoader for BinarySceneLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let scene = deserialize_binary_scene(bytes, &self.type_registry.read())?;
+            load_context.set_default_asset(load_nested_scenes(scene, load_context));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["scn.bin"]
+    }
+
+    fn asset_type_uuid(&self) -> Option<Uuid> {
+        Some(DynamicScene::TYPE_UUID)
+    }
+
+    fn magic_bytes(&self) -> &[&[u8]] {
+        &[&crate::BINARY_SCENE_MAGIC]
+    }
 }
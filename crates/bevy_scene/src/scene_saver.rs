@@ -0,0 +1,66 @@
+use crate::DynamicScene;
+use anyhow::Result;
+use bevy_app::AppTypeRegistry;
+use bevy_asset::AssetSaver;
+use bevy_ecs::world::{FromWorld, World};
+use bevy_reflect::TypeRegistryArc;
+use std::path::Path;
+
+/// Saves [`DynamicScene`]s to `.scn.ron` files, which can be loaded back with the
+/// [`SceneLoader`](crate::SceneLoader).
+#[derive(Debug)]
+pub struct SceneSaver {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for SceneSaver {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        SceneSaver {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl AssetSaver for SceneSaver {
+    type Asset = DynamicScene;
+
+    fn save(&self, scene: &DynamicScene, _path: &Path) -> Result<Vec<u8>> {
+        Ok(scene.serialize_ron(&self.type_registry)?.into_bytes())
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["scn", "scn.ron"]
+    }
+}
+
+/// Saves [`DynamicScene`]s to `.scn.bin` files, in the compact binary format described in
+/// [`serialize_binary_scene`](crate::serialize_binary_scene), which can be loaded back with the
+/// [`BinarySceneLoader`](crate::BinarySceneLoader).
+#[derive(Debug)]
+pub struct BinarySceneSaver {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneSaver {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneSaver {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl AssetSaver for BinarySceneSaver {
+    type Asset = DynamicScene;
+
+    fn save(&self, scene: &DynamicScene, _path: &Path) -> Result<Vec<u8>> {
+        Ok(scene.serialize_binary(&self.type_registry)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["scn.bin"]
+    }
+}
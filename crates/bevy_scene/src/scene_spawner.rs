@@ -1,14 +1,18 @@
 use crate::{DynamicScene, Scene};
 use bevy_app::AppTypeRegistry;
-use bevy_asset::{AssetEvent, Assets, Handle};
+use bevy_asset::{Asset, AssetEvent, AssetServer, Assets, Handle, HandleId, LoadState};
 use bevy_ecs::{
-    entity::{Entity, EntityMap},
+    entity::{Entity, EntityMap, MapEntitiesError},
     event::{Events, ManualEventReader},
+    reflect::ReflectComponent,
     system::{Command, Resource},
     world::{Mut, World},
 };
-use bevy_hierarchy::{AddChild, Parent};
-use bevy_utils::{tracing::error, HashMap};
+use bevy_hierarchy::{
+    despawn_with_children_recursive, AddChild, BuildWorldChildren, Children, Parent,
+};
+use bevy_utils::{tracing::error, HashMap, HashSet};
+use std::any::TypeId;
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -28,12 +32,55 @@ impl InstanceId {
     }
 }
 
+/// Event sent by the [`SceneSpawner`] once a queued scene instance is spawned, and attached to
+/// its parent if it was spawned as a child, such as by a [`SceneBundle`](crate::SceneBundle) or a
+/// [`DynamicSceneBundle`](crate::DynamicSceneBundle).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SceneInstanceReady {
+    /// The entity the scene instance was spawned as a child of, if any.
+    pub parent: Option<Entity>,
+    /// The ready scene instance.
+    pub instance_id: InstanceId,
+}
+
+/// Event sent by the [`SceneSpawner`] when a scene instance is despawned.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SceneInstanceDespawned {
+    /// The entity the scene instance was spawned as a child of, if any.
+    pub parent: Option<Entity>,
+    /// The despawned scene instance.
+    pub instance_id: InstanceId,
+}
+
+/// Event sent by the [`SceneSpawner`] when a queued scene instance fails to spawn, including when
+/// its scene fails to load, or when a spawned instance fails to update after its scene changed.
+///
+/// A queued instance isn't spawned, and won't be retried. A spawned instance keeps the entities of
+/// the previous version of its scene, and is updated again the next time its scene changes.
+#[derive(Debug)]
+pub struct SceneInstanceSpawnFailed {
+    /// The entity the scene instance was to be spawned as a child of, if any.
+    pub parent: Option<Entity>,
+    /// The scene instance which failed to spawn.
+    pub instance_id: InstanceId,
+    /// Why the scene instance failed to spawn.
+    pub error: SceneSpawnError,
+}
+
+/// The types of the components each entity of a scene has, keyed by scene entity.
+type SceneComponentTypes = HashMap<Entity, HashSet<TypeId>>;
+
 #[derive(Default, Resource)]
 pub struct SceneSpawner {
     spawned_scenes: HashMap<Handle<Scene>, Vec<InstanceId>>,
     spawned_dynamic_scenes: HashMap<Handle<DynamicScene>, Vec<InstanceId>>,
     spawned_instances: HashMap<InstanceId, InstanceInfo>,
+    /// The component types the entities of each instance got from their scene, to remove the
+    /// ones which disappear from the scene when it changes.
+    spawned_component_types: HashMap<InstanceId, SceneComponentTypes>,
+    instance_parents: HashMap<InstanceId, Entity>,
     scene_asset_event_reader: ManualEventReader<AssetEvent<DynamicScene>>,
+    real_scene_asset_event_reader: ManualEventReader<AssetEvent<Scene>>,
     dynamic_scenes_to_spawn: Vec<(Handle<DynamicScene>, InstanceId)>,
     scenes_to_spawn: Vec<(Handle<Scene>, InstanceId)>,
     scenes_to_despawn: Vec<Handle<DynamicScene>>,
@@ -45,12 +92,27 @@ pub struct SceneSpawner {
 pub enum SceneSpawnError {
     #[error("scene contains the unregistered component `{type_name}`. consider adding `#[reflect(Component)]` to your type")]
     UnregisteredComponent { type_name: String },
+    #[error("scene contains the unregistered resource `{type_name}`. consider adding `#[reflect(Resource)]` to your type")]
+    UnregisteredResource { type_name: String },
     #[error("scene contains the unregistered type `{type_name}`. consider registering the type using `app.register_type::<T>()`")]
     UnregisteredType { type_name: String },
     #[error("scene does not exist")]
     NonExistentScene { handle: Handle<DynamicScene> },
     #[error("scene does not exist")]
     NonExistentRealScene { handle: Handle<Scene> },
+    #[error("scene resources reference entities which aren't in the scene: {0}")]
+    MapEntities(#[from] MapEntitiesError),
+    #[error("nested scene `{path}` contains itself")]
+    RecursiveNestedScene { path: String },
+    #[error("nested scene `{scene_path}` has no entity at `{entity_path}`")]
+    NonExistentNestedEntity {
+        scene_path: String,
+        entity_path: String,
+    },
+    #[error(
+        "nested scene `{scene_path}` is parented to the entity {parent}, which isn't in the scene"
+    )]
+    NonExistentNestedSceneParent { scene_path: String, parent: u32 },
 }
 
 impl SceneSpawner {
@@ -108,10 +170,16 @@ impl SceneSpawner {
     }
 
     pub fn despawn_instance_sync(&mut self, world: &mut World, instance_id: &InstanceId) {
+        self.spawned_component_types.remove(instance_id);
+        let parent = self.instance_parents.remove(instance_id);
         if let Some(instance) = self.spawned_instances.remove(instance_id) {
             for entity in instance.entity_map.values() {
                 let _ = world.despawn(entity);
             }
+            world.send_event(SceneInstanceDespawned {
+                parent,
+                instance_id: *instance_id,
+            });
         }
     }
 
@@ -121,10 +189,13 @@ impl SceneSpawner {
         scene_handle: &Handle<DynamicScene>,
     ) -> Result<(), SceneSpawnError> {
         let mut entity_map = EntityMap::default();
-        Self::spawn_dynamic_internal(world, scene_handle, &mut entity_map)?;
+        let component_types =
+            Self::spawn_dynamic_internal(world, scene_handle, &mut entity_map, None)?;
         let instance_id = InstanceId::new();
         self.spawned_instances
             .insert(instance_id, InstanceInfo { entity_map });
+        self.spawned_component_types
+            .insert(instance_id, component_types);
         let spawned = self
             .spawned_dynamic_scenes
             .entry(scene_handle.clone())
@@ -133,11 +204,20 @@ impl SceneSpawner {
         Ok(())
     }
 
+    /// Writes the dynamic scene to the world, using the entities of `entity_map`, and returns the
+    /// component types of its entities.
+    ///
+    /// When updating an instance, the entities and components it got from the `previous`
+    /// version of the scene which aren't in the current version are removed first.
+    ///
+    /// The entities of the [nested scenes](DynamicScene::scenes) are written too, see
+    /// [`DynamicScene::flatten`].
     fn spawn_dynamic_internal(
         world: &mut World,
         scene_handle: &Handle<DynamicScene>,
         entity_map: &mut EntityMap,
-    ) -> Result<(), SceneSpawnError> {
+        previous: Option<&SceneComponentTypes>,
+    ) -> Result<SceneComponentTypes, SceneSpawnError> {
         world.resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
             let scene =
                 scenes
@@ -145,7 +225,46 @@ impl SceneSpawner {
                     .ok_or_else(|| SceneSpawnError::NonExistentScene {
                         handle: scene_handle.clone_weak(),
                     })?;
-            scene.write_to_world(world, entity_map)
+            // the entities of nested scenes are spawned as part of the scene
+            let flattened;
+            let scene = if scene.scenes.is_empty() {
+                scene
+            } else {
+                flattened = scene.flatten(&scenes)?;
+                &flattened
+            };
+            let type_registry = world.resource::<AppTypeRegistry>().clone();
+            let component_types = dynamic_scene_component_types(scene, &type_registry);
+            if let Some(previous) = previous {
+                remove_stale_entities(world, entity_map, previous, &component_types);
+            }
+            scene.write_to_world_with(world, entity_map, &type_registry)?;
+            Ok(component_types)
+        })
+    }
+
+    /// Writes the scene to the world, like [`Self::spawn_dynamic_internal`] does for dynamic
+    /// scenes.
+    fn spawn_real_internal(
+        world: &mut World,
+        scene_handle: &Handle<Scene>,
+        entity_map: &mut EntityMap,
+        previous: Option<&SceneComponentTypes>,
+    ) -> Result<SceneComponentTypes, SceneSpawnError> {
+        world.resource_scope(|world, scenes: Mut<Assets<Scene>>| {
+            let scene =
+                scenes
+                    .get(scene_handle)
+                    .ok_or_else(|| SceneSpawnError::NonExistentRealScene {
+                        handle: scene_handle.clone(),
+                    })?;
+            let type_registry = world.resource::<AppTypeRegistry>().clone();
+            let component_types = scene_component_types(scene);
+            if let Some(previous) = previous {
+                remove_stale_entities(world, entity_map, previous, &component_types);
+            }
+            scene.write_to_world_with_entity_map(world, entity_map, &type_registry)?;
+            Ok(component_types)
         })
     }
 
@@ -163,45 +282,103 @@ impl SceneSpawner {
         scene_handle: Handle<Scene>,
         instance_id: InstanceId,
     ) -> Result<InstanceId, SceneSpawnError> {
-        world.resource_scope(|world, scenes: Mut<Assets<Scene>>| {
-            let scene =
-                scenes
-                    .get(&scene_handle)
-                    .ok_or_else(|| SceneSpawnError::NonExistentRealScene {
-                        handle: scene_handle.clone(),
-                    })?;
-
-            let instance_info =
-                scene.write_to_world_with(world, &world.resource::<AppTypeRegistry>().clone())?;
+        let mut entity_map = EntityMap::default();
+        let component_types =
+            Self::spawn_real_internal(world, &scene_handle, &mut entity_map, None)?;
 
-            self.spawned_instances.insert(instance_id, instance_info);
-            let spawned = self
-                .spawned_scenes
-                .entry(scene_handle)
-                .or_insert_with(Vec::new);
-            spawned.push(instance_id);
-            Ok(instance_id)
-        })
+        self.spawned_instances
+            .insert(instance_id, InstanceInfo { entity_map });
+        self.spawned_component_types
+            .insert(instance_id, component_types);
+        let spawned = self
+            .spawned_scenes
+            .entry(scene_handle)
+            .or_insert_with(Vec::new);
+        spawned.push(instance_id);
+        Ok(instance_id)
     }
 
+    /// Updates the instances of the given dynamic scenes to their current version.
+    ///
+    /// Entities and components which were removed from a scene are removed from its instances,
+    /// new ones are spawned or inserted, and the others are updated in place. Entities keep their
+    /// identity, and components which weren't added by the scene are kept.
+    ///
+    /// Instances which fail to update, such as when an overridden entity of a nested scene was
+    /// renamed, keep their entities and are reported with a [`SceneInstanceSpawnFailed`] event
+    /// instead of returning an error, so that the other instances are still updated.
     pub fn update_spawned_scenes(
         &mut self,
         world: &mut World,
         scene_handles: &[Handle<DynamicScene>],
     ) -> Result<(), SceneSpawnError> {
+        let mut failures = Vec::new();
         for scene_handle in scene_handles {
             if let Some(spawned_instances) = self.spawned_dynamic_scenes.get(scene_handle) {
                 for instance_id in spawned_instances {
                     if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
-                        Self::spawn_dynamic_internal(
+                        match Self::spawn_dynamic_internal(
                             world,
                             scene_handle,
                             &mut instance_info.entity_map,
-                        )?;
+                            self.spawned_component_types.get(instance_id),
+                        ) {
+                            Ok(component_types) => {
+                                self.spawned_component_types
+                                    .insert(*instance_id, component_types);
+                            }
+                            Err(error) => failures.push((*instance_id, error)),
+                        }
+                        if let Some(&parent) = self.instance_parents.get(instance_id) {
+                            add_instance_roots_to_parent(world, &instance_info.entity_map, parent);
+                        }
                     }
                 }
             }
         }
+        for (instance_id, error) in failures {
+            self.fail_update(world, instance_id, error);
+        }
+        Ok(())
+    }
+
+    /// Updates the instances of the given scenes to their current version, like
+    /// [`Self::update_spawned_scenes`] does for dynamic scenes.
+    ///
+    /// Instances which fail to update are reported with a [`SceneInstanceSpawnFailed`] event
+    /// instead of returning an error, so that the other instances are still updated.
+    pub fn update_spawned_real_scenes(
+        &mut self,
+        world: &mut World,
+        scene_handles: &[Handle<Scene>],
+    ) -> Result<(), SceneSpawnError> {
+        let mut failures = Vec::new();
+        for scene_handle in scene_handles {
+            if let Some(spawned_instances) = self.spawned_scenes.get(scene_handle) {
+                for instance_id in spawned_instances {
+                    if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
+                        match Self::spawn_real_internal(
+                            world,
+                            scene_handle,
+                            &mut instance_info.entity_map,
+                            self.spawned_component_types.get(instance_id),
+                        ) {
+                            Ok(component_types) => {
+                                self.spawned_component_types
+                                    .insert(*instance_id, component_types);
+                            }
+                            Err(error) => failures.push((*instance_id, error)),
+                        }
+                        if let Some(&parent) = self.instance_parents.get(instance_id) {
+                            add_instance_roots_to_parent(world, &instance_info.entity_map, parent);
+                        }
+                    }
+                }
+            }
+        }
+        for (instance_id, error) in failures {
+            self.fail_update(world, instance_id, error);
+        }
         Ok(())
     }
 
@@ -222,27 +399,41 @@ impl SceneSpawner {
         }
     }
 
+    /// Spawns the queued scenes whose asset is loaded.
+    ///
+    /// A [`SceneInstanceReady`] event is sent for each instance spawned without a parent, the
+    /// instances spawned as children are ready once attached to their parent.
+    /// Instances which fail to spawn are dropped, and a [`SceneInstanceSpawnFailed`] event is sent
+    /// for each of them instead of returning an error, so that the other instances are still
+    /// spawned.
     pub fn spawn_queued_scenes(&mut self, world: &mut World) -> Result<(), SceneSpawnError> {
         let scenes_to_spawn = std::mem::take(&mut self.dynamic_scenes_to_spawn);
 
         for (scene_handle, instance_id) in scenes_to_spawn {
             let mut entity_map = EntityMap::default();
 
-            match Self::spawn_dynamic_internal(world, &scene_handle, &mut entity_map) {
-                Ok(_) => {
+            match Self::spawn_dynamic_internal(world, &scene_handle, &mut entity_map, None) {
+                Ok(component_types) => {
                     self.spawned_instances
                         .insert(instance_id, InstanceInfo { entity_map });
+                    self.spawned_component_types
+                        .insert(instance_id, component_types);
                     let spawned = self
                         .spawned_dynamic_scenes
                         .entry(scene_handle.clone())
                         .or_insert_with(Vec::new);
                     spawned.push(instance_id);
+                    self.send_ready_without_parent(world, instance_id);
                 }
-                Err(SceneSpawnError::NonExistentScene { .. }) => {
+                // wait for the scene, or a scene it nests, to load
+                Err(SceneSpawnError::NonExistentScene { handle })
+                    if (handle == scene_handle && !scene_failed_to_load(world, &handle))
+                        || nested_scene_is_loading(world, &handle) =>
+                {
                     self.dynamic_scenes_to_spawn
                         .push((scene_handle, instance_id));
                 }
-                Err(err) => return Err(err),
+                Err(error) => self.fail_spawn(world, instance_id, error),
             }
         }
 
@@ -250,50 +441,86 @@ impl SceneSpawner {
 
         for (scene_handle, instance_id) in scenes_to_spawn {
             match self.spawn_sync_internal(world, scene_handle, instance_id) {
-                Ok(_) => {}
-                Err(SceneSpawnError::NonExistentRealScene { handle }) => {
+                Ok(_) => self.send_ready_without_parent(world, instance_id),
+                Err(SceneSpawnError::NonExistentRealScene { handle })
+                    if !scene_failed_to_load(world, &handle) =>
+                {
                     self.scenes_to_spawn.push((handle, instance_id));
                 }
-                Err(err) => return Err(err),
+                Err(error) => self.fail_spawn(world, instance_id, error),
             }
         }
 
         Ok(())
     }
 
+    fn queued_parent(&self, instance_id: InstanceId) -> Option<Entity> {
+        self.scenes_with_parent
+            .iter()
+            .find(|(id, _)| *id == instance_id)
+            .map(|(_, parent)| *parent)
+    }
+
+    fn send_ready_without_parent(&self, world: &mut World, instance_id: InstanceId) {
+        if self.queued_parent(instance_id).is_none() {
+            world.send_event(SceneInstanceReady {
+                parent: None,
+                instance_id,
+            });
+        }
+    }
+
+    fn fail_spawn(&mut self, world: &mut World, instance_id: InstanceId, error: SceneSpawnError) {
+        error!("failed to spawn scene instance {instance_id:?}: {error}");
+        let parent = self.queued_parent(instance_id);
+        self.scenes_with_parent.retain(|(id, _)| *id != instance_id);
+        world.send_event(SceneInstanceSpawnFailed {
+            parent,
+            instance_id,
+            error,
+        });
+    }
+
+    /// Reports that a spawned instance failed to update to the current version of its scene.
+    fn fail_update(&self, world: &mut World, instance_id: InstanceId, error: SceneSpawnError) {
+        error!("failed to update scene instance {instance_id:?}: {error}");
+        world.send_event(SceneInstanceSpawnFailed {
+            parent: self.instance_parents.get(&instance_id).copied(),
+            instance_id,
+            error,
+        });
+    }
+
     pub(crate) fn set_scene_instance_parent_sync(&mut self, world: &mut World) {
         let scenes_with_parent = std::mem::take(&mut self.scenes_with_parent);
 
         for (instance_id, parent) in scenes_with_parent {
             if let Some(instance) = self.spawned_instances.get(&instance_id) {
-                for entity in instance.entity_map.values() {
-                    // Add the `Parent` component to the scene root, and update the `Children` component of
-                    // the scene parent
-                    if !world
-                        .get_entity(entity)
-                        // This will filter only the scene root entity, as all other from the
-                        // scene have a parent
-                        .map(|entity| entity.contains::<Parent>())
-                        // Default is true so that it won't run on an entity that wouldn't exist anymore
-                        // this case shouldn't happen anyway
-                        .unwrap_or(true)
-                    {
-                        AddChild {
-                            parent,
-                            child: entity,
-                        }
-                        .write(world);
-                    }
-                }
+                add_instance_roots_to_parent(world, &instance.entity_map, parent);
+                self.instance_parents.insert(instance_id, parent);
+                world.send_event(SceneInstanceReady {
+                    parent: Some(parent),
+                    instance_id,
+                });
             } else {
                 self.scenes_with_parent.push((instance_id, parent));
             }
         }
     }
 
-    /// Check that an scene instance spawned previously is ready to use
+    /// Check that an scene instance spawned previously is ready to use: it is spawned, and
+    /// attached to its parent if it was spawned as a child.
+    ///
+    /// A [`SceneInstanceReady`] event is sent once an instance queued with [`Self::spawn`] or
+    /// similar methods is ready.
     pub fn instance_is_ready(&self, instance_id: InstanceId) -> bool {
         self.spawned_instances.contains_key(&instance_id)
+            && self.queued_parent(instance_id).is_none()
+    }
+
+    /// Returns the entity a ready scene instance was spawned as a child of, if any.
+    pub fn instance_parent(&self, instance_id: InstanceId) -> Option<Entity> {
+        self.instance_parents.get(&instance_id).copied()
     }
 
     /// Get an iterator over the entities in an instance, once it's spawned.
@@ -312,19 +539,186 @@ impl SceneSpawner {
     }
 }
 
+/// Returns `true` if the nested scene of `handle` isn't loaded yet, but may still be.
+/// Returns `true` if the asset server failed to load the scene of `handle`, or unloaded it, so
+/// that instances waiting for it to be spawned should fail instead.
+fn scene_failed_to_load<T: Asset>(world: &World, handle: &Handle<T>) -> bool {
+    let Some(asset_server) = world.get_resource::<AssetServer>() else {
+        return false;
+    };
+    matches!(
+        asset_server.get_load_state(handle),
+        LoadState::Failed | LoadState::Unloaded
+    )
+}
+
+fn nested_scene_is_loading(world: &World, handle: &Handle<DynamicScene>) -> bool {
+    let Some(asset_server) = world.get_resource::<AssetServer>() else {
+        return false;
+    };
+    match asset_server.get_load_state(handle) {
+        LoadState::Loading => true,
+        // nested scenes are loaded as dependencies, which start loading in the background once
+        // the scene nesting them is loaded
+        LoadState::NotLoaded => matches!(handle.id(), HandleId::AssetPathId(_)),
+        LoadState::Loaded | LoadState::Failed | LoadState::Unloaded => false,
+    }
+}
+
+/// Adds the root entities of a scene instance as children of `parent`.
+fn add_instance_roots_to_parent(world: &mut World, entity_map: &EntityMap, parent: Entity) {
+    for entity in entity_map.values() {
+        // Add the `Parent` component to the scene root, and update the `Children` component of
+        // the scene parent
+        if !world
+            .get_entity(entity)
+            // This will filter only the scene root entity, as all other from the
+            // scene have a parent
+            .map(|entity| entity.contains::<Parent>())
+            // Default is true so that it won't run on an entity that wouldn't exist anymore
+            // this case shouldn't happen anyway
+            .unwrap_or(true)
+        {
+            AddChild {
+                parent,
+                child: entity,
+            }
+            .write(world);
+        }
+    }
+}
+
+fn dynamic_scene_component_types(
+    scene: &DynamicScene,
+    type_registry: &AppTypeRegistry,
+) -> SceneComponentTypes {
+    let type_registry = type_registry.read();
+    scene
+        .entities
+        .iter()
+        .map(|entity| {
+            let component_types = entity
+                .components
+                .iter()
+                .filter_map(|component| type_registry.get_with_name(component.type_name()))
+                .map(|registration| registration.type_id())
+                .collect();
+            (Entity::from_raw(entity.entity), component_types)
+        })
+        .collect()
+}
+
+fn scene_component_types(scene: &Scene) -> SceneComponentTypes {
+    let mut scene_component_types = SceneComponentTypes::default();
+    for archetype in scene.world.archetypes().iter() {
+        let component_types: HashSet<TypeId> = archetype
+            .components()
+            .filter_map(|component_id| scene.world.components().get_info(component_id))
+            .filter_map(|component_info| component_info.type_id())
+            .collect();
+        for scene_entity in archetype.entities() {
+            scene_component_types.insert(scene_entity.entity(), component_types.clone());
+        }
+    }
+    scene_component_types
+}
+
+/// Despawns the entities and removes the components a scene instance got from the `previous`
+/// version of its scene which aren't in its `current` version.
+///
+/// Entities despawned at runtime are removed from `entity_map`, so that they are spawned again if
+/// they are still in the scene.
+fn remove_stale_entities(
+    world: &mut World,
+    entity_map: &mut EntityMap,
+    previous: &SceneComponentTypes,
+    current: &SceneComponentTypes,
+) {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+
+    let mut removed_entities = Vec::new();
+    for (&scene_entity, previous_types) in previous {
+        let Ok(entity) = entity_map.get(scene_entity) else {
+            continue;
+        };
+        let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+            entity_map.remove(scene_entity);
+            continue;
+        };
+        let Some(current_types) = current.get(&scene_entity) else {
+            entity_map.remove(scene_entity);
+            removed_entities.push(entity);
+            continue;
+        };
+        for type_id in previous_types.difference(current_types) {
+            if let Some(reflect_component) = type_registry
+                .get(*type_id)
+                .and_then(|registration| registration.data::<ReflectComponent>())
+            {
+                reflect_component.remove(&mut entity_mut);
+            }
+        }
+    }
+
+    // children which are still in the scene are kept, with their identity, when their parent is
+    // despawned
+    let kept_entities: HashSet<Entity> = entity_map.values().collect();
+    for &entity in &removed_entities {
+        let kept_children: Vec<Entity> = world
+            .get::<Children>(entity)
+            .into_iter()
+            .flat_map(|children| children.iter())
+            .filter(|child| kept_entities.contains(child))
+            .copied()
+            .collect();
+        for child in kept_children {
+            world.entity_mut(child).remove_parent();
+        }
+    }
+    for entity in removed_entities {
+        if world.get_entity(entity).is_some() {
+            despawn_with_children_recursive(world, entity);
+        }
+    }
+}
+
 pub fn scene_spawner_system(world: &mut World) {
     world.resource_scope(|world, mut scene_spawner: Mut<SceneSpawner>| {
         let scene_asset_events = world.resource::<Events<AssetEvent<DynamicScene>>>();
 
-        let mut updated_spawned_scenes = Vec::new();
         let scene_spawner = &mut *scene_spawner;
-        for event in scene_spawner
+        let modified_scenes: Vec<Handle<DynamicScene>> = scene_spawner
             .scene_asset_event_reader
             .iter(scene_asset_events)
+            .filter_map(|event| match event {
+                AssetEvent::Modified { handle } => Some(handle.clone_weak()),
+                _ => None,
+            })
+            .collect();
+        // instances of scenes nesting a modified scene are updated too
+        let scenes = world.resource::<Assets<DynamicScene>>();
+        let updated_spawned_scenes: Vec<Handle<DynamicScene>> = scene_spawner
+            .spawned_dynamic_scenes
+            .keys()
+            .filter(|handle| {
+                modified_scenes.iter().any(|modified| {
+                    *handle == modified
+                        || matches!(scenes.get(*handle), Some(scene) if scene.nests(modified, scenes))
+                })
+            })
+            .map(Handle::clone_weak)
+            .collect();
+
+        let real_scene_asset_events = world.resource::<Events<AssetEvent<Scene>>>();
+        let mut updated_spawned_real_scenes = Vec::new();
+        for event in scene_spawner
+            .real_scene_asset_event_reader
+            .iter(real_scene_asset_events)
         {
             if let AssetEvent::Modified { handle } = event {
-                if scene_spawner.spawned_dynamic_scenes.contains_key(handle) {
-                    updated_spawned_scenes.push(handle.clone_weak());
+                if scene_spawner.spawned_scenes.contains_key(handle) {
+                    updated_spawned_real_scenes.push(handle.clone_weak());
                 }
             }
         }
@@ -337,6 +731,443 @@ pub fn scene_spawner_system(world: &mut World) {
         scene_spawner
             .update_spawned_scenes(world, &updated_spawned_scenes)
             .unwrap();
+        scene_spawner
+            .update_spawned_real_scenes(world, &updated_spawned_real_scenes)
+            .unwrap();
         scene_spawner.set_scene_instance_parent_sync(world);
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, AppTypeRegistry};
+    use bevy_asset::{AssetPlugin, AssetServer, Assets, Handle, HandleId};
+    use bevy_core::{Name, TaskPoolPlugin};
+    use bevy_ecs::{
+        component::Component,
+        entity::Entity,
+        event::{Event, Events},
+        reflect::ReflectComponent,
+        world::World,
+    };
+    use bevy_reflect::Reflect;
+
+    use crate::{
+        DynamicScene, DynamicSceneBuilder, DynamicSceneBundle, NestedScene, Scene, SceneBundle,
+        SceneInstanceDespawned, SceneInstanceReady, SceneInstanceSpawnFailed, ScenePlugin,
+        SceneSpawnError, SceneSpawner,
+    };
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct A(u32);
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct B;
+
+    #[derive(Component)]
+    struct Runtime;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_plugin(ScenePlugin)
+            .register_type::<A>()
+            .register_type::<B>();
+        app
+    }
+
+    fn build_scene(world: &World, entities: impl IntoIterator<Item = Entity>) -> DynamicScene {
+        let mut builder = DynamicSceneBuilder::from_world(world);
+        builder.extract_entities(entities.into_iter());
+        builder.build()
+    }
+
+    #[test]
+    fn update_spawned_dynamic_scene_in_place() {
+        let mut app = app();
+
+        let mut source = World::new();
+        source.insert_resource(app.world.resource::<AppTypeRegistry>().clone());
+        let kept = source.spawn((A(1), B)).id();
+        let removed = source.spawn(A(2)).id();
+        let scene = build_scene(&source, [kept, removed]);
+
+        let handle = app.world.resource_mut::<Assets<DynamicScene>>().add(scene);
+        let instance = app
+            .world
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(handle.clone());
+        app.update();
+
+        let scene_spawner = app.world.resource::<SceneSpawner>();
+        assert!(scene_spawner.instance_is_ready(instance));
+        let spawned: Vec<Entity> = scene_spawner.iter_instance_entities(instance).collect();
+        assert_eq!(spawned.len(), 2);
+        let [kept_instance, removed_instance] = [kept, removed].map(|entity| {
+            *spawned
+                .iter()
+                .find(|spawned| app.world.get::<A>(**spawned) == source.get::<A>(entity))
+                .unwrap()
+        });
+        app.world.entity_mut(kept_instance).insert(Runtime);
+
+        // remove `B` and the second entity, update `A` and add a new entity
+        source.entity_mut(kept).insert(A(3)).remove::<B>();
+        // spawned first so that it doesn't reuse the index of the removed entity
+        let added = source.spawn(A(4)).id();
+        source.despawn(removed);
+        let new_scene = build_scene(&source, [kept, added]);
+        *app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .get_mut(&handle)
+            .unwrap() = new_scene;
+        // asset events are sent after the scene spawner ran, the instance is updated next frame
+        app.update();
+        app.update();
+
+        assert_eq!(app.world.get::<A>(kept_instance), Some(&A(3)));
+        assert!(app.world.get::<B>(kept_instance).is_none());
+        assert!(app.world.get::<Runtime>(kept_instance).is_some());
+        assert!(app.world.get_entity(removed_instance).is_none());
+        let spawned: Vec<Entity> = app
+            .world
+            .resource::<SceneSpawner>()
+            .iter_instance_entities(instance)
+            .collect();
+        assert_eq!(spawned.len(), 2);
+        assert!(spawned.contains(&kept_instance));
+        assert!(spawned
+            .iter()
+            .any(|entity| app.world.get::<A>(*entity) == Some(&A(4))));
+    }
+
+    #[test]
+    fn update_spawned_scene_in_place() {
+        let mut app = app();
+
+        let mut scene_world = World::new();
+        scene_world.spawn((A(1), B));
+        let handle = app
+            .world
+            .resource_mut::<Assets<Scene>>()
+            .add(Scene::new(scene_world));
+        let instance = app
+            .world
+            .resource_mut::<SceneSpawner>()
+            .spawn(handle.clone());
+        app.update();
+
+        let entity = app
+            .world
+            .resource::<SceneSpawner>()
+            .iter_instance_entities(instance)
+            .next()
+            .unwrap();
+        assert!(app.world.get::<B>(entity).is_some());
+
+        let mut scene_world = World::new();
+        scene_world.spawn(A(2));
+        *app.world
+            .resource_mut::<Assets<Scene>>()
+            .get_mut(&handle)
+            .unwrap() = Scene::new(scene_world);
+        // asset events are sent after the scene spawner ran, the instance is updated next frame
+        app.update();
+        app.update();
+
+        assert_eq!(app.world.get::<A>(entity), Some(&A(2)));
+        assert!(app.world.get::<B>(entity).is_none());
+    }
+
+    #[test]
+    fn failed_scene_update_event() {
+        let mut app = app();
+
+        let mut scene_world = World::new();
+        scene_world.spawn(A(1));
+        let handle = app
+            .world
+            .resource_mut::<Assets<Scene>>()
+            .add(Scene::new(scene_world));
+        let instance = app
+            .world
+            .resource_mut::<SceneSpawner>()
+            .spawn(handle.clone());
+        app.update();
+        drain_events::<SceneInstanceReady>(&mut app);
+
+        // `Runtime` isn't registered, so the new version can't be written
+        let mut scene_world = World::new();
+        scene_world.spawn((A(2), Runtime));
+        *app.world
+            .resource_mut::<Assets<Scene>>()
+            .get_mut(&handle)
+            .unwrap() = Scene::new(scene_world);
+        app.update();
+        app.update();
+
+        let failed = drain_events::<SceneInstanceSpawnFailed>(&mut app);
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].instance_id, instance);
+        assert!(matches!(
+            failed[0].error,
+            SceneSpawnError::UnregisteredType { .. }
+        ));
+        let scene_spawner = app.world.resource::<SceneSpawner>();
+        assert!(scene_spawner.instance_is_ready(instance));
+        assert_eq!(scene_spawner.iter_instance_entities(instance).count(), 1);
+    }
+
+    fn drain_events<E: Event>(app: &mut App) -> Vec<E> {
+        app.world.resource_mut::<Events<E>>().drain().collect()
+    }
+
+    #[test]
+    fn instance_lifecycle_events() {
+        let mut app = app();
+
+        let mut scene_world = World::new();
+        scene_world.spawn(A(1));
+        let scene = app
+            .world
+            .resource_mut::<Assets<Scene>>()
+            .add(Scene::new(scene_world));
+        let parent = app
+            .world
+            .spawn(SceneBundle {
+                scene,
+                ..Default::default()
+            })
+            .id();
+        app.update();
+
+        let ready = drain_events::<SceneInstanceReady>(&mut app);
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].parent, Some(parent));
+        let instance_id = ready[0].instance_id;
+        let scene_spawner = app.world.resource::<SceneSpawner>();
+        assert!(scene_spawner.instance_is_ready(instance_id));
+        assert_eq!(scene_spawner.instance_parent(instance_id), Some(parent));
+
+        app.world
+            .resource_mut::<SceneSpawner>()
+            .despawn_instance(instance_id);
+        app.update();
+
+        assert_eq!(
+            drain_events::<SceneInstanceDespawned>(&mut app),
+            [SceneInstanceDespawned {
+                parent: Some(parent),
+                instance_id
+            }]
+        );
+    }
+
+    #[test]
+    fn failed_spawn_event() {
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component)]
+        struct Unregistered;
+
+        let mut app = app();
+
+        let mut source = World::new();
+        let type_registry = AppTypeRegistry::default();
+        type_registry.write().register::<Unregistered>();
+        source.insert_resource(type_registry);
+        let entity = source.spawn(Unregistered).id();
+        let scene = app
+            .world
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(build_scene(&source, [entity]));
+        let parent = app
+            .world
+            .spawn(DynamicSceneBundle {
+                scene,
+                ..Default::default()
+            })
+            .id();
+        app.update();
+
+        assert!(drain_events::<SceneInstanceReady>(&mut app).is_empty());
+        let failed = drain_events::<SceneInstanceSpawnFailed>(&mut app);
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].parent, Some(parent));
+        assert!(matches!(
+            failed[0].error,
+            SceneSpawnError::UnregisteredType { .. }
+        ));
+        assert!(!app
+            .world
+            .resource::<SceneSpawner>()
+            .instance_is_ready(failed[0].instance_id));
+    }
+
+    #[test]
+    fn update_scenes_nesting_modified_scene() {
+        let mut app = app();
+
+        let mut source = World::new();
+        source.insert_resource(app.world.resource::<AppTypeRegistry>().clone());
+        let goblin_entity = source.spawn(A(10)).id();
+        let goblin = app
+            .world
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(build_scene(&source, [goblin_entity]));
+        let level = DynamicScene {
+            scenes: vec![NestedScene::new("goblin.scn.ron", goblin.clone())],
+            ..Default::default()
+        };
+        let level = app.world.resource_mut::<Assets<DynamicScene>>().add(level);
+        let instance = app
+            .world
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(level);
+        app.update();
+
+        let entity = app
+            .world
+            .resource::<SceneSpawner>()
+            .iter_instance_entities(instance)
+            .next()
+            .unwrap();
+        assert_eq!(app.world.get::<A>(entity), Some(&A(10)));
+
+        source.entity_mut(goblin_entity).insert(A(20));
+        *app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .get_mut(&goblin)
+            .unwrap() = build_scene(&source, [goblin_entity]);
+        app.update();
+        app.update();
+
+        assert_eq!(app.world.get::<A>(entity), Some(&A(20)));
+    }
+
+    #[test]
+    fn failed_nested_scene_update_event() {
+        let mut app = app();
+        app.register_type::<Name>();
+
+        let mut source = World::new();
+        source.insert_resource(app.world.resource::<AppTypeRegistry>().clone());
+        let goblin_entity = source.spawn((Name::new("Goblin"), A(10))).id();
+        let goblin = app
+            .world
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(build_scene(&source, [goblin_entity]));
+        let level = DynamicScene {
+            scenes: vec![NestedScene::new("goblin.scn.ron", goblin.clone())
+                .with_override("Goblin", vec![Box::new(A(20))])],
+            ..Default::default()
+        };
+        let level = app.world.resource_mut::<Assets<DynamicScene>>().add(level);
+        let instance = app
+            .world
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(level);
+        app.update();
+
+        let entity = app
+            .world
+            .resource::<SceneSpawner>()
+            .iter_instance_entities(instance)
+            .next()
+            .unwrap();
+        assert_eq!(app.world.get::<A>(entity), Some(&A(20)));
+
+        // renaming the overridden entity in the base scene breaks the override
+        source.entity_mut(goblin_entity).insert(Name::new("Orc"));
+        *app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .get_mut(&goblin)
+            .unwrap() = build_scene(&source, [goblin_entity]);
+        app.update();
+        app.update();
+
+        let failed = drain_events::<SceneInstanceSpawnFailed>(&mut app);
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].instance_id, instance);
+        assert!(matches!(
+            failed[0].error,
+            SceneSpawnError::NonExistentNestedEntity { .. }
+        ));
+        // the instance keeps its previous entities
+        assert!(app
+            .world
+            .resource::<SceneSpawner>()
+            .instance_is_ready(instance));
+        assert_eq!(app.world.get::<A>(entity), Some(&A(20)));
+        assert_eq!(app.world.get::<Name>(entity).unwrap().as_str(), "Goblin");
+    }
+
+    #[test]
+    fn missing_nested_scene_fails_spawn() {
+        let mut app = app();
+
+        // a scene which isn't loaded by the asset server, and never will be
+        let missing = Handle::weak(HandleId::random::<DynamicScene>());
+        let level = DynamicScene {
+            scenes: vec![NestedScene::new("missing.scn.ron", missing)],
+            ..Default::default()
+        };
+        let level = app.world.resource_mut::<Assets<DynamicScene>>().add(level);
+        let instance = app
+            .world
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(level);
+        app.update();
+
+        let failed = drain_events::<SceneInstanceSpawnFailed>(&mut app);
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].instance_id, instance);
+        assert!(matches!(
+            failed[0].error,
+            SceneSpawnError::NonExistentScene { .. }
+        ));
+        // the instance isn't queued again
+        app.update();
+        assert!(drain_events::<SceneInstanceSpawnFailed>(&mut app).is_empty());
+    }
+
+    #[test]
+    fn scene_failing_to_load_fails_spawn() {
+        let mut app = app();
+        app.add_plugin(TaskPoolPlugin::default());
+
+        let dynamic_scene = app
+            .world
+            .resource::<AssetServer>()
+            .load::<DynamicScene, _>("missing.scn.ron");
+        let scene = app
+            .world
+            .resource::<AssetServer>()
+            .load::<Scene, _>("missing_too.scn.ron");
+        let mut scene_spawner = app.world.resource_mut::<SceneSpawner>();
+        let dynamic_instance = scene_spawner.spawn_dynamic(dynamic_scene);
+        let instance = scene_spawner.spawn(scene);
+
+        let mut failed = Vec::new();
+        for _ in 0..100 {
+            app.update();
+            failed.extend(drain_events::<SceneInstanceSpawnFailed>(&mut app));
+            if failed.len() == 2 {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+
+        assert_eq!(failed.len(), 2);
+        assert!(failed
+            .iter()
+            .any(|event| event.instance_id == dynamic_instance
+                && matches!(event.error, SceneSpawnError::NonExistentScene { .. })));
+        assert!(failed.iter().any(|event| event.instance_id == instance
+            && matches!(event.error, SceneSpawnError::NonExistentRealScene { .. })));
+        // the instances aren't queued again
+        app.update();
+        assert!(drain_events::<SceneInstanceSpawnFailed>(&mut app).is_empty());
+    }
+}
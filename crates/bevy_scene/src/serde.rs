@@ -1,12 +1,13 @@
-use crate::{DynamicEntity, DynamicScene};
+use crate::{DynamicEntity, DynamicScene, NestedScene, SceneOverride};
 use anyhow::Result;
+use bevy_asset::AssetPath;
 use bevy_reflect::serde::{TypedReflectDeserializer, TypedReflectSerializer};
 use bevy_reflect::{
     serde::{TypeRegistrationDeserializer, UntypedReflectDeserializer},
     Reflect, TypeRegistry, TypeRegistryArc,
 };
 use bevy_utils::HashSet;
-use serde::ser::SerializeMap;
+use serde::ser::{SerializeMap, SerializeSeq};
 use serde::{
     de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
     ser::SerializeStruct,
@@ -15,11 +16,18 @@ use serde::{
 use std::fmt::Formatter;
 
 pub const SCENE_STRUCT: &str = "Scene";
+pub const SCENE_RESOURCES: &str = "resources";
 pub const SCENE_ENTITIES: &str = "entities";
+pub const SCENE_NESTED_SCENES: &str = "scenes";
 
 pub const ENTITY_STRUCT: &str = "Entity";
 pub const ENTITY_FIELD_COMPONENTS: &str = "components";
 
+pub const NESTED_SCENE_STRUCT: &str = "NestedScene";
+pub const NESTED_SCENE_FIELD_PATH: &str = "path";
+pub const NESTED_SCENE_FIELD_PARENT: &str = "parent";
+pub const NESTED_SCENE_FIELD_OVERRIDES: &str = "overrides";
+
 pub struct SceneSerializer<'a> {
     pub scene: &'a DynamicScene,
     pub registry: &'a TypeRegistryArc,
@@ -31,12 +39,24 @@ impl<'a> SceneSerializer<'a> {
     }
 }
 
+/// Scenes are serialized as a struct with their entities, followed by their resources and nested
+/// scenes.
+///
+/// Human-readable formats leave out the resources and nested scenes when they are empty. Binary
+/// formats, which don't have field names, always write every field. Binary scenes written before
+/// resources and nested scenes were supported are rejected by their
+/// [version](crate::BINARY_SCENE_VERSION).
 impl<'a> Serialize for SceneSerializer<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_struct(SCENE_STRUCT, 1)?;
+        let human_readable = serializer.is_human_readable();
+        let serialize_resources = !human_readable || !self.scene.resources.is_empty();
+        let serialize_scenes = !human_readable || !self.scene.scenes.is_empty();
+
+        let len = 1 + serialize_resources as usize + serialize_scenes as usize;
+        let mut state = serializer.serialize_struct(SCENE_STRUCT, len)?;
         state.serialize_field(
             SCENE_ENTITIES,
             &EntitiesSerializer {
@@ -44,6 +64,28 @@ impl<'a> Serialize for SceneSerializer<'a> {
                 registry: self.registry,
             },
         )?;
+        if serialize_resources {
+            state.serialize_field(
+                SCENE_RESOURCES,
+                &ComponentsSerializer {
+                    components: &self.scene.resources,
+                    registry: self.registry,
+                },
+            )?;
+        } else {
+            state.skip_field(SCENE_RESOURCES)?;
+        }
+        if serialize_scenes {
+            state.serialize_field(
+                SCENE_NESTED_SCENES,
+                &NestedScenesSerializer {
+                    scenes: &self.scene.scenes,
+                    registry: self.registry,
+                },
+            )?;
+        } else {
+            state.skip_field(SCENE_NESTED_SCENES)?;
+        }
         state.end()
     }
 }
@@ -94,6 +136,10 @@ impl<'a> Serialize for EntitySerializer<'a> {
     }
 }
 
+/// Serializes reflected values as a map from their [type paths](bevy_reflect::TypePath) to their
+/// values, used for both the components of an entity and the resources of a scene.
+///
+/// The types of the values need to be registered.
 pub struct ComponentsSerializer<'a> {
     pub components: &'a [Box<dyn Reflect>],
     pub registry: &'a TypeRegistryArc,
@@ -104,11 +150,89 @@ impl<'a> Serialize for ComponentsSerializer<'a> {
     where
         S: serde::Serializer,
     {
+        let registry = self.registry.read();
         let mut state = serializer.serialize_map(Some(self.components.len()))?;
         for component in self.components {
+            let registration = registry.get_for_value(&**component).ok_or_else(|| {
+                serde::ser::Error::custom(format_args!(
+                    "no registration found for type `{}`",
+                    component.type_name()
+                ))
+            })?;
+            state.serialize_entry(
+                registration.type_path(),
+                &TypedReflectSerializer::new(&**component, &registry),
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// Serializes the scenes nested in a scene, with the paths they are loaded from.
+pub struct NestedScenesSerializer<'a> {
+    pub scenes: &'a [NestedScene],
+    pub registry: &'a TypeRegistryArc,
+}
+
+impl<'a> Serialize for NestedScenesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.scenes.len()))?;
+        for scene in self.scenes {
+            state.serialize_element(&NestedSceneSerializer {
+                scene,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+pub struct NestedSceneSerializer<'a> {
+    pub scene: &'a NestedScene,
+    pub registry: &'a TypeRegistryArc,
+}
+
+impl<'a> Serialize for NestedSceneSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(NESTED_SCENE_STRUCT, 3)?;
+        state.serialize_field(NESTED_SCENE_FIELD_PATH, &self.scene.path.to_string())?;
+        state.serialize_field(NESTED_SCENE_FIELD_PARENT, &self.scene.parent)?;
+        state.serialize_field(
+            NESTED_SCENE_FIELD_OVERRIDES,
+            &OverridesSerializer {
+                overrides: &self.scene.overrides,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+/// Serializes the overrides of a nested scene as a map from entity paths to components.
+pub struct OverridesSerializer<'a> {
+    pub overrides: &'a [SceneOverride],
+    pub registry: &'a TypeRegistryArc,
+}
+
+impl<'a> Serialize for OverridesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.overrides.len()))?;
+        for scene_override in self.overrides {
             state.serialize_entry(
-                component.type_name(),
-                &TypedReflectSerializer::new(&**component, &self.registry.read()),
+                &scene_override.entity_path,
+                &ComponentsSerializer {
+                    components: &scene_override.components,
+                    registry: self.registry,
+                },
             )?;
         }
         state.end()
@@ -119,6 +243,8 @@ impl<'a> Serialize for ComponentsSerializer<'a> {
 #[serde(field_identifier, rename_all = "lowercase")]
 enum SceneField {
     Entities,
+    Resources,
+    Scenes,
 }
 
 #[derive(Deserialize)]
@@ -127,6 +253,14 @@ enum EntityField {
     Components,
 }
 
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum NestedSceneField {
+    Path,
+    Parent,
+    Overrides,
+}
+
 pub struct SceneDeserializer<'a> {
     pub type_registry: &'a TypeRegistry,
 }
@@ -140,7 +274,7 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
     {
         deserializer.deserialize_struct(
             SCENE_STRUCT,
-            &[SCENE_ENTITIES],
+            &[SCENE_ENTITIES, SCENE_RESOURCES, SCENE_NESTED_SCENES],
             SceneVisitor {
                 type_registry: self.type_registry,
             },
@@ -163,9 +297,19 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
     where
         A: MapAccess<'de>,
     {
+        let mut resources = None;
         let mut entities = None;
+        let mut scenes = None;
         while let Some(key) = map.next_key()? {
             match key {
+                SceneField::Resources => {
+                    if resources.is_some() {
+                        return Err(Error::duplicate_field(SCENE_RESOURCES));
+                    }
+                    resources = Some(map.next_value_seed(ComponentDeserializer {
+                        registry: self.type_registry,
+                    })?);
+                }
                 SceneField::Entities => {
                     if entities.is_some() {
                         return Err(Error::duplicate_field(SCENE_ENTITIES));
@@ -174,12 +318,27 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
                         type_registry: self.type_registry,
                     })?);
                 }
+                SceneField::Scenes => {
+                    if scenes.is_some() {
+                        return Err(Error::duplicate_field(SCENE_NESTED_SCENES));
+                    }
+                    scenes = Some(map.next_value_seed(NestedScenesDeserializer {
+                        type_registry: self.type_registry,
+                    })?);
+                }
             }
         }
 
+        // scenes saved before resources or nested scenes were supported don't have any
+        let resources = resources.unwrap_or_default();
         let entities = entities.ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
+        let scenes = scenes.unwrap_or_default();
 
-        Ok(DynamicScene { entities })
+        Ok(DynamicScene {
+            resources,
+            entities,
+            scenes,
+        })
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
@@ -192,7 +351,22 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
             })?
             .ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
 
-        Ok(DynamicScene { entities })
+        let resources = seq
+            .next_element_seed(ComponentDeserializer {
+                registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
+        let scenes = seq
+            .next_element_seed(NestedScenesDeserializer {
+                type_registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::missing_field(SCENE_NESTED_SCENES))?;
+
+        Ok(DynamicScene {
+            resources,
+            entities,
+            scenes,
+        })
     }
 }
 
@@ -321,6 +495,200 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
     }
 }
 
+/// Deserializes the scenes nested in a scene serialized by a [`NestedScenesSerializer`].
+///
+/// The handles of the nested scenes aren't set, the [`SceneLoader`](crate::SceneLoader) sets
+/// them from the paths of the nested scenes.
+pub struct NestedScenesDeserializer<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for NestedScenesDeserializer<'a> {
+    type Value = Vec<NestedScene>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(NestedScenesVisitor {
+            type_registry: self.type_registry,
+        })
+    }
+}
+
+struct NestedScenesVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for NestedScenesVisitor<'a> {
+    type Value = Vec<NestedScene>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("list of nested scenes")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut scenes = Vec::new();
+        while let Some(scene) = seq.next_element_seed(NestedSceneDeserializer {
+            type_registry: self.type_registry,
+        })? {
+            scenes.push(scene);
+        }
+        Ok(scenes)
+    }
+}
+
+pub struct NestedSceneDeserializer<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for NestedSceneDeserializer<'a> {
+    type Value = NestedScene;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            NESTED_SCENE_STRUCT,
+            &[
+                NESTED_SCENE_FIELD_PATH,
+                NESTED_SCENE_FIELD_PARENT,
+                NESTED_SCENE_FIELD_OVERRIDES,
+            ],
+            NestedSceneVisitor {
+                type_registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct NestedSceneVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for NestedSceneVisitor<'a> {
+    type Value = NestedScene;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("nested scene struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let path: String = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(NESTED_SCENE_FIELD_PATH))?;
+        let parent = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(NESTED_SCENE_FIELD_PARENT))?;
+        let overrides = seq
+            .next_element_seed(OverridesDeserializer {
+                type_registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::missing_field(NESTED_SCENE_FIELD_OVERRIDES))?;
+
+        Ok(NestedScene {
+            parent,
+            overrides,
+            ..NestedScene::new(AssetPath::from(path), Default::default())
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut path: Option<String> = None;
+        let mut parent = None;
+        let mut overrides = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                NestedSceneField::Path => {
+                    if path.is_some() {
+                        return Err(Error::duplicate_field(NESTED_SCENE_FIELD_PATH));
+                    }
+                    path = Some(map.next_value()?);
+                }
+                NestedSceneField::Parent => {
+                    if parent.is_some() {
+                        return Err(Error::duplicate_field(NESTED_SCENE_FIELD_PARENT));
+                    }
+                    parent = Some(map.next_value()?);
+                }
+                NestedSceneField::Overrides => {
+                    if overrides.is_some() {
+                        return Err(Error::duplicate_field(NESTED_SCENE_FIELD_OVERRIDES));
+                    }
+                    overrides = Some(map.next_value_seed(OverridesDeserializer {
+                        type_registry: self.type_registry,
+                    })?);
+                }
+            }
+        }
+
+        let path = path.ok_or_else(|| Error::missing_field(NESTED_SCENE_FIELD_PATH))?;
+        Ok(NestedScene {
+            parent: parent.unwrap_or_default(),
+            overrides: overrides.unwrap_or_default(),
+            ..NestedScene::new(AssetPath::from(path), Default::default())
+        })
+    }
+}
+
+/// Deserializes the overrides of a nested scene serialized by an [`OverridesSerializer`].
+pub struct OverridesDeserializer<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for OverridesDeserializer<'a> {
+    type Value = Vec<SceneOverride>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(OverridesVisitor {
+            type_registry: self.type_registry,
+        })
+    }
+}
+
+struct OverridesVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for OverridesVisitor<'a> {
+    type Value = Vec<SceneOverride>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("map of entity paths to components")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut overrides = Vec::new();
+        while let Some(entity_path) = map.next_key()? {
+            let components = map.next_value_seed(ComponentDeserializer {
+                registry: self.type_registry,
+            })?;
+            overrides.push(SceneOverride {
+                entity_path,
+                components,
+            });
+        }
+        Ok(overrides)
+    }
+}
+
+/// Deserializes a map of reflected values serialized by a [`ComponentsSerializer`].
 pub struct ComponentDeserializer<'a> {
     pub registry: &'a TypeRegistry,
 }
@@ -361,7 +729,7 @@ impl<'a, 'de> Visitor<'de> for ComponentVisitor<'a> {
             if !added.insert(registration.type_id()) {
                 return Err(Error::custom(format_args!(
                     "duplicate component: `{}`",
-                    registration.type_name()
+                    registration.type_path()
                 )));
             }
 
@@ -391,10 +759,11 @@ impl<'a, 'de> Visitor<'de> for ComponentVisitor<'a> {
 #[cfg(test)]
 mod tests {
     use crate::serde::{SceneDeserializer, SceneSerializer};
-    use crate::{DynamicScene, DynamicSceneBuilder};
+    use crate::{DynamicScene, DynamicSceneBuilder, NestedScene};
     use bevy_app::AppTypeRegistry;
+    use bevy_asset::AssetPath;
     use bevy_ecs::entity::EntityMap;
-    use bevy_ecs::prelude::{Component, ReflectComponent, World};
+    use bevy_ecs::prelude::{Component, ReflectComponent, ReflectResource, Resource, World};
     use bevy_reflect::{FromReflect, Reflect, ReflectSerialize};
     use bincode::Options;
     use serde::de::DeserializeSeed;
@@ -419,6 +788,12 @@ mod tests {
         baz: MyEnum,
     }
 
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct MyResource {
+        foo: i32,
+    }
+
     #[derive(Reflect, FromReflect, Default)]
     enum MyEnum {
         #[default]
@@ -443,6 +818,7 @@ mod tests {
             registry.register_type_data::<String, ReflectSerialize>();
             registry.register::<[usize; 3]>();
             registry.register::<(f32, f32)>();
+            registry.register::<MyResource>();
         }
         world.insert_resource(registry);
         world
@@ -455,9 +831,11 @@ mod tests {
         let a = world.spawn(Foo(123)).id();
         let b = world.spawn((Foo(123), Bar(345))).id();
         let c = world.spawn((Foo(123), Bar(345), Baz(789))).id();
+        world.insert_resource(MyResource { foo: 123 });
 
         let mut builder = DynamicSceneBuilder::from_world(&world);
         builder.extract_entities([a, b, c].into_iter());
+        builder.extract_resources();
         let scene = builder.build();
 
         let expected = r#"(
@@ -481,6 +859,11 @@ mod tests {
       },
     ),
   },
+  resources: {
+    "bevy_scene::serde::tests::MyResource": (
+      foo: 123,
+    ),
+  },
 )"#;
         let output = scene
             .serialize_ron(&world.resource::<AppTypeRegistry>().0)
@@ -493,6 +876,11 @@ mod tests {
         let world = create_world();
 
         let input = r#"(
+  resources: {
+    "bevy_scene::serde::tests::MyResource": (
+      foo: 123,
+    ),
+  },
   entities: {
     0: (
       components: {
@@ -520,6 +908,11 @@ mod tests {
         };
         let scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
 
+        assert_eq!(
+            1,
+            scene.resources.len(),
+            "expected `resources` to contain 1 resource"
+        );
         assert_eq!(
             3,
             scene.entities.len(),
@@ -530,11 +923,76 @@ mod tests {
         let mut dst_world = create_world();
         scene.write_to_world(&mut dst_world, &mut map).unwrap();
 
+        assert_eq!(123, dst_world.resource::<MyResource>().foo);
         assert_eq!(3, dst_world.query::<&Foo>().iter(&dst_world).count());
         assert_eq!(2, dst_world.query::<&Bar>().iter(&dst_world).count());
         assert_eq!(1, dst_world.query::<&Baz>().iter(&dst_world).count());
     }
 
+    #[test]
+    fn should_roundtrip_nested_scenes() {
+        let world = create_world();
+
+        let input = r#"(
+  entities: {
+    0: (
+      components: {
+        "bevy_scene::serde::tests::Foo": (123),
+      },
+    ),
+  },
+  scenes: [
+    (
+      path: "enemies/goblin.scn.ron",
+      parent: Some(0),
+      overrides: {
+        "Goblin/Weapon": {
+          "bevy_scene::serde::tests::Bar": (5),
+        },
+      },
+    ),
+  ],
+)"#;
+        let registry = world.resource::<AppTypeRegistry>();
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &registry.read(),
+        };
+        let scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(1, scene.scenes.len());
+        let nested = &scene.scenes[0];
+        assert_eq!(nested.path, AssetPath::from("enemies/goblin.scn.ron"));
+        assert_eq!(nested.parent, Some(0));
+        assert_eq!(1, nested.overrides.len());
+        assert_eq!("Goblin/Weapon", nested.overrides[0].entity_path);
+
+        assert_eq!(input, scene.serialize_ron(registry).unwrap());
+    }
+
+    #[test]
+    fn should_deserialize_without_resources() {
+        let world = create_world();
+
+        let input = r#"(
+  entities: {
+    0: (
+      components: {
+        "bevy_scene::serde::tests::Foo": (123),
+      },
+    ),
+  },
+)"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &world.resource::<AppTypeRegistry>().read(),
+        };
+        let scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
+
+        assert!(scene.resources.is_empty());
+        assert_eq!(1, scene.entities.len());
+    }
+
     #[test]
     fn should_roundtrip_postcard() {
         let mut world = create_world();
@@ -557,7 +1015,7 @@ mod tests {
                 1, 0, 1, 37, 98, 101, 118, 121, 95, 115, 99, 101, 110, 101, 58, 58, 115, 101, 114,
                 100, 101, 58, 58, 116, 101, 115, 116, 115, 58, 58, 77, 121, 67, 111, 109, 112, 111,
                 110, 101, 110, 116, 1, 2, 3, 102, 102, 166, 63, 205, 204, 108, 64, 1, 12, 72, 101,
-                108, 108, 111, 32, 87, 111, 114, 108, 100, 33
+                108, 108, 111, 32, 87, 111, 114, 108, 100, 33, 0, 0
             ],
             serialized_scene
         );
@@ -594,11 +1052,11 @@ mod tests {
 
         assert_eq!(
             vec![
-                145, 129, 0, 145, 129, 217, 37, 98, 101, 118, 121, 95, 115, 99, 101, 110, 101, 58,
+                147, 129, 0, 145, 129, 217, 37, 98, 101, 118, 121, 95, 115, 99, 101, 110, 101, 58,
                 58, 115, 101, 114, 100, 101, 58, 58, 116, 101, 115, 116, 115, 58, 58, 77, 121, 67,
                 111, 109, 112, 111, 110, 101, 110, 116, 147, 147, 1, 2, 3, 146, 202, 63, 166, 102,
                 102, 202, 64, 108, 204, 205, 129, 165, 84, 117, 112, 108, 101, 172, 72, 101, 108,
-                108, 111, 32, 87, 111, 114, 108, 100, 33
+                108, 111, 32, 87, 111, 114, 108, 100, 33, 128, 144
             ],
             buf
         );
@@ -640,7 +1098,8 @@ mod tests {
                 58, 58, 116, 101, 115, 116, 115, 58, 58, 77, 121, 67, 111, 109, 112, 111, 110, 101,
                 110, 116, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0,
                 102, 102, 166, 63, 205, 204, 108, 64, 1, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 72, 101,
-                108, 108, 111, 32, 87, 111, 114, 108, 100, 33
+                108, 108, 111, 32, 87, 111, 114, 108, 100, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0
             ],
             serialized_scene
         );
@@ -658,6 +1117,93 @@ mod tests {
         assert_scene_eq(&scene, &deserialized_scene);
     }
 
+    #[test]
+    fn should_roundtrip_binary_resources_and_nested_scenes() {
+        let mut world = create_world();
+        world.spawn(Foo(123));
+        world.insert_resource(MyResource { foo: 42 });
+        let registry = world.resource::<AppTypeRegistry>();
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.extract_entities(world.iter_entities().map(|entity| entity.id()));
+        builder.extract_resources();
+        let mut scene = builder.build();
+        let mut with_scenes = DynamicScene::default();
+        with_scenes
+            .scenes
+            .push(NestedScene::new("goblin.scn.ron", Default::default()));
+
+        for scene in [&scene, &with_scenes] {
+            let scene_serializer = SceneSerializer::new(scene, &registry.0);
+            let type_registry = registry.read();
+
+            let bytes = postcard::to_allocvec(&scene_serializer).unwrap();
+            let postcard_scene = SceneDeserializer {
+                type_registry: &type_registry,
+            }
+            .deserialize(&mut postcard::Deserializer::from_bytes(&bytes))
+            .unwrap();
+
+            let bytes = bincode::serialize(&scene_serializer).unwrap();
+            let bincode_scene = bincode::DefaultOptions::new()
+                .with_fixint_encoding()
+                .deserialize_seed(
+                    SceneDeserializer {
+                        type_registry: &type_registry,
+                    },
+                    &bytes,
+                )
+                .unwrap();
+
+            let mut bytes = Vec::new();
+            scene_serializer
+                .serialize(&mut rmp_serde::Serializer::new(&mut bytes))
+                .unwrap();
+            let rmp_scene = SceneDeserializer {
+                type_registry: &type_registry,
+            }
+            .deserialize(&mut rmp_serde::Deserializer::new(bytes.as_slice()))
+            .unwrap();
+
+            for deserialized in [postcard_scene, bincode_scene, rmp_scene] {
+                assert_scene_eq(scene, &deserialized);
+                assert_eq!(scene.resources.len(), deserialized.resources.len());
+                assert_eq!(
+                    scene.scenes.iter().map(|s| &s.path).collect::<Vec<_>>(),
+                    deserialized
+                        .scenes
+                        .iter()
+                        .map(|s| &s.path)
+                        .collect::<Vec<_>>()
+                );
+            }
+        }
+
+        // every field is required, so scenes can be followed by other values
+        scene.resources.clear();
+        let bytes = postcard::to_allocvec(&SceneSerializer::new(&scene, &registry.0)).unwrap();
+        let mut deserializer = postcard::Deserializer::from_bytes(&bytes[..bytes.len() - 2]);
+        assert!(SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut deserializer)
+        .is_err());
+
+        let mut bytes = bytes;
+        bytes.push(7);
+        let mut deserializer = postcard::Deserializer::from_bytes(&bytes);
+        let deserialized = SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+        assert!(deserialized.resources.is_empty());
+        assert_eq!(
+            <u8 as serde::Deserialize>::deserialize(&mut deserializer).unwrap(),
+            7
+        );
+    }
+
     /// A crude equality checker for [`DynamicScene`], used solely for testing purposes.
     fn assert_scene_eq(expected: &DynamicScene, received: &DynamicScene) {
         assert_eq!(
@@ -5,6 +5,8 @@ use std::{
     sync::{Arc, Mutex},
 };
 
+use crate::TaskPoolStatistics;
+
 /// Used to create a TaskPool
 #[derive(Debug, Default, Clone)]
 pub struct TaskPoolBuilder {}
@@ -44,6 +46,11 @@ impl TaskPoolBuilder {
         self
     }
 
+    /// No op on the single threaded task pool
+    pub fn steal_from(self, _pool: &TaskPool) -> Self {
+        self
+    }
+
     /// Creates a new [`TaskPool`]
     pub fn build(self) -> TaskPool {
         TaskPool::new_internal()
@@ -71,6 +78,18 @@ impl TaskPool {
         1
     }
 
+    /// No op on the single threaded task pool
+    pub fn set_thread_num(&self, _num_threads: usize) {}
+
+    /// Returns a snapshot of the runtime statistics of this pool. Statistics are not collected
+    /// on the single threaded task pool, so only the thread count is set.
+    pub fn statistics(&self) -> TaskPoolStatistics {
+        TaskPoolStatistics {
+            thread_num: 1,
+            ..Default::default()
+        }
+    }
+
     /// Allows spawning non-`static futures on the thread pool. The function takes a callback,
     /// passing a scope object into it. The scope object provided to the callback can be used
     /// to spawn tasks. This function will await the completion of all tasks before returning.
@@ -0,0 +1,191 @@
+use std::time::Duration;
+
+/// A snapshot of the runtime statistics of a [`TaskPool`](crate::TaskPool).
+///
+/// Time values are cumulative since the pool was created. To get the utilization of a pool over
+/// a given period, subtract two snapshots taken at the start and the end of that period and
+/// compare [`busy_time`](Self::busy_time) against [`idle_time`](Self::idle_time).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TaskPoolStatistics {
+    /// The number of threads currently owned by the pool.
+    pub thread_num: usize,
+    /// The number of tasks that have been spawned on the pool but have not started running yet.
+    pub queued_tasks: usize,
+    /// The number of threads that are currently running one of the pool's tasks.
+    pub active_threads: usize,
+    /// The total time spent by all threads running the pool's tasks.
+    pub busy_time: Duration,
+    /// The total time the pool's threads have been alive without running one of the pool's tasks.
+    pub idle_time: Duration,
+    /// The number of scopes that have completed on the pool.
+    pub scope_count: u64,
+    /// The duration of the most recently completed scope.
+    pub last_scope_duration: Duration,
+    /// The accumulated duration of all completed scopes.
+    pub total_scope_duration: Duration,
+}
+
+impl TaskPoolStatistics {
+    /// Returns the fraction of thread time that was spent running tasks between `previous` and
+    /// `self`, in the `0.0..=1.0` range. Returns `0.0` if no thread time has elapsed.
+    pub fn utilization_since(&self, previous: &TaskPoolStatistics) -> f64 {
+        let busy = self.busy_time.saturating_sub(previous.busy_time);
+        let idle = self.idle_time.saturating_sub(previous.idle_time);
+        let total = (busy + idle).as_secs_f64();
+        if total == 0.0 {
+            0.0
+        } else {
+            (busy.as_secs_f64() / total).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Returns the average duration of the scopes completed between `previous` and `self`, or
+    /// [`None`] if no scope has completed in that period.
+    pub fn average_scope_duration_since(&self, previous: &TaskPoolStatistics) -> Option<Duration> {
+        let scopes = self.scope_count.saturating_sub(previous.scope_count);
+        if scopes == 0 {
+            return None;
+        }
+        let total = self
+            .total_scope_duration
+            .saturating_sub(previous.total_scope_duration);
+        Some(total.div_f64(scopes as f64))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use counters::{Instrumented, TaskPoolCounters};
+
+#[cfg(not(target_arch = "wasm32"))]
+mod counters {
+    use std::{
+        future::Future,
+        pin::Pin,
+        sync::{
+            atomic::{AtomicU64, AtomicUsize, Ordering},
+            Arc,
+        },
+        task::{Context, Poll},
+        time::{Duration, Instant},
+    };
+
+    /// Lock-free counters updated by the threads of a [`TaskPool`](crate::TaskPool) and the
+    /// tasks spawned on it.
+    #[derive(Debug, Default)]
+    pub(crate) struct TaskPoolCounters {
+        queued_tasks: AtomicUsize,
+        active_threads: AtomicUsize,
+        busy_nanos: AtomicU64,
+        retired_thread_nanos: AtomicU64,
+        scope_count: AtomicU64,
+        last_scope_nanos: AtomicU64,
+        total_scope_nanos: AtomicU64,
+    }
+
+    impl TaskPoolCounters {
+        /// Records that a worker thread that was alive for `lifetime` has shut down.
+        pub(crate) fn retire_thread(&self, lifetime: Duration) {
+            self.retired_thread_nanos
+                .fetch_add(lifetime.as_nanos() as u64, Ordering::Relaxed);
+        }
+
+        /// Records the completion of a scope that ran for `duration`.
+        pub(crate) fn record_scope(&self, duration: Duration) {
+            let nanos = duration.as_nanos() as u64;
+            self.scope_count.fetch_add(1, Ordering::Relaxed);
+            self.last_scope_nanos.store(nanos, Ordering::Relaxed);
+            self.total_scope_nanos.fetch_add(nanos, Ordering::Relaxed);
+        }
+
+        /// Builds a [`TaskPoolStatistics`](super::TaskPoolStatistics) snapshot. `live_thread_time`
+        /// is the accumulated lifetime of the threads that are still running.
+        pub(crate) fn snapshot(
+            &self,
+            thread_num: usize,
+            live_thread_time: Duration,
+        ) -> super::TaskPoolStatistics {
+            let busy_time = Duration::from_nanos(self.busy_nanos.load(Ordering::Relaxed));
+            let thread_time = live_thread_time
+                + Duration::from_nanos(self.retired_thread_nanos.load(Ordering::Relaxed));
+            super::TaskPoolStatistics {
+                thread_num,
+                queued_tasks: self.queued_tasks.load(Ordering::Relaxed),
+                active_threads: self.active_threads.load(Ordering::Relaxed),
+                busy_time,
+                idle_time: thread_time.saturating_sub(busy_time),
+                scope_count: self.scope_count.load(Ordering::Relaxed),
+                last_scope_duration: Duration::from_nanos(
+                    self.last_scope_nanos.load(Ordering::Relaxed),
+                ),
+                total_scope_duration: Duration::from_nanos(
+                    self.total_scope_nanos.load(Ordering::Relaxed),
+                ),
+            }
+        }
+    }
+
+    /// Decrements the active thread count when dropped, even if the task being polled panics.
+    struct ActiveGuard<'a> {
+        counters: &'a TaskPoolCounters,
+        start: Instant,
+    }
+
+    impl<'a> Drop for ActiveGuard<'a> {
+        fn drop(&mut self) {
+            self.counters
+                .busy_nanos
+                .fetch_add(self.start.elapsed().as_nanos() as u64, Ordering::Relaxed);
+            self.counters.active_threads.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Wraps a future spawned on a [`TaskPool`](crate::TaskPool) so that time spent polling it is
+    /// reported to the pool's [`TaskPoolCounters`].
+    pub(crate) struct Instrumented<F> {
+        future: F,
+        counters: Arc<TaskPoolCounters>,
+        started: bool,
+    }
+
+    impl<F> Instrumented<F> {
+        pub(crate) fn new(future: F, counters: Arc<TaskPoolCounters>) -> Self {
+            counters.queued_tasks.fetch_add(1, Ordering::Relaxed);
+            Self {
+                future,
+                counters,
+                started: false,
+            }
+        }
+    }
+
+    impl<F: Future> Future for Instrumented<F> {
+        type Output = F::Output;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            // SAFETY: `future` is structurally pinned and is never moved out of `self`. The other
+            // fields are not pinned.
+            let this = unsafe { self.get_unchecked_mut() };
+            if !this.started {
+                this.started = true;
+                this.counters.queued_tasks.fetch_sub(1, Ordering::Relaxed);
+            }
+
+            this.counters.active_threads.fetch_add(1, Ordering::Relaxed);
+            let _guard = ActiveGuard {
+                counters: &this.counters,
+                start: Instant::now(),
+            };
+            // SAFETY: see above.
+            unsafe { Pin::new_unchecked(&mut this.future) }.poll(cx)
+        }
+    }
+
+    impl<F> Drop for Instrumented<F> {
+        fn drop(&mut self) {
+            // Tasks that are cancelled before they are first polled are no longer queued.
+            if !self.started {
+                self.counters.queued_tasks.fetch_sub(1, Ordering::Relaxed);
+            }
+        }
+    }
+}
@@ -1,10 +1,13 @@
 use std::{
+    fmt,
     future::Future,
     marker::PhantomData,
     mem,
     panic::AssertUnwindSafe,
-    sync::Arc,
+    pin::Pin,
+    sync::{Arc, Mutex},
     thread::{self, JoinHandle},
+    time::{Duration, Instant},
 };
 
 use async_task::FallibleTask;
@@ -12,8 +15,9 @@ use concurrent_queue::ConcurrentQueue;
 use futures_lite::{future, FutureExt};
 
 use crate::{
+    statistics::{Instrumented, TaskPoolCounters},
     thread_executor::{ThreadExecutor, ThreadExecutorTicker},
-    Task,
+    Task, TaskPoolStatistics,
 };
 
 struct CallOnDrop(Option<Arc<dyn Fn() + Send + Sync + 'static>>);
@@ -38,6 +42,8 @@ pub struct TaskPoolBuilder {
     /// Allows customizing the name of the threads - helpful for debugging. If set, threads will
     /// be named <thread_name> (<thread_index>), i.e. "MyThreadPool (2)"
     thread_name: Option<String>,
+    /// Executors of other pools that idle threads of this pool are allowed to run tasks from
+    steal_from: Vec<Arc<async_executor::Executor<'static>>>,
 
     on_thread_spawn: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
     on_thread_destroy: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
@@ -69,6 +75,16 @@ impl TaskPoolBuilder {
         self
     }
 
+    /// Allows the threads of the created pool to run tasks spawned on `pool` whenever they have
+    /// no work of their own.
+    ///
+    /// Tasks stolen this way still count towards the [`TaskPoolStatistics`] of `pool`. Work is
+    /// only stolen in one direction: the threads of `pool` never run tasks of the created pool.
+    pub fn steal_from(mut self, pool: &TaskPool) -> Self {
+        self.steal_from.push(Arc::clone(&pool.executor));
+        self
+    }
+
     /// Sets a callback that is invoked once for every created thread as it starts.
     ///
     /// This is called on the thread itself and has access to all thread-local storage.
@@ -93,9 +109,16 @@ impl TaskPoolBuilder {
     }
 }
 
+/// A thread owned by a [`TaskPool`], along with the channel used to shut it down.
+#[derive(Debug)]
+struct WorkerThread {
+    join_handle: JoinHandle<()>,
+    shutdown_tx: async_channel::Sender<()>,
+    spawned_at: Instant,
+}
+
 /// A thread pool for executing tasks. Tasks are futures that are being automatically driven by
 /// the pool on threads owned by the pool.
-#[derive(Debug)]
 pub struct TaskPool {
     /// The executor for the pool
     ///
@@ -105,8 +128,27 @@ pub struct TaskPool {
     executor: Arc<async_executor::Executor<'static>>,
 
     /// Inner state of the pool
-    threads: Vec<JoinHandle<()>>,
-    shutdown_tx: async_channel::Sender<()>,
+    threads: Mutex<Vec<WorkerThread>>,
+    counters: Arc<TaskPoolCounters>,
+
+    /// Configuration used to spawn new threads when the pool is resized
+    stack_size: Option<usize>,
+    thread_name: Option<String>,
+    steal_from: Vec<Arc<async_executor::Executor<'static>>>,
+    on_thread_spawn: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
+    on_thread_destroy: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
+}
+
+impl fmt::Debug for TaskPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaskPool")
+            .field("executor", &self.executor)
+            .field("threads", &self.threads)
+            .field("counters", &self.counters)
+            .field("stack_size", &self.stack_size)
+            .field("thread_name", &self.thread_name)
+            .finish_non_exhaustive()
+    }
 }
 
 impl TaskPool {
@@ -121,72 +163,131 @@ impl TaskPool {
     }
 
     fn new_internal(builder: TaskPoolBuilder) -> Self {
-        let (shutdown_tx, shutdown_rx) = async_channel::unbounded::<()>();
-
-        let executor = Arc::new(async_executor::Executor::new());
-
         let num_threads = builder
             .num_threads
             .unwrap_or_else(crate::available_parallelism);
 
-        let threads = (0..num_threads)
-            .map(|i| {
-                let ex = Arc::clone(&executor);
-                let shutdown_rx = shutdown_rx.clone();
+        let pool = Self {
+            executor: Arc::new(async_executor::Executor::new()),
+            threads: Mutex::new(Vec::with_capacity(num_threads)),
+            counters: Arc::default(),
+            stack_size: builder.stack_size,
+            thread_name: builder.thread_name,
+            steal_from: builder.steal_from,
+            on_thread_spawn: builder.on_thread_spawn,
+            on_thread_destroy: builder.on_thread_destroy,
+        };
+        pool.set_thread_num(num_threads);
+        pool
+    }
+
+    fn spawn_thread(&self, index: usize) -> WorkerThread {
+        let (shutdown_tx, shutdown_rx) = async_channel::bounded::<()>(1);
+        let ex = Arc::clone(&self.executor);
+        let steal_from = self.steal_from.clone();
 
-                let thread_name = if let Some(thread_name) = builder.thread_name.as_deref() {
-                    format!("{thread_name} ({i})")
-                } else {
-                    format!("TaskPool ({i})")
-                };
-                let mut thread_builder = thread::Builder::new().name(thread_name);
+        let thread_name = if let Some(thread_name) = self.thread_name.as_deref() {
+            format!("{thread_name} ({index})")
+        } else {
+            format!("TaskPool ({index})")
+        };
+        let mut thread_builder = thread::Builder::new().name(thread_name);
 
-                if let Some(stack_size) = builder.stack_size {
-                    thread_builder = thread_builder.stack_size(stack_size);
-                }
+        if let Some(stack_size) = self.stack_size {
+            thread_builder = thread_builder.stack_size(stack_size);
+        }
 
-                let on_thread_spawn = builder.on_thread_spawn.clone();
-                let on_thread_destroy = builder.on_thread_destroy.clone();
+        let on_thread_spawn = self.on_thread_spawn.clone();
+        let on_thread_destroy = self.on_thread_destroy.clone();
 
-                thread_builder
-                    .spawn(move || {
-                        TaskPool::LOCAL_EXECUTOR.with(|local_executor| {
-                            if let Some(on_thread_spawn) = on_thread_spawn {
-                                on_thread_spawn();
-                                drop(on_thread_spawn);
-                            }
-                            let _destructor = CallOnDrop(on_thread_destroy);
-                            loop {
-                                let res = std::panic::catch_unwind(|| {
-                                    let tick_forever = async move {
-                                        loop {
-                                            local_executor.tick().await;
-                                        }
-                                    };
-                                    future::block_on(ex.run(tick_forever.or(shutdown_rx.recv())))
-                                });
-                                if let Ok(value) = res {
-                                    // Use unwrap_err because we expect a Closed error
-                                    value.unwrap_err();
-                                    break;
+        let join_handle = thread_builder
+            .spawn(move || {
+                TaskPool::LOCAL_EXECUTOR.with(|local_executor| {
+                    if let Some(on_thread_spawn) = on_thread_spawn {
+                        on_thread_spawn();
+                        drop(on_thread_spawn);
+                    }
+                    let _destructor = CallOnDrop(on_thread_destroy);
+                    loop {
+                        let res = std::panic::catch_unwind(|| {
+                            let tick_forever = async move {
+                                loop {
+                                    local_executor.tick().await;
                                 }
+                            };
+                            // Register this thread as a runner of every executor it may steal
+                            // from, so it picks up their tasks while its own executor is idle.
+                            let mut run: Pin<Box<dyn Future<Output = _>>> =
+                                Box::pin(tick_forever.or(shutdown_rx.recv()));
+                            for other in &steal_from {
+                                run = Box::pin(other.run(run));
                             }
+                            future::block_on(ex.run(run))
                         });
-                    })
-                    .expect("Failed to spawn thread.")
+                        if let Ok(value) = res {
+                            // Use unwrap_err because we expect a Closed error
+                            value.unwrap_err();
+                            break;
+                        }
+                    }
+                });
             })
-            .collect();
+            .expect("Failed to spawn thread.");
 
-        Self {
-            executor,
-            threads,
+        WorkerThread {
+            join_handle,
             shutdown_tx,
+            spawned_at: Instant::now(),
         }
     }
 
     /// Return the number of threads owned by the task pool
     pub fn thread_num(&self) -> usize {
-        self.threads.len()
+        self.threads.lock().unwrap().len()
+    }
+
+    /// Resizes the pool so that it owns exactly `num_threads` threads.
+    ///
+    /// Growing the pool spawns new threads configured like the existing ones. Shrinking it shuts
+    /// down the most recently spawned threads, blocking until they have finished the task they are
+    /// currently running. Tasks that have not started yet stay queued and are run by the remaining
+    /// threads.
+    pub fn set_thread_num(&self, num_threads: usize) {
+        let retired = {
+            let mut threads = self.threads.lock().unwrap();
+            while threads.len() < num_threads {
+                let thread = self.spawn_thread(threads.len());
+                threads.push(thread);
+            }
+            let retained = num_threads.min(threads.len());
+            threads.split_off(retained)
+        };
+        self.shutdown_threads(retired);
+    }
+
+    fn shutdown_threads(&self, threads: Vec<WorkerThread>) {
+        for thread in &threads {
+            thread.shutdown_tx.close();
+        }
+
+        let panicking = thread::panicking();
+        for thread in threads {
+            let res = thread.join_handle.join();
+            self.counters.retire_thread(thread.spawned_at.elapsed());
+            if !panicking {
+                res.expect("Task thread panicked while executing.");
+            }
+        }
+    }
+
+    /// Returns a snapshot of the runtime statistics of this pool.
+    pub fn statistics(&self) -> TaskPoolStatistics {
+        let threads = self.threads.lock().unwrap();
+        let live_thread_time = threads
+            .iter()
+            .map(|thread| thread.spawned_at.elapsed())
+            .sum::<Duration>();
+        self.counters.snapshot(threads.len(), live_thread_time)
     }
 
     /// Allows spawning non-`'static` futures on the thread pool. The function takes a callback,
@@ -340,6 +441,7 @@ impl TaskPool {
         // the transmuted reference for the rest of this function.
         let executor: &async_executor::Executor = &self.executor;
         let executor: &'env async_executor::Executor = unsafe { mem::transmute(executor) };
+        let counters: &'env Arc<TaskPoolCounters> = unsafe { mem::transmute(&self.counters) };
         let external_executor: &'env ThreadExecutor<'env> =
             unsafe { mem::transmute(external_executor) };
         let scope_executor: &'env ThreadExecutor<'env> = unsafe { mem::transmute(scope_executor) };
@@ -347,8 +449,10 @@ impl TaskPool {
         // shadow the variable so that the owned value cannot be used for the rest of the function
         let spawned: &'env ConcurrentQueue<FallibleTask<T>> = unsafe { mem::transmute(&spawned) };
 
+        let scope_start = Instant::now();
         let scope = Scope {
             executor,
+            counters,
             external_executor,
             scope_executor,
             spawned,
@@ -361,7 +465,7 @@ impl TaskPool {
 
         f(scope);
 
-        if spawned.is_empty() {
+        let results = if spawned.is_empty() {
             Vec::new()
         } else {
             future::block_on(async move {
@@ -373,7 +477,7 @@ impl TaskPool {
                     results
                 };
 
-                let tick_task_pool_executor = tick_task_pool_executor || self.threads.is_empty();
+                let tick_task_pool_executor = tick_task_pool_executor || self.thread_num() == 0;
 
                 // we get this from a thread local so we should always be on the scope executors thread.
                 let scope_ticker = scope_executor.ticker().unwrap();
@@ -396,7 +500,9 @@ impl TaskPool {
                     Self::execute_scope(scope_ticker, get_results).await
                 }
             })
-        }
+        };
+        self.counters.record_scope(scope_start.elapsed());
+        results
     }
 
     #[inline]
@@ -494,7 +600,10 @@ impl TaskPool {
     where
         T: Send + 'static,
     {
-        Task::new(self.executor.spawn(future))
+        Task::new(
+            self.executor
+                .spawn(Instrumented::new(future, Arc::clone(&self.counters))),
+        )
     }
 
     /// Spawns a static future on the thread-local async executor for the current thread. The task
@@ -536,15 +645,8 @@ impl Default for TaskPool {
 
 impl Drop for TaskPool {
     fn drop(&mut self) {
-        self.shutdown_tx.close();
-
-        let panicking = thread::panicking();
-        for join_handle in self.threads.drain(..) {
-            let res = join_handle.join();
-            if !panicking {
-                res.expect("Task thread panicked while executing.");
-            }
-        }
+        let threads = mem::take(self.threads.get_mut().unwrap());
+        self.shutdown_threads(threads);
     }
 }
 
@@ -554,6 +656,7 @@ impl Drop for TaskPool {
 #[derive(Debug)]
 pub struct Scope<'scope, 'env: 'scope, T> {
     executor: &'scope async_executor::Executor<'scope>,
+    counters: &'scope Arc<TaskPoolCounters>,
     external_executor: &'scope ThreadExecutor<'scope>,
     scope_executor: &'scope ThreadExecutor<'scope>,
     spawned: &'scope ConcurrentQueue<FallibleTask<T>>,
@@ -572,7 +675,10 @@ impl<'scope, 'env, T: Send + 'scope> Scope<'scope, 'env, T> {
     ///
     /// For more information, see [`TaskPool::scope`].
     pub fn spawn<Fut: Future<Output = T> + 'scope + Send>(&self, f: Fut) {
-        let task = self.executor.spawn(f).fallible();
+        let task = self
+            .executor
+            .spawn(Instrumented::new(f, Arc::clone(self.counters)))
+            .fallible();
         // ConcurrentQueue only errors when closed or full, but we never
         // close and use an unbounded queue, so it is safe to unwrap
         self.spawned.push(task).unwrap();
@@ -867,4 +973,91 @@ mod tests {
         assert!(!thread_check_failed.load(Ordering::Acquire));
         assert_eq!(count.load(Ordering::Acquire), 200);
     }
+
+    #[test]
+    fn test_set_thread_num() {
+        let counter = Arc::new(AtomicI32::new(0));
+        let start_counter = counter.clone();
+        let end_counter = counter.clone();
+        let pool = TaskPoolBuilder::new()
+            .num_threads(2)
+            .on_thread_spawn(move || {
+                start_counter.fetch_add(1, Ordering::Relaxed);
+            })
+            .on_thread_destroy(move || {
+                end_counter.fetch_sub(1, Ordering::Relaxed);
+            })
+            .build();
+        assert_eq!(pool.thread_num(), 2);
+
+        pool.set_thread_num(5);
+        assert_eq!(pool.thread_num(), 5);
+        let outputs = pool.scope(|scope| {
+            for i in 0..10 {
+                scope.spawn(async move { i });
+            }
+        });
+        assert_eq!(outputs.len(), 10);
+
+        pool.set_thread_num(1);
+        assert_eq!(pool.thread_num(), 1);
+        // Threads that were shut down have run their destroy callback.
+        assert_eq!(pool.statistics().thread_num, 1);
+        drop(pool);
+        assert_eq!(counter.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn test_statistics() {
+        let pool = TaskPoolBuilder::new().num_threads(2).build();
+        let before = pool.statistics();
+        assert_eq!(before.thread_num, 2);
+        assert_eq!(before.scope_count, 0);
+
+        pool.scope(|scope| {
+            for _ in 0..10 {
+                scope.spawn(async {
+                    std::thread::sleep(Duration::from_millis(1));
+                });
+            }
+        });
+
+        let after = pool.statistics();
+        assert_eq!(after.scope_count, 1);
+        assert_eq!(after.queued_tasks, 0);
+        assert_eq!(after.active_threads, 0);
+        assert!(after.busy_time >= Duration::from_millis(10));
+        assert!(after.last_scope_duration > Duration::ZERO);
+        assert_eq!(after.last_scope_duration, after.total_scope_duration);
+        assert!(after.utilization_since(&before) > 0.0);
+        assert_eq!(
+            after.average_scope_duration_since(&before),
+            Some(after.last_scope_duration)
+        );
+        assert_eq!(after.average_scope_duration_since(&after), None);
+    }
+
+    #[test]
+    fn test_steal_from() {
+        // A pool without threads never runs its own tasks, so the task can only complete if it is
+        // stolen by the other pool.
+        let idle_pool = TaskPoolBuilder::new().num_threads(0).build();
+        let stealing_pool = TaskPoolBuilder::new()
+            .num_threads(1)
+            .steal_from(&idle_pool)
+            .build();
+
+        let (tx, rx) = async_channel::bounded(1);
+        idle_pool
+            .spawn(async move {
+                tx.send(std::thread::current().name().map(str::to_owned))
+                    .await
+                    .unwrap();
+            })
+            .detach();
+
+        let thread_name = future::block_on(rx.recv()).unwrap();
+        assert_eq!(thread_name.as_deref(), Some("TaskPool (0)"));
+        drop(stealing_pool);
+    }
 }
@@ -1,9 +1,10 @@
 use crate::Font;
 use anyhow::Result;
 use bevy_asset::{AssetLoader, LoadContext, LoadedAsset};
-use bevy_utils::BoxedFuture;
+use bevy_reflect::{TypePath, TypeUuid};
+use bevy_utils::{BoxedFuture, Uuid};
 
-#[derive(Default)]
+#[derive(Default, TypePath)]
 pub struct FontLoader;
 
 impl AssetLoader for FontLoader {
@@ -22,4 +23,8 @@ impl AssetLoader for FontLoader {
     fn extensions(&self) -> &[&str] {
         &["ttf", "otf"]
     }
+
+    fn asset_type_uuid(&self) -> Option<Uuid> {
+        Some(Font::TYPE_UUID)
+    }
 }
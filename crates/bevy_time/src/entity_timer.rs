@@ -0,0 +1,494 @@
+use crate::{fixed_timestep::Fixed, Real, Stopwatch, Time, Timer, TimerMode, Virtual};
+use bevy_ecs::{prelude::*, reflect::ReflectComponent};
+use bevy_reflect::prelude::*;
+use bevy_utils::Duration;
+use std::f32::consts::PI;
+
+/// The clock used to tick an [`EntityTimer`] or an [`EntityStopwatch`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default, Reflect, FromReflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default)]
+pub enum TimerClock {
+    /// Ticked with [`Time<Real>`], keeps running while virtual time is paused.
+    Real,
+    /// Ticked with [`Time<Virtual>`], follows pausing and scaling of game time.
+    #[default]
+    Virtual,
+    /// Ticked with [`Time<Fixed>`] every time the
+    /// [`CoreSchedule::FixedUpdate`](bevy_app::CoreSchedule::FixedUpdate) schedule runs.
+    Fixed,
+}
+
+/// An easing curve applied to the progress of an [`EntityTimer`].
+///
+/// See <https://easings.net> for a visual reference of the curves.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Reflect, FromReflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default)]
+pub enum TimerEasing {
+    /// Progress advances at a constant rate.
+    #[default]
+    Linear,
+    /// Progress starts slowly and accelerates.
+    QuadraticIn,
+    /// Progress starts quickly and decelerates.
+    QuadraticOut,
+    /// Progress accelerates until the midpoint, then decelerates.
+    QuadraticInOut,
+    /// Like [`TimerEasing::QuadraticIn`], with a sharper curve.
+    CubicIn,
+    /// Like [`TimerEasing::QuadraticOut`], with a sharper curve.
+    CubicOut,
+    /// Like [`TimerEasing::QuadraticInOut`], with a sharper curve.
+    CubicInOut,
+    /// Progress follows the first quarter of a sine wave.
+    SineIn,
+    /// Progress follows the second quarter of a sine wave.
+    SineOut,
+    /// Progress follows half of a cosine wave.
+    SineInOut,
+}
+
+impl TimerEasing {
+    /// Applies the easing curve to `t`, which is clamped to the `0.0..=1.0` range.
+    pub fn ease(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            TimerEasing::Linear => t,
+            TimerEasing::QuadraticIn => t * t,
+            TimerEasing::QuadraticOut => 1.0 - (1.0 - t) * (1.0 - t),
+            TimerEasing::QuadraticInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+            TimerEasing::CubicIn => t * t * t,
+            TimerEasing::CubicOut => 1.0 - (1.0 - t).powi(3),
+            TimerEasing::CubicInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            TimerEasing::SineIn => 1.0 - (t * PI / 2.0).cos(),
+            TimerEasing::SineOut => (t * PI / 2.0).sin(),
+            TimerEasing::SineInOut => -((PI * t).cos() - 1.0) / 2.0,
+        }
+    }
+}
+
+/// A [`Timer`] component that is ticked automatically by the [`TimePlugin`](crate::TimePlugin).
+///
+/// The timer is ticked with the [`TimerClock`] it is configured with, and a [`TimerFinished`]
+/// event is sent every time it finishes.
+///
+/// On top of the plain [`Timer`], this supports:
+/// - a delay before the timer starts ticking,
+/// - a maximum number of repetitions for [`TimerMode::Repeating`] timers,
+/// - an easing curve applied to [`EntityTimer::progress`].
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::*;
+/// # use bevy_utils::Duration;
+/// fn spawn_blinker(mut commands: Commands) {
+///     commands.spawn(
+///         // blink three times, starting in half a second, even while the game is paused
+///         EntityTimer::new(Timer::from_seconds(0.2, TimerMode::Repeating))
+///             .with_repeat_count(3)
+///             .with_delay(Duration::from_millis(500))
+///             .with_clock(TimerClock::Real),
+///     );
+/// }
+///
+/// fn blink(mut finished: EventReader<TimerFinished>) {
+///     for event in finished.iter() {
+///         println!("{:?} blinked", event.entity);
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(spawn_blinker);
+/// # bevy_ecs::system::assert_is_system(blink);
+/// ```
+#[derive(Component, Clone, Debug, Default, Reflect, FromReflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default)]
+pub struct EntityTimer {
+    /// The wrapped timer.
+    pub timer: Timer,
+    /// The clock the timer is ticked with.
+    pub clock: TimerClock,
+    /// The number of times a [`TimerMode::Repeating`] timer finishes before it stops ticking,
+    /// or [`None`] to repeat forever. Ignored by [`TimerMode::Once`] timers.
+    pub repeat_count: Option<u32>,
+    /// How much time must pass before the timer starts ticking.
+    pub delay: Duration,
+    /// The easing curve applied to [`EntityTimer::progress`].
+    pub easing: TimerEasing,
+    delay_elapsed: Duration,
+    times_finished: u32,
+    times_finished_this_tick: u32,
+}
+
+impl EntityTimer {
+    /// Creates a new timer component ticked with [`TimerClock::Virtual`].
+    pub fn new(timer: Timer) -> Self {
+        Self {
+            timer,
+            ..Default::default()
+        }
+    }
+
+    /// Returns this timer ticked with the given `clock`.
+    #[must_use]
+    pub fn with_clock(mut self, clock: TimerClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns this timer limited to finishing `repeat_count` times.
+    #[must_use]
+    pub fn with_repeat_count(mut self, repeat_count: u32) -> Self {
+        self.repeat_count = Some(repeat_count);
+        self
+    }
+
+    /// Returns this timer with a `delay` before it starts ticking.
+    #[must_use]
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Returns this timer with an `easing` curve applied to its progress.
+    #[must_use]
+    pub fn with_easing(mut self, easing: TimerEasing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Advances the timer by `delta`, first expending the remaining delay.
+    ///
+    /// Returns the number of times the timer finished during this tick.
+    pub fn tick(&mut self, delta: Duration) -> u32 {
+        self.times_finished_this_tick = 0;
+        if self.timer.paused() || self.is_completed() {
+            return 0;
+        }
+
+        let remaining_delay = self.delay.saturating_sub(self.delay_elapsed);
+        if delta < remaining_delay {
+            self.delay_elapsed += delta;
+            return 0;
+        }
+        self.delay_elapsed = self.delay;
+
+        self.timer.tick(delta - remaining_delay);
+        let mut finished = self.timer.times_finished_this_tick();
+        if let (TimerMode::Repeating, Some(repeat_count)) = (self.timer.mode(), self.repeat_count) {
+            let remaining = repeat_count.saturating_sub(self.times_finished);
+            if finished >= remaining {
+                finished = remaining;
+                let duration = self.timer.duration();
+                self.timer.set_elapsed(duration);
+            }
+        }
+
+        self.times_finished += finished;
+        self.times_finished_this_tick = finished;
+        finished
+    }
+
+    /// Resets the timer, its delay and its repetitions.
+    pub fn reset(&mut self) {
+        self.timer.reset();
+        self.delay_elapsed = Duration::ZERO;
+        self.times_finished = 0;
+        self.times_finished_this_tick = 0;
+    }
+
+    /// Returns `true` while the delay before the timer starts hasn't elapsed.
+    pub fn is_delayed(&self) -> bool {
+        self.delay_elapsed < self.delay
+    }
+
+    /// Returns `true` if the timer won't finish anymore: a [`TimerMode::Once`] timer that has
+    /// finished, or a [`TimerMode::Repeating`] timer that has finished
+    /// [`repeat_count`](Self::repeat_count) times.
+    pub fn is_completed(&self) -> bool {
+        match self.timer.mode() {
+            TimerMode::Once => self.timer.finished(),
+            TimerMode::Repeating => {
+                matches!(self.repeat_count, Some(repeat_count) if self.times_finished >= repeat_count)
+            }
+        }
+    }
+
+    /// Returns `true` if the timer finished during the last tick.
+    pub fn just_finished(&self) -> bool {
+        self.times_finished_this_tick > 0
+    }
+
+    /// Returns the number of times the timer finished during the last tick.
+    pub fn times_finished_this_tick(&self) -> u32 {
+        self.times_finished_this_tick
+    }
+
+    /// Returns the number of times the timer finished since it was created or last reset.
+    pub fn times_finished(&self) -> u32 {
+        self.times_finished
+    }
+
+    /// Returns the progress of the current repetition of the timer with the
+    /// [`easing`](Self::easing) curve applied, from `0.0` to `1.0`.
+    pub fn progress(&self) -> f32 {
+        self.easing.ease(self.timer.percent())
+    }
+}
+
+/// A [`Stopwatch`] component that is ticked automatically by the
+/// [`TimePlugin`](crate::TimePlugin) with the [`TimerClock`] it is configured with.
+#[derive(Component, Clone, Debug, Default, Reflect, FromReflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default)]
+pub struct EntityStopwatch {
+    /// The wrapped stopwatch.
+    pub stopwatch: Stopwatch,
+    /// The clock the stopwatch is ticked with.
+    pub clock: TimerClock,
+}
+
+impl EntityStopwatch {
+    /// Creates a new stopwatch component ticked with the given `clock`.
+    pub fn new(clock: TimerClock) -> Self {
+        Self {
+            stopwatch: Stopwatch::new(),
+            clock,
+        }
+    }
+}
+
+/// An event sent every time an [`EntityTimer`] finishes.
+///
+/// A repeating timer that finishes several times during a single tick sends one event per
+/// repetition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimerFinished {
+    /// The entity of the [`EntityTimer`] that finished.
+    pub entity: Entity,
+}
+
+fn tick_timers(
+    clock: TimerClock,
+    delta: Duration,
+    timers: &mut Query<(Entity, &mut EntityTimer)>,
+    stopwatches: &mut Query<&mut EntityStopwatch>,
+    finished: &mut EventWriter<TimerFinished>,
+) {
+    for (entity, mut timer) in timers {
+        if timer.clock == clock {
+            let times = timer.tick(delta);
+            finished.send_batch((0..times).map(|_| TimerFinished { entity }));
+        }
+    }
+    for mut stopwatch in stopwatches {
+        if stopwatch.clock == clock {
+            stopwatch.stopwatch.tick(delta);
+        }
+    }
+}
+
+/// Ticks the [`EntityTimer`] and [`EntityStopwatch`] components that use [`TimerClock::Real`]
+/// or [`TimerClock::Virtual`], and sends [`TimerFinished`] events.
+pub fn tick_entity_timers(
+    real_time: Res<Time<Real>>,
+    virtual_time: Res<Time<Virtual>>,
+    mut timers: Query<(Entity, &mut EntityTimer)>,
+    mut stopwatches: Query<&mut EntityStopwatch>,
+    mut finished: EventWriter<TimerFinished>,
+) {
+    for (clock, delta) in [
+        (TimerClock::Real, real_time.delta()),
+        (TimerClock::Virtual, virtual_time.delta()),
+    ] {
+        tick_timers(clock, delta, &mut timers, &mut stopwatches, &mut finished);
+    }
+}
+
+/// Ticks the [`EntityTimer`] and [`EntityStopwatch`] components that use [`TimerClock::Fixed`],
+/// and sends [`TimerFinished`] events.
+///
+/// This runs in the [`CoreSchedule::FixedUpdate`](bevy_app::CoreSchedule::FixedUpdate) schedule.
+pub fn tick_fixed_entity_timers(
+    fixed_time: Res<Time<Fixed>>,
+    mut timers: Query<(Entity, &mut EntityTimer)>,
+    mut stopwatches: Query<&mut EntityStopwatch>,
+    mut finished: EventWriter<TimerFinished>,
+) {
+    tick_timers(
+        TimerClock::Fixed,
+        fixed_time.delta(),
+        &mut timers,
+        &mut stopwatches,
+        &mut finished,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixed_timestep::FixedTime, TimePlugin, TimeUpdateStrategy};
+    use bevy_app::prelude::*;
+
+    #[test]
+    fn delay_is_expended_first() {
+        let mut timer = EntityTimer::new(Timer::from_seconds(1.0, TimerMode::Once))
+            .with_delay(Duration::from_secs(1));
+
+        assert_eq!(timer.tick(Duration::from_millis(500)), 0);
+        assert!(timer.is_delayed());
+        assert_eq!(timer.timer.elapsed(), Duration::ZERO);
+
+        assert_eq!(timer.tick(Duration::from_millis(750)), 0);
+        assert!(!timer.is_delayed());
+        assert_eq!(timer.timer.elapsed(), Duration::from_millis(250));
+
+        assert_eq!(timer.tick(Duration::from_millis(750)), 1);
+        assert!(timer.just_finished());
+        assert!(timer.is_completed());
+    }
+
+    #[test]
+    fn repeat_count_limits_repetitions() {
+        let mut timer =
+            EntityTimer::new(Timer::from_seconds(1.0, TimerMode::Repeating)).with_repeat_count(3);
+
+        assert_eq!(timer.tick(Duration::from_secs(2)), 2);
+        assert!(!timer.is_completed());
+        assert_eq!(timer.tick(Duration::from_secs(5)), 1);
+        assert!(timer.is_completed());
+        assert_eq!(timer.times_finished(), 3);
+        assert_eq!(timer.progress(), 1.0);
+
+        assert_eq!(timer.tick(Duration::from_secs(1)), 0);
+        assert!(!timer.just_finished());
+
+        timer.reset();
+        assert!(!timer.is_completed());
+        assert_eq!(timer.tick(Duration::from_secs(1)), 1);
+    }
+
+    #[test]
+    fn easing_is_applied_to_progress() {
+        let mut timer = EntityTimer::new(Timer::from_seconds(2.0, TimerMode::Once))
+            .with_easing(TimerEasing::QuadraticIn);
+        timer.tick(Duration::from_secs(1));
+        assert_eq!(timer.progress(), 0.25);
+
+        for easing in [
+            TimerEasing::Linear,
+            TimerEasing::QuadraticIn,
+            TimerEasing::QuadraticOut,
+            TimerEasing::QuadraticInOut,
+            TimerEasing::CubicIn,
+            TimerEasing::CubicOut,
+            TimerEasing::CubicInOut,
+            TimerEasing::SineIn,
+            TimerEasing::SineOut,
+            TimerEasing::SineInOut,
+        ] {
+            assert!(easing.ease(0.0).abs() < 1e-6, "{easing:?}");
+            assert!((easing.ease(1.0) - 1.0).abs() < 1e-6, "{easing:?}");
+        }
+    }
+
+    #[derive(Resource, Default)]
+    struct FinishedEntities(Vec<Entity>);
+
+    fn collect_finished(
+        mut events: EventReader<TimerFinished>,
+        mut finished: ResMut<FinishedEntities>,
+    ) {
+        finished.0.extend(events.iter().map(|event| event.entity));
+    }
+
+    #[test]
+    fn timers_are_ticked_with_their_clock() {
+        let mut app = App::new();
+        app.add_plugin(TimePlugin)
+            .insert_resource(FixedTime::new(Duration::from_millis(100)))
+            .init_resource::<FinishedEntities>()
+            .add_system(collect_finished);
+
+        let timer = EntityTimer::new(Timer::new(Duration::from_millis(100), TimerMode::Repeating));
+        let real = app
+            .world
+            .spawn(timer.clone().with_clock(TimerClock::Real))
+            .id();
+        let virt = app.world.spawn(timer.clone()).id();
+        let fixed = app.world.spawn(timer.with_clock(TimerClock::Fixed)).id();
+        let stopwatch = app.world.spawn(EntityStopwatch::new(TimerClock::Real)).id();
+
+        let mut now = app.world.resource::<Time<Real>>().startup();
+        app.world.resource_mut::<Time<Virtual>>().pause();
+        for _ in 0..2 {
+            now += Duration::from_millis(150);
+            app.insert_resource(TimeUpdateStrategy::ManualInstant(now));
+            app.update();
+        }
+
+        // virtual and fixed time are paused, real time isn't
+        assert_eq!(app.world.resource::<FinishedEntities>().0, vec![real; 3]);
+        let stopwatch = app.world.get::<EntityStopwatch>(stopwatch).unwrap();
+        assert_eq!(stopwatch.stopwatch.elapsed(), Duration::from_millis(300));
+
+        app.world.resource_mut::<FinishedEntities>().0.clear();
+        app.world.resource_mut::<Time<Virtual>>().unpause();
+        now += Duration::from_millis(100);
+        app.insert_resource(TimeUpdateStrategy::ManualInstant(now));
+        app.update();
+
+        let finished = &app.world.resource::<FinishedEntities>().0;
+        assert_eq!(finished.len(), 3);
+        assert!(finished.contains(&real));
+        assert!(finished.contains(&virt));
+        assert!(finished.contains(&fixed));
+    }
+
+    #[derive(Resource, Default)]
+    struct ObservedFinishes(usize);
+
+    fn observe_finished_timers(
+        timers: Query<&EntityTimer>,
+        mut observed: ResMut<ObservedFinishes>,
+    ) {
+        observed.0 += timers.iter().filter(|timer| timer.just_finished()).count();
+    }
+
+    #[test]
+    fn fixed_timers_are_ticked_before_fixed_update_systems() {
+        let mut app = App::new();
+        app.add_plugin(TimePlugin)
+            .insert_resource(FixedTime::new(Duration::from_millis(100)))
+            .init_resource::<ObservedFinishes>()
+            .add_system_to_schedule(CoreSchedule::FixedUpdate, observe_finished_timers);
+        app.world.spawn(
+            EntityTimer::new(Timer::new(Duration::from_millis(100), TimerMode::Repeating))
+                .with_clock(TimerClock::Fixed),
+        );
+
+        let mut now = app.world.resource::<Time<Real>>().startup();
+        for _ in 0..3 {
+            now += Duration::from_millis(100);
+            app.insert_resource(TimeUpdateStrategy::ManualInstant(now));
+            app.update();
+        }
+
+        // the timer finishes on every step, which systems in the step see
+        assert_eq!(app.world.resource::<ObservedFinishes>().0, 3);
+    }
+}
@@ -18,44 +18,79 @@
 //! with ~4.167ms frames. However, the same criteria may not result in exactly 8.333ms passing
 //! between each execution.
 //!
-//! When using fixed time steps, it is advised not to rely on [`Time::delta`] or any of it's
-//! variants for game simulation, but rather use the value of [`FixedTime`] instead.
+//! While the schedule runs, the default [`Time`] resource is replaced with a copy of the
+//! [`Time<Fixed>`](Fixed) clock, which advances by exactly one period per step. Systems that read
+//! `Res<Time>` therefore see the fixed delta inside [`CoreSchedule::FixedUpdate`] and the
+//! [`Time<Virtual>`](Virtual) delta everywhere else.
+//!
+//! Time is accumulated from [`Time<Virtual>`](Virtual), so the fixed timestep respects
+//! [`Time::pause`] and [`Time::set_relative_speed`]: no steps are run while time is paused, and
+//! steps are run twice as often when time runs twice as fast.
+//!
+//! If the fixed timestep systems take longer to run than the period they simulate, more and more
+//! time accumulates every frame and the app can end up spending all of its time catching up.
+//! To avoid this, the time accumulated per frame can be capped with [`FixedTime::max_delta`],
+//! and the number of steps run per frame with [`FixedTime::max_steps_per_frame`].
+//!
+//! Time that is left over after running the steps of a frame can be used to interpolate
+//! rendered state between the two last steps through [`FixedTime::overstep_fraction`].
 
-use crate::Time;
+use crate::{Time, Virtual};
 use bevy_app::CoreSchedule;
 use bevy_ecs::{system::Resource, world::World};
+use bevy_reflect::{FromReflect, Reflect};
 use bevy_utils::Duration;
 use thiserror::Error;
 
+/// The fixed timestep game clock following virtual time.
+///
+/// A specialization of the [`Time`] structure, normally used as `Time<Fixed>`. It is
+/// automatically inserted as a resource by [`TimePlugin`](crate::TimePlugin) and advanced by
+/// one [`FixedTime::period`] every time the [`CoreSchedule::FixedUpdate`] schedule runs.
+///
+/// While [`CoreSchedule::FixedUpdate`] runs, the default [`Time`] resource is a copy of this
+/// clock, so systems in that schedule see the fixed period as their [`Time::delta`].
+#[derive(Debug, Default, Copy, Clone, Reflect, FromReflect)]
+pub struct Fixed;
+
 /// The amount of time that must pass before the fixed timstep schedule is run again.
 #[derive(Resource, Debug)]
 pub struct FixedTime {
     accumulated: Duration,
+    steps_this_frame: u32,
     /// Defaults to 1/60th of a second.
     /// To configure this value, simply mutate or overwrite this resource.
     pub period: Duration,
+    /// The maximum amount of time accumulated by a single call to [`FixedTime::tick`].
+    /// Any time beyond this is discarded, which slows down the simulation after long frames
+    /// instead of running many steps to catch up.
+    ///
+    /// Defaults to [`None`], which doesn't limit the accumulated time.
+    pub max_delta: Option<Duration>,
+    /// The maximum number of times the [`CoreSchedule::FixedUpdate`] schedule is run per frame.
+    /// When this limit is reached, any whole periods left in the accumulated time are discarded.
+    ///
+    /// Defaults to [`None`], which runs as many steps as needed to expend the accumulated time.
+    pub max_steps_per_frame: Option<u32>,
 }
 
 impl FixedTime {
     /// Creates a new [`FixedTime`] struct
     pub fn new(period: Duration) -> Self {
         FixedTime {
-            accumulated: Duration::ZERO,
             period,
+            ..Default::default()
         }
     }
 
     /// Creates a new [`FixedTime`] struct with a period specified in `f32` seconds
     pub fn new_from_secs(period: f32) -> Self {
-        FixedTime {
-            accumulated: Duration::ZERO,
-            period: Duration::from_secs_f32(period),
-        }
+        Self::new(Duration::from_secs_f32(period))
     }
 
-    /// Adds the `delta_time` to the accumulated time so far.
+    /// Adds the `delta_time` to the accumulated time so far, clamped by [`FixedTime::max_delta`].
     pub fn tick(&mut self, delta_time: Duration) {
-        self.accumulated += delta_time;
+        self.accumulated += self.max_delta.map_or(delta_time, |max| delta_time.min(max));
     }
 
     /// Returns the current amount of accumulated time
@@ -63,6 +98,42 @@ impl FixedTime {
         self.accumulated
     }
 
+    /// Returns how far the accumulated time has progressed towards the next step, as a fraction
+    /// of the period.
+    ///
+    /// After the fixed timestep schedule has run for a frame, this is in the `0.0..1.0` range and
+    /// can be used to interpolate between the states of the two last steps.
+    pub fn overstep_fraction(&self) -> f32 {
+        self.overstep_fraction_f64() as f32
+    }
+
+    /// Returns how far the accumulated time has progressed towards the next step, as a fraction
+    /// of the period.
+    ///
+    /// See [`FixedTime::overstep_fraction`] for more details.
+    pub fn overstep_fraction_f64(&self) -> f64 {
+        if self.period.is_zero() {
+            0.0
+        } else {
+            self.accumulated.as_secs_f64() / self.period.as_secs_f64()
+        }
+    }
+
+    /// Returns the number of times the [`CoreSchedule::FixedUpdate`] schedule was run during the
+    /// current frame by [`run_fixed_update_schedule`].
+    pub fn steps_this_frame(&self) -> u32 {
+        self.steps_this_frame
+    }
+
+    /// Discards all whole periods of accumulated time, keeping only the fraction of a period
+    /// that is left over.
+    pub fn discard_whole_periods(&mut self) {
+        if !self.period.is_zero() {
+            let remainder = self.accumulated.as_nanos() % self.period.as_nanos();
+            self.accumulated = Duration::from_nanos(remainder as u64);
+        }
+    }
+
     /// Expends one `period` of accumulated time.
     ///
     /// [`Err(FixedTimstepError`)] will be returned
@@ -83,7 +154,10 @@ impl Default for FixedTime {
     fn default() -> Self {
         FixedTime {
             accumulated: Duration::ZERO,
+            steps_this_frame: 0,
             period: Duration::from_secs_f32(1. / 60.),
+            max_delta: None,
+            max_steps_per_frame: None,
         }
     }
 }
@@ -99,28 +173,54 @@ pub enum FixedUpdateError {
 }
 
 /// Ticks the [`FixedTime`] resource then runs the [`CoreSchedule::FixedUpdate`].
+///
+/// Each step advances [`Time<Fixed>`](Fixed) by one period and exposes it as the default
+/// [`Time`]. Once all steps have run, the default [`Time`] is reset to
+/// [`Time<Virtual>`](Virtual).
 pub fn run_fixed_update_schedule(world: &mut World) {
     // Tick the time
-    let delta_time = world.resource::<Time>().delta();
+    let delta_time = world.resource::<Time<Virtual>>().delta();
     let mut fixed_time = world.resource_mut::<FixedTime>();
     fixed_time.tick(delta_time);
+    fixed_time.steps_this_frame = 0;
 
     // Run the schedule until we run out of accumulated time
-    let mut check_again = true;
-    while check_again {
+    loop {
         let mut fixed_time = world.resource_mut::<FixedTime>();
-        let fixed_time_run = fixed_time.expend().is_ok();
-        if fixed_time_run {
-            world.run_schedule(CoreSchedule::FixedUpdate);
-        } else {
-            check_again = false;
+        if let Some(max_steps) = fixed_time.max_steps_per_frame {
+            if fixed_time.steps_this_frame >= max_steps {
+                fixed_time.discard_whole_periods();
+                break;
+            }
+        }
+        if fixed_time.expend().is_err() {
+            break;
         }
+        fixed_time.steps_this_frame += 1;
+        let period = fixed_time.period;
+
+        let mut fixed_clock = world.resource_mut::<Time<Fixed>>();
+        fixed_clock.advance_by(period);
+        let generic = fixed_clock.as_generic();
+        *world.resource_mut::<Time>() = generic;
+
+        world.run_schedule(CoreSchedule::FixedUpdate);
+    }
+
+    if world.resource::<FixedTime>().steps_this_frame > 0 {
+        let generic = world.resource::<Time<Virtual>>().as_generic();
+        *world.resource_mut::<Time>() = generic;
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::{virt::update_virtual_time, Real};
+    use bevy_ecs::{
+        schedule::{Schedule, Schedules},
+        system::{Res, ResMut},
+    };
 
     #[test]
     fn fixed_time_starts_at_zero() {
@@ -150,6 +250,111 @@ mod test {
         assert_eq!(fixed_time.accumulated(), Duration::ZERO);
     }
 
+    #[test]
+    fn max_delta_clamps_accumulated_time() {
+        let mut fixed_time = FixedTime {
+            max_delta: Some(Duration::from_millis(250)),
+            ..FixedTime::new(Duration::from_millis(100))
+        };
+        fixed_time.tick(Duration::from_secs(5));
+        assert_eq!(fixed_time.accumulated(), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn overstep_fraction() {
+        let mut fixed_time = FixedTime::new(Duration::from_secs(2));
+        fixed_time.tick(Duration::from_secs(3));
+        assert!(fixed_time.expend().is_ok());
+        assert_eq!(fixed_time.overstep_fraction(), 0.5);
+    }
+
+    #[derive(Resource, Default)]
+    struct StepCount(u32);
+
+    #[derive(Resource, Default)]
+    struct ObservedDeltas(Vec<Duration>);
+
+    fn world_with_fixed_update(fixed_time: FixedTime) -> World {
+        let mut world = World::new();
+        let mut schedule = Schedule::new();
+        schedule.add_system(
+            |mut count: ResMut<StepCount>, time: Res<Time>, mut deltas: ResMut<ObservedDeltas>| {
+                count.0 += 1;
+                deltas.0.push(time.delta());
+            },
+        );
+        let mut schedules = Schedules::new();
+        schedules.insert(CoreSchedule::FixedUpdate, schedule);
+        world.insert_resource(schedules);
+        world.init_resource::<StepCount>();
+        world.init_resource::<ObservedDeltas>();
+        world.insert_resource(fixed_time);
+        world.init_resource::<Time>();
+        world.init_resource::<Time<Real>>();
+        world.insert_resource(Time::<Virtual>::from_max_delta(Duration::MAX));
+        world.init_resource::<Time<Fixed>>();
+        world
+    }
+
+    fn advance(world: &mut World, delta: Duration) {
+        let mut real = *world.resource::<Time<Real>>();
+        let mut virt = *world.resource::<Time<Virtual>>();
+        let mut time = Time::default();
+        real.update_with_duration(delta);
+        update_virtual_time(&mut time, &mut virt, &real);
+        world.insert_resource(real);
+        world.insert_resource(virt);
+        world.insert_resource(time);
+        run_fixed_update_schedule(world);
+    }
+
+    #[test]
+    fn max_steps_per_frame_discards_whole_periods() {
+        let mut world = world_with_fixed_update(FixedTime {
+            max_steps_per_frame: Some(2),
+            ..FixedTime::new(Duration::from_secs(1))
+        });
+        advance(&mut world, Duration::from_secs_f32(5.5));
+
+        assert_eq!(world.resource::<StepCount>().0, 2);
+        let fixed_time = world.resource::<FixedTime>();
+        assert_eq!(fixed_time.steps_this_frame(), 2);
+        assert_eq!(fixed_time.overstep_fraction(), 0.5);
+    }
+
+    #[test]
+    fn fixed_update_respects_virtual_time() {
+        let mut world = world_with_fixed_update(FixedTime::new(Duration::from_secs(1)));
+
+        world.resource_mut::<Time<Virtual>>().pause();
+        advance(&mut world, Duration::from_secs(3));
+        assert_eq!(world.resource::<StepCount>().0, 0);
+
+        world.resource_mut::<Time<Virtual>>().unpause();
+        world
+            .resource_mut::<Time<Virtual>>()
+            .set_relative_speed(2.0);
+        advance(&mut world, Duration::from_secs(3));
+        assert_eq!(world.resource::<StepCount>().0, 6);
+    }
+
+    #[test]
+    fn fixed_update_sees_fixed_clock() {
+        let mut world = world_with_fixed_update(FixedTime::new(Duration::from_millis(100)));
+        advance(&mut world, Duration::from_millis(250));
+
+        assert_eq!(
+            world.resource::<ObservedDeltas>().0,
+            vec![Duration::from_millis(100); 2]
+        );
+        assert_eq!(
+            world.resource::<Time<Fixed>>().elapsed(),
+            Duration::from_millis(200)
+        );
+        // outside of the schedule, the default clock follows virtual time again
+        assert_eq!(world.resource::<Time>().delta(), Duration::from_millis(250));
+    }
+
     #[test]
     fn repeatedly_expending_time() {
         let mut fixed_time = FixedTime::new(Duration::from_secs(1));
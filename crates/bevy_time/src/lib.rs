@@ -1,22 +1,33 @@
+mod entity_timer;
 pub mod fixed_timestep;
+mod real;
 mod stopwatch;
 #[allow(clippy::module_inception)]
 mod time;
 mod timer;
+mod virt;
 
+pub use entity_timer::*;
+pub use fixed_timestep::Fixed;
 use fixed_timestep::{run_fixed_update_schedule, FixedTime};
+pub use real::*;
 pub use stopwatch::*;
 pub use time::*;
 pub use timer::*;
+pub use virt::*;
 
 use bevy_ecs::system::{Res, ResMut};
 use bevy_utils::{tracing::warn, Duration, Instant};
 use crossbeam_channel::{Receiver, Sender};
+use virt::update_virtual_time;
 
 pub mod prelude {
     //! The Bevy Time Prelude.
     #[doc(hidden)]
-    pub use crate::{fixed_timestep::FixedTime, Time, Timer, TimerMode};
+    pub use crate::{
+        fixed_timestep::FixedTime, EntityStopwatch, EntityTimer, Fixed, Real, Time, Timer,
+        TimerClock, TimerFinished, TimerMode, Virtual,
+    };
 }
 
 use bevy_app::prelude::*;
@@ -27,28 +38,51 @@ use bevy_ecs::prelude::*;
 pub struct TimePlugin;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
-/// Updates the elapsed time. Any system that interacts with [Time] component should run after
-/// this.
+/// Updates the elapsed time and ticks [`EntityTimer`] and [`EntityStopwatch`] components. Any
+/// system that interacts with the [`Time`] resources or those components should run after this.
+///
+/// In the [`CoreSchedule::FixedUpdate`] schedule, this set runs in [`FixedUpdateSet::First`] and
+/// ticks the components that use [`TimerClock::Fixed`].
 pub struct TimeSystem;
 
 impl Plugin for TimePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<Time>()
+            .init_resource::<Time<Real>>()
+            .init_resource::<Time<Virtual>>()
+            .init_resource::<Time<Fixed>>()
             .init_resource::<TimeUpdateStrategy>()
             .register_type::<Timer>()
             .register_type::<Time>()
+            .register_type::<Time<Real>>()
+            .register_type::<Time<Virtual>>()
+            .register_type::<Time<Fixed>>()
             .register_type::<Stopwatch>()
+            .register_type::<TimerClock>()
+            .register_type::<TimerEasing>()
+            .register_type::<EntityTimer>()
+            .register_type::<EntityStopwatch>()
             .init_resource::<FixedTime>()
+            .add_event::<TimerFinished>()
             .configure_set(TimeSystem.in_base_set(CoreSet::First))
             .add_system(time_system.in_set(TimeSystem))
-            .add_system(run_fixed_update_schedule.in_base_set(CoreSet::FixedUpdate));
+            .add_system(tick_entity_timers.in_set(TimeSystem).after(time_system))
+            .add_system(run_fixed_update_schedule.in_base_set(CoreSet::FixedUpdate))
+            .edit_schedule(CoreSchedule::FixedUpdate, |schedule| {
+                schedule.configure_set(TimeSystem.in_base_set(FixedUpdateSet::First));
+            })
+            .add_system_to_schedule(
+                CoreSchedule::FixedUpdate,
+                tick_fixed_entity_timers.in_set(TimeSystem),
+            );
     }
 }
 
 /// Configuration resource used to determine how the time system should run.
 ///
 /// For most cases, [`TimeUpdateStrategy::Automatic`] is fine. When writing tests, dealing with networking, or similar
-/// you may prefer to set the next [`Time`] value manually.
+/// you may prefer to set the next [`Time<Real>`] value manually. [`Time<Virtual>`] and the default
+/// [`Time`] are always advanced from [`Time<Real>`].
 #[derive(Resource, Default)]
 pub enum TimeUpdateStrategy {
     #[default]
@@ -77,7 +111,12 @@ pub fn create_time_channels() -> (TimeSender, TimeReceiver) {
 
 /// The system used to update the [`Time`] used by app logic. If there is a render world the time is sent from
 /// there to this system through channels. Otherwise the time is updated in this system.
+///
+/// [`Time<Real>`] is updated first, then [`Time<Virtual>`] is advanced from it and copied into the
+/// default [`Time`].
 fn time_system(
+    mut real_time: ResMut<Time<Real>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
     mut time: ResMut<Time>,
     update_strategy: Res<TimeUpdateStrategy>,
     time_recv: Option<Res<TimeReceiver>>,
@@ -99,10 +138,12 @@ fn time_system(
     };
 
     match update_strategy.as_ref() {
-        TimeUpdateStrategy::Automatic => time.update_with_instant(new_time),
-        TimeUpdateStrategy::ManualInstant(instant) => time.update_with_instant(*instant),
+        TimeUpdateStrategy::Automatic => real_time.update_with_instant(new_time),
+        TimeUpdateStrategy::ManualInstant(instant) => real_time.update_with_instant(*instant),
         TimeUpdateStrategy::ManualDuration(duration) => {
-            time.update_with_instant(Instant::now() + *duration);
+            real_time.update_with_instant(Instant::now() + *duration);
         }
     }
+
+    update_virtual_time(&mut time, &mut virtual_time, &real_time);
 }
@@ -0,0 +1,151 @@
+use bevy_reflect::{FromReflect, Reflect};
+use bevy_utils::{Duration, Instant};
+
+use crate::time::Time;
+
+/// Real time clock representing elapsed wall clock time.
+///
+/// A specialization of the [`Time`] structure. **For method documentation, see
+/// [`Time<Real>#impl-Time<Real>`].**
+///
+/// It is automatically inserted as a resource by [`TimePlugin`](crate::TimePlugin) and updated
+/// with time instants according to [`TimeUpdateStrategy`](crate::TimeUpdateStrategy).
+///
+/// The [`delta()`](Time::delta) and [`elapsed()`](Time::elapsed) values of this clock should be
+/// used for anything which deals with real time passing. This clock keeps running while the
+/// [`Time<Virtual>`](crate::Virtual) clock is paused or slowed down, which makes it the right
+/// choice for UI animations, diagnostics and other things that should not follow game time.
+///
+/// The first update of the clock advances it by the time elapsed since
+/// [`startup()`](Time::startup), so that [`elapsed()`](Time::elapsed) always measures the time
+/// since the app was started.
+#[derive(Debug, Copy, Clone, Reflect, FromReflect)]
+pub struct Real {
+    startup: Instant,
+    first_update: Option<Instant>,
+    last_update: Option<Instant>,
+}
+
+impl Default for Real {
+    fn default() -> Self {
+        Self {
+            startup: Instant::now(),
+            first_update: None,
+            last_update: None,
+        }
+    }
+}
+
+impl Time<Real> {
+    /// Constructs a new `Time<Real>` instance with a specific startup `Instant`.
+    pub fn new(startup: Instant) -> Self {
+        Self::new_with(Real {
+            startup,
+            ..Default::default()
+        })
+    }
+
+    /// Updates the internal time measurements.
+    ///
+    /// Calling this method as part of your app will most likely result in inaccurate timekeeping,
+    /// as the `Time<Real>` resource is ordinarily managed by the [`TimePlugin`](crate::TimePlugin).
+    pub fn update(&mut self) {
+        let instant = Instant::now();
+        self.update_with_instant(instant);
+    }
+
+    /// Updates time with a specified [`Duration`].
+    ///
+    /// This method is provided for use in tests.
+    ///
+    /// Calling this method as part of your app will most likely result in inaccurate timekeeping,
+    /// as the `Time<Real>` resource is ordinarily managed by the [`TimePlugin`](crate::TimePlugin).
+    pub fn update_with_duration(&mut self, duration: Duration) {
+        let last_update = self.context().last_update.unwrap_or(self.context().startup);
+        self.update_with_instant(last_update + duration);
+    }
+
+    /// Updates time with a specified [`Instant`].
+    ///
+    /// This method is provided for use in tests.
+    ///
+    /// Calling this method as part of your app will most likely result in inaccurate timekeeping,
+    /// as the `Time<Real>` resource is ordinarily managed by the [`TimePlugin`](crate::TimePlugin).
+    pub fn update_with_instant(&mut self, instant: Instant) {
+        let Some(last_update) = self.context().last_update else {
+            let context = self.context_mut();
+            context.first_update = Some(instant);
+            context.last_update = Some(instant);
+            let startup = context.startup;
+            self.advance_to(instant.saturating_duration_since(startup));
+            return;
+        };
+        let delta = instant.saturating_duration_since(last_update);
+        self.advance_by(delta);
+        self.context_mut().last_update = Some(instant);
+    }
+
+    /// Returns the [`Instant`] the clock was created.
+    ///
+    /// This usually represents when the app was started.
+    #[inline]
+    pub fn startup(&self) -> Instant {
+        self.context().startup
+    }
+
+    /// Returns the [`Instant`] when [`Self::update`] was first called, if it exists.
+    ///
+    /// This usually represents when the first app update started.
+    #[inline]
+    pub fn first_update(&self) -> Option<Instant> {
+        self.context().first_update
+    }
+
+    /// Returns the [`Instant`] when [`Self::update`] was last called, if it exists.
+    ///
+    /// This usually represents when the current app update started.
+    #[inline]
+    pub fn last_update(&self) -> Option<Instant> {
+        self.context().last_update
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_update() {
+        let startup = Instant::now();
+        let mut time = Time::<Real>::new(startup);
+
+        assert_eq!(time.startup(), startup);
+        assert_eq!(time.first_update(), None);
+        assert_eq!(time.last_update(), None);
+        assert_eq!(time.delta(), Duration::ZERO);
+        assert_eq!(time.elapsed(), Duration::ZERO);
+
+        time.update_with_instant(startup + Duration::from_millis(5));
+
+        assert_eq!(
+            time.first_update(),
+            Some(startup + Duration::from_millis(5))
+        );
+        assert_eq!(time.last_update(), Some(startup + Duration::from_millis(5)));
+        assert_eq!(time.delta(), Duration::from_millis(5));
+        assert_eq!(time.elapsed(), Duration::from_millis(5));
+
+        time.update_with_duration(Duration::from_millis(10));
+
+        assert_eq!(
+            time.first_update(),
+            Some(startup + Duration::from_millis(5))
+        );
+        assert_eq!(
+            time.last_update(),
+            Some(startup + Duration::from_millis(15))
+        );
+        assert_eq!(time.delta(), Duration::from_millis(10));
+        assert_eq!(time.elapsed(), Duration::from_millis(15));
+    }
+}
@@ -0,0 +1,198 @@
+use crate::components::Transform;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, reflect::ReflectComponent};
+use bevy_reflect::prelude::*;
+use bevy_time::fixed_timestep::{run_fixed_update_schedule, FixedTime};
+
+/// Interpolates the [`Transform`] of an entity between the states of the two last fixed timesteps.
+///
+/// Systems in [`CoreSchedule::FixedUpdate`] see and modify the simulated transform. Everywhere
+/// else, the transform is blended between the two last simulated states using
+/// [`FixedTime::overstep_fraction`]. This hides the stutter caused by the fixed timestep not
+/// lining up with the frame rate, at the cost of lagging up to one period behind the simulation.
+///
+/// Changes made to the transform outside of [`CoreSchedule::FixedUpdate`] are treated as
+/// teleports: they become the simulated state, without any interpolation.
+///
+/// This requires the [`TransformInterpolationPlugin`].
+#[derive(Component, Debug, Default, PartialEq, Clone, Copy, Reflect, FromReflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct TransformInterpolation {
+    /// The simulated transform after the second-to-last fixed timestep.
+    previous: Transform,
+    /// The simulated transform after the last fixed timestep.
+    current: Transform,
+    /// The interpolated transform that was last written to the entity, if any.
+    rendered: Option<Transform>,
+}
+
+impl TransformInterpolation {
+    /// Returns the simulated transform after the last fixed timestep.
+    pub fn current(&self) -> Transform {
+        self.current
+    }
+
+    /// Returns the simulated transform after the second-to-last fixed timestep.
+    pub fn previous(&self) -> Transform {
+        self.previous
+    }
+}
+
+/// Set enum for the systems of the [`TransformInterpolationPlugin`].
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub enum TransformInterpolationSystem {
+    /// Restores the simulated [`Transform`] before the fixed timestep schedule runs.
+    RestoreSimulated,
+    /// Writes the interpolated [`Transform`] after the fixed timestep schedule has run.
+    Interpolate,
+}
+
+/// Adds interpolation of [`Transform`]s for entities with a [`TransformInterpolation`] component.
+///
+/// This depends on the [`FixedTime`] resource, which is added by `TimePlugin`.
+#[derive(Default)]
+pub struct TransformInterpolationPlugin;
+
+impl Plugin for TransformInterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<TransformInterpolation>()
+            .add_system(
+                restore_simulated_transforms
+                    .in_set(TransformInterpolationSystem::RestoreSimulated)
+                    .in_base_set(CoreSet::FixedUpdate)
+                    .before(run_fixed_update_schedule),
+            )
+            .add_system(
+                interpolate_transforms
+                    .in_set(TransformInterpolationSystem::Interpolate)
+                    .in_base_set(CoreSet::FixedUpdate)
+                    .after(run_fixed_update_schedule),
+            );
+    }
+}
+
+/// Blends two transforms, interpolating rotations spherically.
+fn interpolate(from: &Transform, to: &Transform, s: f32) -> Transform {
+    Transform {
+        translation: from.translation.lerp(to.translation, s),
+        rotation: from.rotation.slerp(to.rotation, s),
+        scale: from.scale.lerp(to.scale, s),
+    }
+}
+
+/// Puts the simulated state back into the [`Transform`] of interpolated entities, so that the
+/// fixed timestep schedule continues the simulation from it.
+pub fn restore_simulated_transforms(
+    mut query: Query<(&mut Transform, &mut TransformInterpolation)>,
+) {
+    for (mut transform, mut interpolation) in &mut query {
+        if interpolation.rendered == Some(*transform) {
+            if *transform != interpolation.current {
+                *transform = interpolation.current;
+            }
+        } else {
+            // The entity is new, or its transform was changed outside of the fixed timestep
+            interpolation.previous = *transform;
+            interpolation.current = *transform;
+        }
+        interpolation.rendered = None;
+    }
+}
+
+/// Records the state produced by the fixed timestep schedule and replaces the [`Transform`] of
+/// interpolated entities with a blend of the two last simulated states.
+pub fn interpolate_transforms(
+    fixed_time: Res<FixedTime>,
+    mut query: Query<(&mut Transform, &mut TransformInterpolation)>,
+) {
+    let steps = fixed_time.steps_this_frame();
+    let overstep = fixed_time.overstep_fraction().clamp(0.0, 1.0);
+
+    for (mut transform, mut interpolation) in &mut query {
+        if steps > 0 {
+            // Only the states before and after all of this frame's steps are known, so the
+            // state before the last step is approximated linearly between them.
+            let start = interpolation.current;
+            let end = *transform;
+            interpolation.previous = interpolate(&start, &end, (steps - 1) as f32 / steps as f32);
+            interpolation.current = end;
+        }
+
+        let rendered = interpolate(&interpolation.previous, &interpolation.current, overstep);
+        if *transform != rendered {
+            *transform = rendered;
+        }
+        interpolation.rendered = Some(rendered);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bevy_math::Vec3;
+    use bevy_time::{Real, Time, TimePlugin, TimeUpdateStrategy, Virtual};
+    use bevy_utils::Duration;
+
+    #[derive(Resource)]
+    struct Velocity(Vec3);
+
+    fn move_entities(velocity: Res<Velocity>, mut query: Query<&mut Transform>) {
+        for mut transform in &mut query {
+            transform.translation += velocity.0;
+        }
+    }
+
+    #[test]
+    fn transforms_are_interpolated_between_steps() {
+        let mut app = App::new();
+        app.add_plugin(TimePlugin)
+            .add_plugin(TransformInterpolationPlugin)
+            .insert_resource(FixedTime::new(Duration::from_secs(1)))
+            .insert_resource(Time::<Virtual>::from_max_delta(Duration::MAX))
+            .insert_resource(Velocity(Vec3::X))
+            .add_system_to_schedule(CoreSchedule::FixedUpdate, move_entities);
+        let entity = app
+            .world
+            .spawn((Transform::default(), TransformInterpolation::default()))
+            .id();
+
+        let mut now = app.world.resource::<Time<Real>>().startup();
+        let mut advance = |app: &mut App, secs: f32| {
+            now += Duration::from_secs_f32(secs);
+            app.insert_resource(TimeUpdateStrategy::ManualInstant(now));
+            app.update();
+            *app.world.get::<Transform>(entity).unwrap()
+        };
+
+        advance(&mut app, 0.0);
+        // one step: 0 -> 1, half a period left over
+        assert_eq!(advance(&mut app, 1.5).translation, Vec3::new(0.5, 0.0, 0.0));
+        // no step, a quarter period further
+        assert_eq!(
+            advance(&mut app, 0.25).translation,
+            Vec3::new(0.75, 0.0, 0.0)
+        );
+        // two steps: 1 -> 3, the previous state is approximated as 2
+        assert_eq!(
+            advance(&mut app, 2.0).translation,
+            Vec3::new(2.75, 0.0, 0.0)
+        );
+
+        let interpolation = app.world.get::<TransformInterpolation>(entity).unwrap();
+        assert_eq!(
+            interpolation.current().translation,
+            Vec3::new(3.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            interpolation.previous().translation,
+            Vec3::new(2.0, 0.0, 0.0)
+        );
+
+        // teleporting outside of the fixed timestep isn't interpolated
+        app.world.get_mut::<Transform>(entity).unwrap().translation = Vec3::new(10.0, 0.0, 0.0);
+        assert_eq!(
+            advance(&mut app, 0.0).translation,
+            Vec3::new(10.0, 0.0, 0.0)
+        );
+    }
+}
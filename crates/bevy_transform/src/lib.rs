@@ -5,6 +5,8 @@
 pub mod commands;
 /// The basic components of the transform crate
 pub mod components;
+/// Interpolation of transforms between fixed timesteps
+pub mod interpolation;
 /// Systems responsible for transform propagation
 pub mod systems;
 
@@ -12,7 +14,10 @@ pub mod systems;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        commands::BuildChildrenTransformExt, components::*, TransformBundle, TransformPlugin,
+        commands::BuildChildrenTransformExt,
+        components::*,
+        interpolation::{TransformInterpolation, TransformInterpolationPlugin},
+        TransformBundle, TransformPlugin,
     };
 }
 
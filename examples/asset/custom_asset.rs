@@ -8,13 +8,13 @@ use bevy::{
 };
 use serde::Deserialize;
 
-#[derive(Debug, Deserialize, TypeUuid)]
+#[derive(Debug, Deserialize, TypePath, TypeUuid)]
 #[uuid = "39cadc56-aa9c-4543-8640-a018b74b5052"]
 pub struct CustomAsset {
     pub value: i32,
 }
 
-#[derive(Default)]
+#[derive(Default, TypePath)]
 pub struct CustomAssetLoader;
 
 impl AssetLoader for CustomAssetLoader {
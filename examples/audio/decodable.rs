@@ -10,7 +10,7 @@ use bevy::utils::Duration;
 // This is where data read from an audio file would be stored, for example.
 // Implementing `TypeUuid` will automatically implement `Asset`.
 // This allows the type to be registered as an asset.
-#[derive(TypeUuid)]
+#[derive(TypePath, TypeUuid)]
 #[uuid = "c2090c23-78fd-44f1-8508-c89b1f3cec29"]
 struct SineAudio {
     frequency: f32,
@@ -15,24 +15,30 @@ fn main() {
         .run();
 }
 
-fn frame_update(mut last_time: Local<f32>, time: Res<Time>) {
+fn frame_update(mut last_time: Local<f32>, time: Res<Time<Real>>) {
     info!(
         "time since last frame_update: {}",
-        time.raw_elapsed_seconds() - *last_time
+        time.elapsed_seconds() - *last_time
     );
-    *last_time = time.raw_elapsed_seconds();
+    *last_time = time.elapsed_seconds();
 }
 
-fn fixed_update(mut last_time: Local<f32>, time: Res<Time>, fixed_time: Res<FixedTime>) {
+fn fixed_update(
+    mut last_time: Local<f32>,
+    real_time: Res<Time<Real>>,
+    time: Res<Time>,
+    fixed_time: Res<FixedTime>,
+) {
     info!(
         "time since last fixed_update: {}\n",
-        time.raw_elapsed_seconds() - *last_time
+        real_time.elapsed_seconds() - *last_time
     );
 
-    info!("fixed timestep: {}\n", FIXED_TIMESTEP);
+    // inside the fixed update schedule, `Time` advances by exactly one timestep per run
+    info!("fixed timestep: {}\n", time.delta_seconds());
     info!(
         "time accrued toward next fixed_update: {}\n",
         fixed_time.accumulated().as_secs_f32()
     );
-    *last_time = time.raw_elapsed_seconds();
+    *last_time = real_time.elapsed_seconds();
 }
@@ -14,6 +14,7 @@ fn main() {
         }))
         .register_type::<ComponentA>()
         .register_type::<ComponentB>()
+        .register_type::<ResourceA>()
         .add_startup_system(save_scene_system)
         .add_startup_system(load_scene_system)
         .add_startup_system(infotext_system)
@@ -56,6 +57,13 @@ impl FromWorld for ComponentB {
     }
 }
 
+// Resources can be serialized in scenes as well, with the same requirements `Component`s have.
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct ResourceA {
+    pub score: u32,
+}
+
 // The initial scene file will be loaded below and not change when the scene is saved
 const SCENE_FILE_PATH: &str = "scenes/load_scene_example.scn.ron";
 
@@ -96,11 +104,18 @@ fn save_scene_system(world: &mut World) {
         Transform::IDENTITY,
     ));
     scene_world.spawn(ComponentA { x: 3.0, y: 4.0 });
+    scene_world.insert_resource(ResourceA { score: 1 });
 
     // The TypeRegistry resource contains information about all registered types (including
     // components). This is used to construct scenes.
     let type_registry = world.resource::<AppTypeRegistry>();
-    let scene = DynamicScene::from_world(&scene_world, type_registry);
+    // Resources aren't extracted by `DynamicScene::from_world`, so the scene is built with a
+    // `DynamicSceneBuilder` to include them.
+    let mut builder =
+        DynamicSceneBuilder::from_world_with_type_registry(&scene_world, type_registry.clone());
+    builder.extract_entities(scene_world.iter_entities().map(|entity| entity.id()));
+    builder.extract_resources();
+    let scene = builder.build();
 
     // Scenes can be serialized like this:
     let serialized_scene = scene.serialize_ron(type_registry).unwrap();